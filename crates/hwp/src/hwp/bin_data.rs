@@ -1,5 +1,7 @@
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct File {
     pub name: String,
+    #[cfg_attr(feature = "serde", serde(with = "crate::hwp::utils::base64"))]
     pub data: Vec<u8>,
 }
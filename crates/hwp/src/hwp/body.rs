@@ -2,40 +2,61 @@ use std::io::{Read, Seek};
 
 use cfb::CompoundFile;
 
-use super::{header::Header, section::Section};
+use super::{
+    error::Error,
+    header::Header,
+    options::{invalid_stream, Limiter},
+    record::RecordCursor,
+    section::Section,
+};
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Body {
     pub sections: Vec<Section>,
 }
 
 impl Body {
     pub fn from_cfb<T: Read + Seek>(cfb: &mut CompoundFile<T>, header: &Header) -> Self {
-        let body_text = cfb.read_storage("/BodyText").unwrap();
-
-        let size = body_text.count();
-        let mut sections: Vec<Section> = Vec::with_capacity(size);
-        for i in 0..size {
-            let mut stream = cfb.open_stream(format!("/BodyText/Section{}", i)).unwrap();
-            let section = Section::from_stream(&mut stream, header);
-            sections.push(section);
-        }
-
-        Self { sections }
+        Self::read(cfb, header, false, &mut Limiter::unlimited())
+            .unwrap_or_else(|error| panic!("{}", error))
     }
 
     pub fn from_distributed<T: Read + Seek>(cfb: &mut CompoundFile<T>, header: &Header) -> Self {
-        let view_text = cfb.read_storage("/ViewText").unwrap();
-        let size = view_text.count();
+        Self::read(cfb, header, true, &mut Limiter::unlimited())
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
 
-        let mut sections: Vec<Section> = Vec::with_capacity(size);
+    /// 제한을 확인하며 본문(`BodyText`) 또는 배포용 문서의 본문(`ViewText`)을 읽는다
+    pub(crate) fn read<T: Read + Seek>(
+        cfb: &mut CompoundFile<T>,
+        header: &Header,
+        distributed: bool,
+        limiter: &mut Limiter,
+    ) -> Result<Self, Error> {
+        let storage = if distributed {
+            "/ViewText"
+        } else {
+            "/BodyText"
+        };
+        let size = cfb
+            .read_storage(storage)
+            .map_err(|_| invalid_stream(storage))?
+            .count();
 
+        let mut sections: Vec<Section> = Vec::with_capacity(size);
         for i in 0..size {
-            let mut stream = cfb.open_stream(format!("/ViewText/Section{}", i)).unwrap();
-            let section = Section::from_distributed(&mut stream, header);
-            sections.push(section);
+            let path = format!("{}/Section{}", storage, i);
+            let data = limiter
+                .read_stream(cfb, header, &path, distributed)?
+                .ok_or_else(|| invalid_stream(&path))?;
+            let records = limiter.read_records(&data, &path)?;
+            limiter.count_section(&records, &path)?;
+
+            let mut cursor = RecordCursor::from_records(records);
+            sections.push(Section::from_record_cursor(&mut cursor, &header.version));
         }
 
-        Self { sections }
+        Ok(Self { sections })
     }
 }
@@ -1,6 +1,7 @@
 use super::utils::bits::get_value_range;
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ColorRef {
     pub red: u32,
     pub blue: u32,
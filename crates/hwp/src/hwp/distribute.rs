@@ -0,0 +1,344 @@
+use std::io::{Cursor, Read, Seek, Write};
+
+use byteorder::{LittleEndian, ReadBytesExt};
+use cfb::CompoundFile;
+use flate2::{write::DeflateEncoder, Compression, Crc};
+use hwp_macro::make_4chid;
+use sha1::{Digest, Sha1};
+
+use super::{
+    error::Error,
+    header::update_flags,
+    options::{invalid_stream, Limiter, ParseOptions},
+    paragraph::char::{match_char_control, read_char, Char, CharControls},
+    record::{tags::BodyTextRecord, write_records, Record},
+    storage::{read_header, Storage},
+    utils::{
+        distribute::{
+            decode_distribute_doc_data, encode_distribute_doc_data, encrypt_distributed,
+            hash_code_offset, read_distribute_doc_data, DISTRIBUTE_DOC_DATA_SIZE,
+        },
+        random::SRand,
+    },
+};
+
+/// 배포용 문서 정보 (`HWPTAG_DISTRIBUTE_DOC_DATA`)
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DistributionInfo {
+    /// 해시 코드 (SHA-1, 16진수 문자열)
+    pub hash_code: String,
+    /// 제한 속성 원본 값
+    ///
+    /// NOTE: 비트의 의미가 확인되지 않았으므로 해석하지 않고 그대로 둔다
+    pub flags: u16,
+}
+
+impl DistributionInfo {
+    /// `ViewText`의 첫 구역에서 배포용 문서 정보를 읽는다
+    pub fn from_cfb<T: Read + Seek>(cfb: &mut CompoundFile<T>) -> Option<Self> {
+        let mut stream = cfb.open_stream("/ViewText/Section0").ok()?;
+        let data = read_distribute_doc_data(&mut stream);
+
+        Some(Self::from_data(&data))
+    }
+
+    pub fn from_data(data: &[u8; DISTRIBUTE_DOC_DATA_SIZE]) -> Self {
+        let decoded = decode_distribute_doc_data(data);
+        let offset = hash_code_offset(data);
+
+        let hash_code: Vec<u16> = decoded[offset..offset + 80]
+            .chunks_exact(2)
+            .map(|c| u16::from_le_bytes([c[0], c[1]]))
+            .take_while(|c| *c != 0)
+            .collect();
+        let hash_code = String::from_utf16_lossy(&hash_code);
+
+        let flags = u16::from_le_bytes([decoded[offset + 80], decoded[offset + 81]]);
+
+        Self { hash_code, flags }
+    }
+}
+
+/// 배포용 문서 설정
+#[derive(Debug, Clone, Default)]
+pub struct DistributeOptions {
+    /// 배포용 문서 암호
+    ///
+    /// NOTE: 한/글이 암호를 해시 하는 방식은 확인하지 못했다.
+    /// 한/글에서 이 암호로 배포용 설정을 풀 수 있는지는 확인되지 않았다.
+    pub password: String,
+    /// 제한 속성. `0x8000`과 합쳐서 [`DistributionInfo::flags`]에 그대로 기록한다
+    ///
+    /// 한/글과 같은 값을 쓰려면 [`HANCOM_RESTRICTIONS`]를 사용한다.
+    pub restrictions: u16,
+    /// 난수 seed. 없다면 문서의 내용으로 정한다
+    pub seed: Option<u32>,
+}
+
+const BODY_PATH: &str = "/BodyText/Section0";
+
+/// 일반 문서를 배포용 문서로 만든다
+///
+/// 본문(`BodyText`)은 암호화 하여 `ViewText`로 옮기고, `BodyText`에는 내용을 지운 구역 하나만 남긴다.
+/// 스크립트와 문서 이력도 같은 방식으로 암호화 한다.
+///
+/// 이미 배포용 문서라면 `Error::AlreadyDistributed`, 압축되지 않은 문서라면 `Error::NotCompressed`를 반환한다.
+/// 암호의 해시 코드 형식은 확인되지 않았다 ([`DistributeOptions::password`]).
+pub fn to_distributed(
+    bytes: &[u8],
+    options: &DistributeOptions,
+    parse_options: &ParseOptions,
+) -> Result<Vec<u8>, Error> {
+    let mut storage = Storage::from_bytes(bytes, parse_options)?;
+    let header = read_header(&storage)?;
+
+    if header.flags.distributed {
+        return Err(Error::AlreadyDistributed);
+    }
+    // NOTE: 암호화 블록을 채운 값이 압축 해제 후에 남지 않도록 압축된 문서만 지원한다
+    if !header.flags.compressed {
+        return Err(Error::NotCompressed);
+    }
+
+    let placeholder = match storage.get(BODY_PATH) {
+        Some(section) => placeholder_section(section, &Limiter::new(parse_options))?,
+        None => return Err(invalid_stream(BODY_PATH)),
+    };
+
+    let seed = options.seed.unwrap_or_else(|| default_seed(&storage));
+    let hash_code = hash_password(&options.password);
+
+    let mut data = [0u8; DISTRIBUTE_DOC_DATA_SIZE];
+    let mut rand = SRand::new(seed);
+    for value in data.iter_mut() {
+        *value = rand.rand() as u8;
+    }
+
+    let offset = ((seed & 0xF) + 4) as usize;
+    data[offset..offset + 80].copy_from_slice(&hash_code);
+
+    let flags = DISTRIBUTE_FLAG | options.restrictions;
+    data[offset + 80..offset + 82].copy_from_slice(&flags.to_le_bytes());
+
+    let data = encode_distribute_doc_data(seed, &data);
+    let key = &hash_code[0..16];
+
+    let mut index = 0;
+    while let Some(section) = storage.remove(&format!("/BodyText/Section{}", index)) {
+        storage.set(
+            &format!("/ViewText/Section{}", index),
+            encrypt_distributed(&data, key, &section),
+        );
+        index += 1;
+    }
+    storage.set(BODY_PATH, placeholder);
+
+    let paths: Vec<String> = storage
+        .paths()
+        .into_iter()
+        .filter(|path| {
+            ["/Scripts/DefaultJScript", "/Scripts/JScriptVersion"].contains(path)
+                || path.starts_with("/DocHistory/")
+        })
+        .map(|path| path.to_string())
+        .collect();
+    for path in paths {
+        if let Some(stream) = storage.get(&path) {
+            let encrypted = encrypt_distributed(&data, key, stream);
+            storage.set(&path, encrypted);
+        }
+    }
+
+    update_flags(&mut storage, |flags| flags.distributed = true);
+
+    Ok(storage.to_bytes())
+}
+
+/// NOTE: 한/글이 만든 배포용 문서(`tests/integration/hancom`)에 모두 켜져 있다.
+/// 의미는 확인하지 못했으므로 같은 값을 쓴다.
+const DISTRIBUTE_FLAG: u16 = 0x8000;
+
+/// 한/글에서 복사와 인쇄를 모두 제한한 배포용 문서의 제한 속성
+///
+/// NOTE: 한/글이 만든 문서 중에는 이 값(`0x8003`)만 확인했으며 각 비트의 의미는 확인하지 못했다
+pub const HANCOM_RESTRICTIONS: u16 = 0x0003;
+
+/// 암호의 해시 코드 (SHA-1의 16진수 대문자 문자열, UTF-16LE)
+///
+/// NOTE: 한/글이 암호의 어떤 표현을 해시 하는지는 확인하지 못했다.
+/// 문서를 여는 쪽은 해시 코드만 사용하므로 읽기에는 영향이 없다.
+fn hash_password(password: &str) -> [u8; 80] {
+    let bytes: Vec<u8> = password
+        .encode_utf16()
+        .flat_map(|c| c.to_le_bytes())
+        .collect();
+    let digest = Sha1::digest(bytes);
+
+    let mut hash_code = [0u8; 80];
+    let hex: Vec<u16> = digest
+        .iter()
+        .flat_map(|byte| format!("{:02X}", byte).encode_utf16().collect::<Vec<u16>>())
+        .collect();
+    for (i, c) in hex.iter().enumerate() {
+        hash_code[i * 2..i * 2 + 2].copy_from_slice(&c.to_le_bytes());
+    }
+
+    hash_code
+}
+
+/// NOTE: wasm에서도 동작하도록 운영체제의 난수 대신 문서 내용의 CRC를 사용한다
+fn default_seed(storage: &Storage) -> u32 {
+    let mut crc = Crc::new();
+    for stream in storage
+        .paths()
+        .into_iter()
+        .filter_map(|path| storage.get(path))
+    {
+        crc.update(stream);
+    }
+
+    crc.sum()
+}
+
+/// 배포용 문서의 `BodyText`에 남길 구역
+///
+/// 첫 문단의 구역 정의와 단 정의만 남기고 나머지 내용은 모두 지운다.
+/// 첫 문단을 해석할 수 없다면 `Error::InvalidStream`을 반환한다.
+fn placeholder_section(section: &[u8], limiter: &Limiter) -> Result<Vec<u8>, Error> {
+    let invalid = || invalid_stream(BODY_PATH);
+
+    let decoded = limiter.decode_stream(section.to_vec(), BODY_PATH, true, false)?;
+    let records = limiter.read_records(&decoded, BODY_PATH)?;
+
+    let paragraph_header = records.first().ok_or_else(invalid)?;
+    // 글자 수, 컨트롤 마스크, 문단 모양, 문단 스타일, 단 나누기 종류, 글자 모양 수, 범위 태그 수, 줄 정보 수
+    if paragraph_header.tag_id != BodyTextRecord::HWPTAG_PARA_HEADER as u32
+        || paragraph_header.data.len() < 18
+    {
+        return Err(invalid());
+    }
+    let level = paragraph_header.level;
+
+    let mut text = None;
+    let mut char_shape = None;
+    let mut line_segment = None;
+    let mut controls: Vec<Vec<Record>> = vec![];
+    for record in records[1..].iter() {
+        if record.level <= level {
+            break;
+        }
+
+        if record.level > level + 1 {
+            if let Some(control) = controls.last_mut() {
+                control.push(record.clone());
+            }
+            continue;
+        }
+
+        match record.tag_id {
+            tag if tag == BodyTextRecord::HWPTAG_PARA_TEXT as u32 => text = Some(record),
+            tag if tag == BodyTextRecord::HWPTAG_PARA_CHAR_SHAPE as u32 => {
+                char_shape = Some(record)
+            }
+            tag if tag == BodyTextRecord::HWPTAG_PARA_LINE_SEG as u32 => {
+                line_segment = Some(record)
+            }
+            tag if tag == BodyTextRecord::HWPTAG_CTRL_HEADER as u32 => {
+                controls.push(vec![record.clone()])
+            }
+            _ => {}
+        }
+    }
+
+    // NOTE: 확장 컨트롤 문자는 컨트롤 레코드와 같은 순서로 저장된다
+    let mut kept_controls = vec![];
+    let mut units: Vec<u16> = vec![];
+    if let Some(text) = text {
+        let mut index = 0;
+        let mut position = 0;
+        while position + 2 <= text.data.len() {
+            let code = u16::from_le_bytes([text.data[position], text.data[position + 1]]);
+            if code > 31 || match_char_control(code).is_some() {
+                position += 2;
+                continue;
+            }
+
+            // 제어 문자 (8 WCHAR)는 같은 코드로 시작하고 끝난다
+            let bytes = text.data.get(position..position + 16).ok_or_else(invalid)?;
+            if bytes[14..16] != bytes[0..2] {
+                return Err(invalid());
+            }
+            position += 16;
+
+            if let Char::ExtendedControl(code, data) = read_char(&mut Cursor::new(bytes)) {
+                let control = controls.get(index).ok_or_else(invalid)?;
+                index += 1;
+
+                let ctrl_id = control[0]
+                    .get_data_reader()
+                    .read_u32::<LittleEndian>()
+                    .map_err(|_| invalid())?;
+                if ctrl_id != make_4chid!('s', 'e', 'c', 'd')
+                    && ctrl_id != make_4chid!('c', 'o', 'l', 'd')
+                {
+                    continue;
+                }
+
+                units.push(code);
+                for pair in data.chunks_exact(2) {
+                    units.push(u16::from_le_bytes([pair[0], pair[1]]));
+                }
+                units.push(code);
+                kept_controls.push(control.clone());
+            }
+        }
+    }
+    units.push(CharControls::ParaBreak as u16);
+
+    let mut data = paragraph_header.data.clone();
+    let chars = (u32::from_le_bytes([data[0], data[1], data[2], data[3]]) & 0x80000000)
+        | units.len() as u32;
+    data[0..4].copy_from_slice(&chars.to_le_bytes());
+    let ctrl_mask = u32::from_le_bytes([data[4], data[5], data[6], data[7]]) & (1 << 2);
+    data[4..8].copy_from_slice(&ctrl_mask.to_le_bytes());
+    data[12..14].copy_from_slice(&(char_shape.is_some() as u16).to_le_bytes());
+    data[14..16].copy_from_slice(&0u16.to_le_bytes());
+    data[16..18].copy_from_slice(&(line_segment.is_some() as u16).to_le_bytes());
+
+    let mut result = vec![Record::new(
+        paragraph_header.tag_id,
+        level,
+        data.len() as u32,
+        data,
+    )];
+
+    let text: Vec<u8> = units.iter().flat_map(|unit| unit.to_le_bytes()).collect();
+    result.push(Record::new(
+        BodyTextRecord::HWPTAG_PARA_TEXT as u32,
+        level + 1,
+        text.len() as u32,
+        text,
+    ));
+
+    // 첫 글자 모양과 줄 정보만 위치를 0으로 맞춰서 남긴다
+    if let Some(record) = char_shape {
+        let mut data = record.data.get(0..8).ok_or_else(invalid)?.to_vec();
+        data[0..4].copy_from_slice(&0u32.to_le_bytes());
+        result.push(Record::new(record.tag_id, record.level, 8, data));
+    }
+    if let Some(record) = line_segment {
+        let mut data = record.data.get(0..36).ok_or_else(invalid)?.to_vec();
+        data[0..4].copy_from_slice(&0u32.to_le_bytes());
+        result.push(Record::new(record.tag_id, record.level, 36, data));
+    }
+
+    for control in kept_controls {
+        result.extend(control);
+    }
+
+    let mut encoder = DeflateEncoder::new(vec![], Compression::default());
+    encoder.write_all(&write_records(&result)).unwrap();
+
+    Ok(encoder.finish().unwrap())
+}
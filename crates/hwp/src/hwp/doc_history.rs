@@ -0,0 +1,212 @@
+use std::io::{Cursor, Read, Seek};
+
+use byteorder::{LittleEndian, ReadBytesExt};
+use cfb::CompoundFile;
+
+use super::{
+    error::Error,
+    header::Header,
+    options::Limiter,
+    record::{tags::DocHistoryRecord, Record},
+    utils::bits::get_flag,
+};
+
+/// 문서 이력 관리 (`DocHistory`)
+///
+/// 각 버전의 정보와 저장된 HWPML, DiffML 원문을 담는다.
+/// NOTE: DiffML의 형식은 공개되어있지 않아 이전 버전의 문서를 다시 만들지는 않는다
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DocHistory {
+    /// 히스토리 아이템 (`VersionLog0`부터 순서대로)
+    pub items: Vec<HistoryItem>,
+}
+
+impl DocHistory {
+    /// 문서 이력을 읽는다. 형식이 올바르지 않은 `VersionLog`는 건너뛴다
+    pub fn from_cfb<T: Read + Seek>(cfb: &mut CompoundFile<T>, header: &Header) -> Option<Self> {
+        Self::read(cfb, header, &Limiter::unlimited()).unwrap_or_default()
+    }
+
+    /// 제한을 확인하며 문서 이력을 읽는다
+    pub(crate) fn read<T: Read + Seek>(
+        cfb: &mut CompoundFile<T>,
+        header: &Header,
+        limiter: &Limiter,
+    ) -> Result<Option<Self>, Error> {
+        let size = match cfb.read_storage("/DocHistory") {
+            Ok(entries) => entries.count(),
+            Err(_) => return Ok(None),
+        };
+
+        let mut items = vec![];
+        for i in 0..size {
+            let path = format!("/DocHistory/VersionLog{}", i);
+            // NOTE: 배포용 문서는 모든 스트림이 배포용 문서 데이터로 암호화 되어있다
+            let records = limiter
+                .read_stream(cfb, header, &path, header.flags.distributed)
+                .and_then(|data| match data {
+                    Some(data) => limiter.read_records(&data, &path),
+                    None => Ok(vec![]),
+                });
+
+            match records {
+                Ok(records) => items.extend(read_items(records)),
+                Err(Error::InvalidStream(_)) => continue,
+                Err(error) => return Err(error),
+            }
+        }
+
+        Ok(Some(Self { items }))
+    }
+
+    /// 해당 버전의 히스토리 아이템
+    pub fn get(&self, version: u32) -> Option<&HistoryItem> {
+        self.items.iter().find(|item| item.version == Some(version))
+    }
+}
+
+/// `VersionLog` 스트림의 레코드에서 히스토리 아이템을 읽는다
+fn read_items(records: Vec<Record>) -> Vec<HistoryItem> {
+    let mut records = records.into_iter();
+    let mut items = vec![];
+    while let Some(record) = records.next() {
+        items.push(HistoryItem::from_records(record, &mut records));
+    }
+
+    items
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HistoryItem {
+    /// 버전
+    pub version: Option<u32>,
+    /// 날짜
+    pub date: Option<SystemTime>,
+    /// 작성자
+    pub writer: Option<String>,
+    /// 설명
+    pub description: Option<String>,
+    /// 비교 정보 (DiffML)
+    pub diff_data: Option<String>,
+    /// 가장 마지막 최근 문서 (HWPML)
+    pub last_document_data: Option<String>,
+    /// 현재 히스토리 아이템의 Lock 상태
+    pub locked: bool,
+    /// 문서 저장 시 자동 저장된 버전 여부
+    pub auto_saved: bool,
+}
+
+impl HistoryItem {
+    /// 시작 태그부터 끝 태그까지의 레코드로 히스토리 아이템을 만든다
+    fn from_records<I: Iterator<Item = Record>>(first: Record, records: &mut I) -> Self {
+        let mut item = Self {
+            version: None,
+            date: None,
+            writer: None,
+            description: None,
+            diff_data: None,
+            last_document_data: None,
+            locked: false,
+            auto_saved: false,
+        };
+
+        if first.tag_id == DocHistoryRecord::HISTORY_RECORD_TYPE_ETAG as u32 {
+            return item;
+        }
+        // NOTE: 시작 태그 없이 저장된 경우 해당 레코드부터 아이템으로 본다
+        item.apply(first);
+
+        for record in records {
+            if record.tag_id == DocHistoryRecord::HISTORY_RECORD_TYPE_ETAG as u32 {
+                break;
+            }
+            item.apply(record);
+        }
+
+        item
+    }
+
+    fn apply(&mut self, record: Record) {
+        match record.tag_id {
+            tag if tag == DocHistoryRecord::HISTORY_RECORD_TYPE_STAG as u32 => {
+                let mut reader = record.get_data_reader();
+                let flag = reader.read_u16::<LittleEndian>().unwrap_or(0);
+                let option = reader.read_u32::<LittleEndian>().unwrap_or(0);
+
+                self.locked = get_flag(flag as u32, 6);
+                self.auto_saved = get_flag(option, 0);
+            }
+            tag if tag == DocHistoryRecord::HISTORY_RECORD_TYPE_VERSION as u32 => {
+                self.version = record.get_data_reader().read_u32::<LittleEndian>().ok();
+            }
+            tag if tag == DocHistoryRecord::HISTORY_RECORD_TYPE_DATE as u32 => {
+                self.date = SystemTime::from_data(&record.data);
+            }
+            tag if tag == DocHistoryRecord::HISTORY_RECORD_TYPE_WRITER as u32 => {
+                self.writer = Some(read_history_string(&record.data));
+            }
+            tag if tag == DocHistoryRecord::HISTORY_RECORD_TYPE_DESCRIPTION as u32 => {
+                self.description = Some(read_history_string(&record.data));
+            }
+            tag if tag == DocHistoryRecord::HISTORY_RECORD_TYPE_DIFFDATA as u32 => {
+                self.diff_data = Some(read_history_string(&record.data));
+            }
+            tag if tag == DocHistoryRecord::HISTORY_RECORD_TYPE_LASTDOCDATA as u32 => {
+                self.last_document_data = Some(read_history_string(&record.data));
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Win32 SYSTEMTIME
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SystemTime {
+    pub year: u16,
+    pub month: u16,
+    pub day_of_week: u16,
+    pub day: u16,
+    pub hour: u16,
+    pub minute: u16,
+    pub second: u16,
+    pub milliseconds: u16,
+}
+
+impl SystemTime {
+    fn from_data(data: &[u8]) -> Option<Self> {
+        if data.len() < 16 {
+            return None;
+        }
+
+        let mut values = [0u16; 8];
+        Cursor::new(data)
+            .read_u16_into::<LittleEndian>(&mut values)
+            .unwrap();
+        let [year, month, day_of_week, day, hour, minute, second, milliseconds] = values;
+
+        Some(Self {
+            year,
+            month,
+            day_of_week,
+            day,
+            hour,
+            minute,
+            second,
+            milliseconds,
+        })
+    }
+}
+
+/// WCHAR 배열. 끝의 NULL 문자는 버린다
+fn read_history_string(data: &[u8]) -> String {
+    let chars: Vec<u16> = data
+        .chunks_exact(2)
+        .map(|c| u16::from_le_bytes([c[0], c[1]]))
+        .take_while(|c| *c != 0)
+        .collect();
+
+    String::from_utf16_lossy(&chars)
+}
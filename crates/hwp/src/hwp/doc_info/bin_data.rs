@@ -10,6 +10,7 @@ use crate::hwp::{
 };
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BinData {
     pub properties: BinDataProperties,
     pub absolute_path: Option<String>,
@@ -93,6 +94,7 @@ impl FromRecordCursor for BinData {
 
 #[repr(u8)]
 #[derive(Debug, PartialEq, Eq, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BinDataKind {
     /// 그림 외부 파일 참조
     Link,
@@ -104,6 +106,7 @@ pub enum BinDataKind {
 
 #[repr(u8)]
 #[derive(Debug, PartialEq, Eq, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CompressMode {
     /// 스토리지의 디폴트 모드 따라감
     Default,
@@ -115,6 +118,7 @@ pub enum CompressMode {
 
 #[repr(u8)]
 #[derive(Debug, PartialEq, Eq, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BinDataStatus {
     /// 아직 access 된 적이 없는 상태
     Initial,
@@ -127,6 +131,7 @@ pub enum BinDataStatus {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BinDataProperties {
     /// 타입
     pub kind: BinDataKind,
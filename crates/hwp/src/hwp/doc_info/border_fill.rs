@@ -14,6 +14,7 @@ use crate::hwp::{
 use super::bullet::Image;
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BorderFill {
     /// 3D 효과의 유무
     pub effect_3d: bool,
@@ -96,6 +97,7 @@ impl FromRecordCursor for BorderFill {
 
 #[repr(u8)]
 #[derive(Debug, Clone, PartialEq, Eq, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SlashDiagonalShape {
     None = 0b000,
     Slash = 0b010,
@@ -106,6 +108,7 @@ pub enum SlashDiagonalShape {
 
 #[repr(u8)]
 #[derive(Debug, Clone, PartialEq, Eq, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BackSlashDiagonalShape {
     None = 0b000,
     BackSlash = 0b010,
@@ -116,6 +119,7 @@ pub enum BackSlashDiagonalShape {
 
 #[repr(u8)]
 #[derive(Debug, Clone, PartialEq, Eq, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BorderKind {
     /// 실선
     Solid,
@@ -154,6 +158,7 @@ pub enum BorderKind {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Border {
     pub width: u8,
     pub kind: BorderKind,
@@ -171,6 +176,7 @@ impl Border {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Fill {
     /// 채우기 종류
     pub kind: FillKind,
@@ -220,6 +226,7 @@ impl Fill {
 
 #[repr(u32)]
 #[derive(Debug, Clone, PartialEq, Eq, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FillKind {
     /// 채우기 없음
     None = 0x00000000,
@@ -232,6 +239,7 @@ pub enum FillKind {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FillContent {
     None(()),
     Color(ColorFill),
@@ -240,6 +248,7 @@ pub enum FillContent {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ColorFill {
     /// 배경색
     pub background_color: ColorRef,
@@ -276,6 +285,7 @@ impl ColorFill {
 /// 채우기 무늬 종류
 #[repr(u8)]
 #[derive(Debug, Clone, PartialEq, Eq, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PatternKind {
     /// 없음
     None,
@@ -294,6 +304,7 @@ pub enum PatternKind {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GradationFill {
     /// 그러데이션 유형
     pub kind: GradationKind,
@@ -358,6 +369,7 @@ impl GradationFill {
 /// 그러데이션 유형
 #[repr(u8)]
 #[derive(Debug, Clone, PartialEq, Eq, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum GradationKind {
     /// 줄무늬형
     Linear = 1,
@@ -370,12 +382,14 @@ pub enum GradationKind {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ImageFill {
     /// 이미지 채우기 유형
     pub kind: ImageFillKind,
     /// 이미지 정보
     pub image: Image,
     /// 문서에 미정의된 값
+    #[cfg_attr(feature = "serde", serde(with = "crate::hwp::utils::base64"))]
     pub unknown: Vec<u8>,
 }
 
@@ -400,6 +414,7 @@ impl ImageFill {
 
 #[repr(u8)]
 #[derive(Debug, Clone, PartialEq, Eq, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ImageFillKind {
     /// 바둑판식으로-모두
     Tile,
@@ -11,6 +11,7 @@ use crate::hwp::{
 };
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Bullet {
     /// 문단 머리의 정보
     pub paragraph_head: ParagraphHead,
@@ -49,6 +50,7 @@ impl FromRecordCursor for Bullet {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Image {
     /// 밝기
     pub bright: u8,
@@ -73,6 +75,7 @@ impl Image {
 
 #[repr(u8)]
 #[derive(Debug, Clone, PartialEq, Eq, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ImageEffect {
     /// 원래 그림에서
     RealPic,
@@ -5,6 +5,7 @@ use crate::hwp::{
 
 // TODO: (@hahnlee)
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ChangeTrackingAuthor {}
 
 impl FromRecordCursor for ChangeTrackingAuthor {
@@ -12,6 +12,7 @@ use crate::hwp::{
 use super::border_fill::BorderKind;
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CharShape {
     /// 언어별 글꼴 ID(FaceID) 참조 값
     pub font_ids: [u16; 7],
@@ -211,6 +212,7 @@ impl FromRecordCursor for CharShape {
 
 #[repr(u8)]
 #[derive(Debug, PartialEq, Eq, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum UnderlineKind {
     None,
     Bottom,
@@ -219,6 +221,7 @@ pub enum UnderlineKind {
 
 #[repr(u8)]
 #[derive(Debug, PartialEq, Eq, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum OutlineKind {
     /// 없음
     None,
@@ -238,6 +241,7 @@ pub enum OutlineKind {
 
 #[repr(u8)]
 #[derive(Debug, PartialEq, Eq, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ShadowKind {
     /// 없음
     None,
@@ -249,6 +253,7 @@ pub enum ShadowKind {
 
 #[repr(u8)]
 #[derive(Debug, PartialEq, Eq, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SymMark {
     /// 없음
     None,
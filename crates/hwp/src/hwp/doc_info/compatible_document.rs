@@ -5,6 +5,7 @@ use num_derive::FromPrimitive;
 use crate::hwp::record::{tags::DocInfoRecord, RecordCursor};
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CompatibleDocument {
     /// 대상 프로그램
     pub target_program: TargetProgram,
@@ -33,6 +34,7 @@ impl CompatibleDocument {
 
 #[repr(u8)]
 #[derive(Debug, PartialEq, Eq, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TargetProgram {
     /// 한/글 문서(현재 버전)
     HWP201X,
@@ -43,6 +45,7 @@ pub enum TargetProgram {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LayoutCompatibility {
     /// 글자 단위 서식
     pub text_attribute: u32,
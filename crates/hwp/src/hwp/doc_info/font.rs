@@ -11,6 +11,7 @@ use crate::hwp::{
 };
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Font {
     /// 글꼴 이름
     pub name: String,
@@ -79,6 +80,7 @@ impl FromRecordCursor for Font {
 /// https://en.wikipedia.org/wiki/PANOSE
 /// https://monotype.github.io/panose/pan1.htm
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Panose {
     /// 글꼴 계열
     pub kind: u8,
@@ -121,6 +123,7 @@ impl Panose {
 
 #[repr(u8)]
 #[derive(Debug, PartialEq, Eq, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AlternativeKind {
     /// 원래 종류를 알 수 없을 때
     Unknown,
@@ -13,6 +13,7 @@ use super::{
 };
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IDMappings {
     /// 바이너리 데이터
     pub binary_data: Vec<BinData>,
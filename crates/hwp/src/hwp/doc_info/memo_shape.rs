@@ -5,6 +5,7 @@ use crate::hwp::{
 
 // TODO: (@hahnlee)
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MemoShape {}
 
 impl FromRecordCursor for MemoShape {
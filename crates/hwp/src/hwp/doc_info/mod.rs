@@ -18,10 +18,11 @@ pub mod track_change;
 use std::io::{Read, Seek};
 
 use cfb::CompoundFile;
-use flate2::read::DeflateDecoder;
 
 use crate::hwp::{
     doc_info::{compatible_document::CompatibleDocument, track_change::TrackChange},
+    error::Error,
+    options::{invalid_stream, Limiter},
     record::RecordCursor,
 };
 
@@ -30,6 +31,7 @@ use self::{id_mappings::IDMappings, properties::Properties};
 use super::{header::Header, record::tags::DocInfoRecord, version::Version};
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DocInfo {
     pub properties: Properties,
     pub id_mappings: IDMappings,
@@ -40,18 +42,32 @@ pub struct DocInfo {
 
 impl DocInfo {
     pub fn from_cfb<T: Read + Seek>(cfb: &mut CompoundFile<T>, header: &Header) -> Self {
-        let mut stream = cfb.open_stream("/DocInfo").unwrap();
-        if header.flags.compressed {
-            let mut data = DeflateDecoder::new(&mut stream);
-            return DocInfo::from_reader(&mut data, &header.version);
-        } else {
-            return DocInfo::from_reader(&mut stream, &header.version);
-        }
+        Self::read(cfb, header, &Limiter::unlimited()).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// 제한을 확인하며 문서 정보를 읽는다
+    pub(crate) fn read<T: Read + Seek>(
+        cfb: &mut CompoundFile<T>,
+        header: &Header,
+        limiter: &Limiter,
+    ) -> Result<Self, Error> {
+        let path = "/DocInfo";
+        let data = limiter
+            .read_stream(cfb, header, path, false)?
+            .ok_or_else(|| invalid_stream(path))?;
+        let records = limiter.read_records(&data, path)?;
+
+        Ok(Self::from_record_cursor(
+            RecordCursor::from_records(records),
+            &header.version,
+        ))
     }
 
     pub fn from_reader<T: Read>(reader: &mut T, version: &Version) -> Self {
-        let mut cursor = RecordCursor::new(reader);
+        Self::from_record_cursor(RecordCursor::new(reader), version)
+    }
 
+    fn from_record_cursor(mut cursor: RecordCursor, version: &Version) -> Self {
         let properties = Properties::from_record(&mut cursor.current());
         let id_mappings = IDMappings::from_record_cursor(&mut cursor, &version);
 
@@ -11,6 +11,7 @@ use crate::hwp::{
 };
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Numbering {
     /// 시작 번호
     pub start: u16,
@@ -61,6 +62,7 @@ impl FromRecordCursor for Numbering {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ParagraphHead {
     /// 문단의 정렬 종류
     pub align: ParagraphHeadAlign,
@@ -121,6 +123,7 @@ impl ParagraphHead {
 
 #[repr(u8)]
 #[derive(Debug, PartialEq, Eq, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ParagraphHeadAlign {
     Left,
     Center,
@@ -129,6 +132,7 @@ pub enum ParagraphHeadAlign {
 
 #[repr(u8)]
 #[derive(Debug, PartialEq, Eq, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TextOffsetKind {
     /// 글자 크기에 대한 상대 비율
     Percent,
@@ -9,6 +9,7 @@ use crate::hwp::{
 };
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ParagraphShape {
     /// 줄 간격 종류. 한/글 2007 이하 버전에서 사용.
     pub line_space_kind_old: LineSpacingKind,
@@ -209,6 +210,7 @@ impl FromRecordCursor for ParagraphShape {
 
 #[repr(u8)]
 #[derive(Debug, PartialEq, Eq, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Align {
     /// 양쪽 정렬
     Justify,
@@ -226,6 +228,7 @@ pub enum Align {
 
 #[repr(u8)]
 #[derive(Debug, PartialEq, Eq, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BreakLatinWord {
     /// 단어
     KeepWord,
@@ -237,6 +240,7 @@ pub enum BreakLatinWord {
 
 #[repr(u8)]
 #[derive(Debug, PartialEq, Eq, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BreakNonLatinWord {
     /// 단어
     KeepWord,
@@ -246,6 +250,7 @@ pub enum BreakNonLatinWord {
 
 #[repr(u8)]
 #[derive(Debug, PartialEq, Eq, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum VerticalAlign {
     /// 글꼴기준
     Baseline,
@@ -259,6 +264,7 @@ pub enum VerticalAlign {
 
 #[repr(u8)]
 #[derive(Debug, PartialEq, Eq, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ParagraphHeadingKind {
     /// 없음
     None,
@@ -272,6 +278,7 @@ pub enum ParagraphHeadingKind {
 
 #[repr(u8)]
 #[derive(Debug, PartialEq, Eq, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum LineSpacingKind {
     /// 글자에 따라 (%)
     Percent,
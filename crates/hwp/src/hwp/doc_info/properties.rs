@@ -3,6 +3,7 @@ use byteorder::{LittleEndian, ReadBytesExt};
 use crate::hwp::record::{tags::DocInfoRecord, Record};
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Properties {
     /// 구역 개수
     pub sections: u16,
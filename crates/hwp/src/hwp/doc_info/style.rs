@@ -9,6 +9,7 @@ use crate::hwp::{
 };
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Style {
     /// 로컬 스타일 이름. 한글 윈도우에서는 한글 스타일 이름
     pub name: String,
@@ -64,6 +65,7 @@ impl FromRecordCursor for Style {
 
 #[repr(u8)]
 #[derive(Debug, PartialEq, Eq, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum StyleKind {
     /// 문단 스타일
     Para,
@@ -11,6 +11,7 @@ use crate::hwp::{
 };
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TabDefinition {
     /// 문단 왼쪽 끝 자동 탭(내어 쓰기용 자동 탭) 유무
     pub left_tab: bool,
@@ -31,7 +32,7 @@ impl FromRecordCursor for TabDefinition {
         let right_tab = get_flag(attribute, 1);
 
         let count = reader.read_u32::<LittleEndian>().unwrap();
-        let mut tab_infos = Vec::with_capacity(count as usize);
+        let mut tab_infos = Vec::with_capacity((count as usize).min(record.data.len()));
         for _ in 0..count {
             tab_infos.push(TabInfo::from_reader(&mut reader));
         }
@@ -47,6 +48,7 @@ impl FromRecordCursor for TabDefinition {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TabInfo {
     pub position: u32,
     pub kind: TabKind,
@@ -71,6 +73,7 @@ impl TabInfo {
 
 #[repr(u8)]
 #[derive(Debug, PartialEq, Eq, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TabKind {
     Left,
     Right,
@@ -4,7 +4,9 @@ use crate::hwp::record::{tags::DocInfoRecord, RecordCursor};
 
 /// 변경 추적 정보
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TrackChange {
+    #[cfg_attr(feature = "serde", serde(with = "crate::hwp::utils::base64"))]
     pub unknown: Vec<u8>,
 }
 
@@ -0,0 +1,1250 @@
+use std::ops::Range;
+
+use super::{
+    bin_data::File,
+    doc_info::{
+        border_fill::{BorderFill, BorderKind, FillContent},
+        char_shape::{CharShape, UnderlineKind},
+        numbering::{ParagraphHead, ParagraphHeadAlign},
+        paragraph_shape::{Align, LineSpacingKind, ParagraphHeadingKind, ParagraphShape},
+        style::StyleKind,
+        DocInfo,
+    },
+    paragraph::{
+        control::{
+            common_properties::{Caption, CaptionAlign},
+            page_definition::{Landscape, PageDefinition},
+            shape_object::picture::PictureRecord,
+            table::{Cell, TableControl},
+            Control,
+        },
+        text_options::TextOptions,
+        Paragraph,
+    },
+    section::Section,
+    summary_info::SummaryInfo,
+    utils::{
+        xml::{border_width, escape, LineStyle},
+        zip::ZipWriter,
+    },
+};
+
+const NAMESPACES: &str = concat!(
+    " xmlns:w=\"http://schemas.openxmlformats.org/wordprocessingml/2006/main\"",
+    " xmlns:r=\"http://schemas.openxmlformats.org/officeDocument/2006/relationships\"",
+    " xmlns:wp=\"http://schemas.openxmlformats.org/drawingml/2006/wordprocessingDrawing\"",
+    " xmlns:a=\"http://schemas.openxmlformats.org/drawingml/2006/main\"",
+    " xmlns:pic=\"http://schemas.openxmlformats.org/drawingml/2006/picture\"",
+);
+const XML_DECLARATION: &str = "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\n";
+const RELATIONSHIP: &str = "http://schemas.openxmlformats.org/officeDocument/2006/relationships";
+const CONTENT_TYPE: &str = "application/vnd.openxmlformats-officedocument.wordprocessingml";
+
+/// DOCX로 바꾸는 방법
+#[derive(Debug, Clone)]
+pub struct DocxOptions {
+    /// 문단의 글자를 바꾸는 방법. 탭과 강제 줄 나눔은 워드의 탭과 줄 바꿈으로 바꾼다
+    ///
+    /// 기본값은 머리말/꼬리말을 넣는다
+    pub text: TextOptions,
+}
+
+impl Default for DocxOptions {
+    fn default() -> Self {
+        Self {
+            text: TextOptions {
+                headers_footers: true,
+                ..TextOptions::default()
+            },
+        }
+    }
+}
+
+/// 구역들을 워드 문서(OOXML WordprocessingML) 패키지로 바꾼다
+///
+/// 문서의 스타일은 `s{ID}` 스타일로, 번호/글머리표는 `numbering.xml`의 번호 정의로 옮기고
+/// 문단 모양과 글자 모양은 각 문단과 글자에 직접 지정한다.
+pub(crate) fn sections_to_docx(
+    sections: &[Section],
+    doc_info: &DocInfo,
+    bin_data: &[File],
+    summary_info: Option<&SummaryInfo>,
+    options: &DocxOptions,
+) -> Vec<u8> {
+    let mut writer = DocxWriter {
+        doc_info,
+        options,
+        headers_footers: vec![],
+        section_headers: vec![],
+        footnotes: vec![],
+        endnotes: vec![],
+        images: vec![],
+        outline_numbering_id: None,
+        drawing_id: 0,
+        bookmark_id: 0,
+    };
+
+    let mut body = String::new();
+    for (index, section) in sections.iter().enumerate() {
+        let page_definition = writer.begin_section(section);
+        writer.write_paragraphs(&mut body, &section.paragraphs);
+
+        // 마지막 구역의 설정은 본문 끝에, 나머지는 구역의 마지막 문단에 넣는다
+        let section_properties = writer.section_properties(page_definition);
+        if index + 1 < sections.len() {
+            body.push_str(&format!("<w:p><w:pPr>{}</w:pPr></w:p>", section_properties));
+        } else {
+            body.push_str(&section_properties);
+        }
+    }
+
+    // 실제로 쓰인 그림만 넣는다
+    let mut media = vec![];
+    for id in &writer.images {
+        let item = doc_info
+            .id_mappings
+            .binary_data
+            .iter()
+            .find(|item| item.id == Some(*id));
+        let name = match item.and_then(|item| item.cfb_file_name()) {
+            Some(name) => name,
+            None => continue,
+        };
+        if let Some(file) = bin_data.iter().find(|file| file.name == name) {
+            media.push((*id, file));
+        }
+    }
+
+    let mut relationships = vec![
+        ("styles".to_string(), "styles", "styles.xml".to_string()),
+        (
+            "numbering".to_string(),
+            "numbering",
+            "numbering.xml".to_string(),
+        ),
+    ];
+    // NOTE: 머리말, 각주 등 다른 파트에서도 같은 ID로 그림을 참조할 수 있도록 모든 파트에 그림 관계를 넣는다
+    let image_relationships: Vec<(String, &str, String)> = media
+        .iter()
+        .map(|(id, file)| {
+            (
+                format!("image{}", id),
+                "image",
+                format!("media/{}", file.name),
+            )
+        })
+        .collect();
+
+    let mut zip = ZipWriter::default();
+    let mut overrides = vec![
+        ("/word/document.xml".to_string(), "document.main"),
+        ("/word/styles.xml".to_string(), "styles"),
+        ("/word/numbering.xml".to_string(), "numbering"),
+    ];
+
+    for (index, (name, xml)) in writer.headers_footers.iter().enumerate() {
+        let kind = if name.starts_with("header") {
+            "header"
+        } else {
+            "footer"
+        };
+        let root = if kind == "header" { "w:hdr" } else { "w:ftr" };
+        zip_xml(
+            &mut zip,
+            &format!("word/{}", name),
+            &format!("<{0}{1}>{2}</{0}>", root, NAMESPACES, xml),
+        );
+        zip_relationships(
+            &mut zip,
+            &format!("word/_rels/{}.rels", name),
+            &image_relationships,
+        );
+        relationships.push((format!("hf{}", index + 1), kind, name.clone()));
+        overrides.push((format!("/word/{}", name), kind));
+    }
+
+    for (notes, kind) in [
+        (&writer.footnotes, "footnote"),
+        (&writer.endnotes, "endnote"),
+    ] {
+        if notes.is_empty() {
+            continue;
+        }
+
+        // 워드는 -1, 0번을 구분선으로 사용한다
+        let mut xml = format!("<w:{0}s{1}>", kind, NAMESPACES);
+        for (id, separator) in [(-1, "separator"), (0, "continuationSeparator")] {
+            xml.push_str(&format!(
+                "<w:{0} w:type=\"{1}\" w:id=\"{2}\"><w:p><w:r><w:{1}/></w:r></w:p></w:{0}>",
+                kind, separator, id
+            ));
+        }
+        for (index, note) in notes.iter().enumerate() {
+            xml.push_str(&format!(
+                "<w:{0} w:id=\"{1}\">{2}</w:{0}>",
+                kind,
+                index + 1,
+                note
+            ));
+        }
+        xml.push_str(&format!("</w:{}s>", kind));
+
+        let part = if kind == "footnote" {
+            "footnotes"
+        } else {
+            "endnotes"
+        };
+        let name = format!("{}.xml", part);
+        zip_xml(&mut zip, &format!("word/{}", name), &xml);
+        zip_relationships(
+            &mut zip,
+            &format!("word/_rels/{}.rels", name),
+            &image_relationships,
+        );
+        relationships.push((part.to_string(), part, name.clone()));
+        overrides.push((format!("/word/{}", name), part));
+    }
+
+    relationships.extend(image_relationships.iter().cloned());
+
+    zip_xml(
+        &mut zip,
+        "word/document.xml",
+        &format!(
+            "<w:document{}><w:body>{}</w:body></w:document>",
+            NAMESPACES, body
+        ),
+    );
+    zip_xml(&mut zip, "word/styles.xml", &styles(doc_info));
+    zip_xml(&mut zip, "word/numbering.xml", &numbering(doc_info));
+    zip_relationships(&mut zip, "word/_rels/document.xml.rels", &relationships);
+
+    let mut extensions: Vec<&str> = vec![];
+    for (_, file) in &media {
+        zip.add_file(&format!("word/media/{}", file.name), &file.data, false);
+
+        let extension = file.name.rsplit('.').next().unwrap_or_default();
+        if !extensions.contains(&extension) {
+            extensions.push(extension);
+        }
+    }
+
+    zip_xml(
+        &mut zip,
+        "docProps/core.xml",
+        &core_properties(summary_info),
+    );
+    zip_xml(
+        &mut zip,
+        "_rels/.rels",
+        &format!(
+            concat!(
+                "<Relationships xmlns=\"http://schemas.openxmlformats.org/package/2006/relationships\">",
+                "<Relationship Id=\"document\" Type=\"{}/officeDocument\" Target=\"word/document.xml\"/>",
+                "<Relationship Id=\"core\" ",
+                "Type=\"http://schemas.openxmlformats.org/package/2006/relationships/metadata/core-properties\" ",
+                "Target=\"docProps/core.xml\"/>",
+                "</Relationships>"
+            ),
+            RELATIONSHIP
+        ),
+    );
+
+    let mut content_types = String::from(concat!(
+        "<Types xmlns=\"http://schemas.openxmlformats.org/package/2006/content-types\">",
+        "<Default Extension=\"rels\" ContentType=\"application/vnd.openxmlformats-package.relationships+xml\"/>",
+        "<Default Extension=\"xml\" ContentType=\"application/xml\"/>",
+    ));
+    for extension in extensions {
+        content_types.push_str(&format!(
+            "<Default Extension=\"{}\" ContentType=\"{}\"/>",
+            extension,
+            image_content_type(extension).unwrap_or_default()
+        ));
+    }
+    for (part, kind) in overrides {
+        content_types.push_str(&format!(
+            "<Override PartName=\"{}\" ContentType=\"{}.{}+xml\"/>",
+            part, CONTENT_TYPE, kind
+        ));
+    }
+    content_types.push_str(concat!(
+        "<Override PartName=\"/docProps/core.xml\" ",
+        "ContentType=\"application/vnd.openxmlformats-package.core-properties+xml\"/>",
+        "</Types>"
+    ));
+    zip_xml(&mut zip, "[Content_Types].xml", &content_types);
+
+    zip.finish()
+}
+
+struct DocxWriter<'a> {
+    doc_info: &'a DocInfo,
+    options: &'a DocxOptions,
+    /// 머리말/꼬리말 파트의 (파일 이름, 내용)
+    headers_footers: Vec<(String, String)>,
+    /// 현재 구역이 참조하는 머리말/꼬리말의 (종류, 관계 ID)
+    section_headers: Vec<(&'static str, String)>,
+    footnotes: Vec<String>,
+    endnotes: Vec<String>,
+    /// 문서에서 참조한 그림의 BinData ID
+    images: Vec<u16>,
+    /// 현재 구역의 개요 번호 ID (1부터)
+    outline_numbering_id: Option<u16>,
+    drawing_id: u32,
+    bookmark_id: u32,
+}
+
+impl DocxWriter<'_> {
+    /// 구역 정의 컨트롤을 찾아 개요 번호를 정하고 용지 설정을 반환한다
+    fn begin_section<'s>(&mut self, section: &'s Section) -> Option<&'s PageDefinition> {
+        self.section_headers.clear();
+        self.outline_numbering_id = None;
+
+        let control = section
+            .paragraphs
+            .iter()
+            .flat_map(|paragraph| paragraph.controls.iter())
+            .find_map(|control| match control {
+                Control::SectionDefinition(control) => Some(control),
+                _ => None,
+            })?;
+
+        self.outline_numbering_id = Some(control.numbering_id).filter(|id| *id > 0);
+
+        Some(&control.page_definition)
+    }
+
+    fn section_properties(&self, page_definition: Option<&PageDefinition>) -> String {
+        let mut xml = String::from("<w:sectPr>");
+        for (kind, id) in &self.section_headers {
+            xml.push_str(&format!(
+                "<w:{}Reference w:type=\"default\" r:id=\"{}\"/>",
+                kind, id
+            ));
+        }
+
+        if let Some(page) = page_definition {
+            let (mut width, mut height) = (page.width, page.height);
+            let landscape = page.landscape == Landscape::Widely;
+            if landscape {
+                std::mem::swap(&mut width, &mut height);
+            }
+            xml.push_str(&format!(
+                "<w:pgSz w:w=\"{}\" w:h=\"{}\"{}/>",
+                to_twip(width as i32),
+                to_twip(height as i32),
+                if landscape {
+                    " w:orient=\"landscape\""
+                } else {
+                    ""
+                }
+            ));
+
+            // NOTE: 한/글의 위쪽/아래쪽 여백은 머리말/꼬리말 여백을 뺀 값이다
+            let padding = &page.padding;
+            xml.push_str(&format!(
+                "<w:pgMar w:top=\"{}\" w:right=\"{}\" w:bottom=\"{}\" w:left=\"{}\" w:header=\"{}\" w:footer=\"{}\" w:gutter=\"{}\"/>",
+                to_twip((padding.top + padding.header) as i32),
+                to_twip(padding.right as i32),
+                to_twip((padding.bottom + padding.footer) as i32),
+                to_twip(padding.left as i32),
+                to_twip(padding.top as i32),
+                to_twip(padding.bottom as i32),
+                to_twip(padding.binding as i32),
+            ));
+        }
+        xml.push_str("</w:sectPr>");
+
+        xml
+    }
+
+    fn write_paragraphs(&mut self, out: &mut String, paragraphs: &[Paragraph]) {
+        for paragraph in paragraphs {
+            self.write_paragraph(out, paragraph);
+        }
+    }
+
+    fn write_paragraph(&mut self, out: &mut String, paragraph: &Paragraph) {
+        let char_shapes = &self.doc_info.id_mappings.char_shapes;
+
+        let mut ranges: Vec<(Range<u32>, Option<u32>)> = paragraph
+            .char_shape_ranges()
+            .into_iter()
+            .map(|(range, shape_id)| (range, Some(shape_id)))
+            .collect();
+        if ranges.is_empty() {
+            ranges.push((0..u32::MAX, None));
+        }
+
+        let mut inline = String::new();
+        let mut blocks = String::new();
+        for (range, shape_id) in ranges {
+            let properties = shape_id
+                .and_then(|shape_id| char_shapes.get(shape_id as usize))
+                .map(|shape| run_properties(self.doc_info, shape))
+                .unwrap_or_default();
+
+            let mut raw = String::new();
+            let options = &self.options.text;
+            paragraph.write_text_range(&mut raw, options, range, |raw, control| {
+                inline.push_str(&text_run(&properties, &std::mem::take(raw)));
+                self.write_control(&mut inline, &mut blocks, control, &properties);
+            });
+            inline.push_str(&text_run(&properties, &raw));
+        }
+
+        // NOTE: 표 등 블록으로 나타내는 개체는 문단 안의 위치와 관계없이 문단 뒤에 넣는다
+        if !inline.is_empty() || blocks.is_empty() {
+            out.push_str(&format!(
+                "<w:p><w:pPr>{}</w:pPr>{}</w:p>",
+                self.paragraph_properties(paragraph),
+                inline
+            ));
+        }
+        out.push_str(&blocks);
+    }
+
+    fn paragraph_properties(&self, paragraph: &Paragraph) -> String {
+        let id_mappings = &self.doc_info.id_mappings;
+
+        let mut xml = String::new();
+        let style_id = paragraph.header.style_id as usize;
+        if matches!(id_mappings.styles.get(style_id), Some(style) if style.kind == StyleKind::Para)
+        {
+            xml.push_str(&format!("<w:pStyle w:val=\"s{}\"/>", style_id));
+        }
+
+        let shape = id_mappings
+            .paragraph_shapes
+            .get(paragraph.header.paragraph_shape_id as usize);
+        if let Some(shape) = shape {
+            // 번호 정의는 번호 문단, 글머리표 문단 순서로 이어 붙인다
+            let numbering_id = match shape.heading_kind {
+                ParagraphHeadingKind::Outline => self.outline_numbering_id,
+                ParagraphHeadingKind::Number => Some(shape.numbering_bullet_id),
+                ParagraphHeadingKind::Bullet => Some(shape.numbering_bullet_id)
+                    .filter(|id| *id > 0)
+                    .map(|id| id + id_mappings.numberings.len() as u16),
+                ParagraphHeadingKind::None => None,
+            };
+            let numbering = numbering_id
+                .filter(|id| *id > 0)
+                .map(|id| (id, shape.heading_level.min(8)));
+
+            xml.push_str(&paragraph_shape_properties(shape, numbering));
+        }
+
+        xml
+    }
+
+    /// `char_properties`는 컨트롤이 놓인 글자의 `w:rPr`이다
+    fn write_control(
+        &mut self,
+        inline: &mut String,
+        blocks: &mut String,
+        control: &Control,
+        char_properties: &str,
+    ) {
+        match control {
+            Control::AutoNumber(auto_number) if self.options.text.controls => {
+                inline.push_str(&text_run(char_properties, &auto_number.to_string()));
+            }
+            Control::Bookmark(bookmark) => {
+                // 워드의 책갈피 이름은 공백 없이 40자까지 쓸 수 있다
+                let name: String = bookmark
+                    .name
+                    .chars()
+                    .map(|c| if c.is_whitespace() { '_' } else { c })
+                    .take(40)
+                    .collect();
+                self.bookmark_id += 1;
+                inline.push_str(&format!(
+                    "<w:bookmarkStart w:id=\"{0}\" w:name=\"{1}\"/><w:bookmarkEnd w:id=\"{0}\"/>",
+                    self.bookmark_id,
+                    escape(&name)
+                ));
+            }
+            Control::Footnote(note) | Control::Endnote(note) if self.options.text.notes => {
+                let kind = match control {
+                    Control::Footnote(_) => "footnote",
+                    _ => "endnote",
+                };
+
+                let mut xml = String::new();
+                self.write_paragraphs(&mut xml, &note.paragraph_list.paragraphs);
+
+                // 주석 내용의 첫 문단 앞에 주석 번호를 넣는다
+                let reference = format!(
+                    "<w:r><w:rPr><w:vertAlign w:val=\"superscript\"/></w:rPr><w:{}Ref/></w:r>",
+                    kind
+                );
+                match xml.find("</w:pPr>") {
+                    Some(index) if xml.starts_with("<w:p>") => {
+                        xml.insert_str(index + "</w:pPr>".len(), &reference)
+                    }
+                    _ => xml.insert_str(0, &format!("<w:p>{}</w:p>", reference)),
+                }
+
+                let notes = match control {
+                    Control::Footnote(_) => &mut self.footnotes,
+                    _ => &mut self.endnotes,
+                };
+                notes.push(xml);
+                inline.push_str(&format!(
+                    "<w:r><w:rPr><w:vertAlign w:val=\"superscript\"/></w:rPr><w:{}Reference w:id=\"{}\"/></w:r>",
+                    kind,
+                    notes.len()
+                ));
+            }
+            Control::Header(header_footer) | Control::Footer(header_footer)
+                if self.options.text.headers_footers =>
+            {
+                let kind = match control {
+                    Control::Header(_) => "header",
+                    _ => "footer",
+                };
+
+                let mut xml = String::new();
+                self.write_paragraphs(&mut xml, &header_footer.paragraph_list.paragraphs);
+                if xml.is_empty() {
+                    xml.push_str("<w:p/>");
+                }
+
+                // NOTE: 워드는 구역마다 종류별로 하나의 기본 머리말/꼬리말만 가질 수 있어
+                // 구역의 첫 머리말/꼬리말만 사용한다
+                if self.section_headers.iter().any(|(k, _)| *k == kind) {
+                    return;
+                }
+                let count = self
+                    .headers_footers
+                    .iter()
+                    .filter(|(name, _)| name.starts_with(kind))
+                    .count();
+                self.headers_footers
+                    .push((format!("{}{}.xml", kind, count + 1), xml));
+                self.section_headers
+                    .push((kind, format!("hf{}", self.headers_footers.len())));
+            }
+            _ => {
+                let properties = control.common_properties();
+                let caption = properties.and_then(|properties| properties.caption.as_ref());
+
+                self.write_caption(blocks, caption, true);
+                match control {
+                    Control::Table(table) => self.write_table(blocks, table),
+                    Control::Equation(equation) => {
+                        // NOTE: 한/글 수식 스크립트는 OMML로 바꾸지 않고 글자로 넣는다
+                        let script = equation.record.script.split_whitespace();
+                        inline.push_str(&text_run(
+                            char_properties,
+                            &script.collect::<Vec<_>>().join(" "),
+                        ));
+                    }
+                    _ => {}
+                }
+
+                let pictures = control.pictures();
+                for picture in &pictures {
+                    // 묶음 개체가 아니라면 개체의 크기로 나타낸다
+                    let size = match properties {
+                        Some(properties) if pictures.len() == 1 => {
+                            (properties.width as i32, properties.height as i32)
+                        }
+                        _ => (
+                            picture.rect.right_bottom.x - picture.rect.left_top.x,
+                            picture.rect.right_bottom.y - picture.rect.left_top.y,
+                        ),
+                    };
+                    inline.push_str(&self.drawing(picture, size));
+                }
+
+                for draw_text in control.draw_texts() {
+                    self.write_paragraphs(blocks, &draw_text.paragraph_list.paragraphs);
+                }
+                self.write_caption(blocks, caption, false);
+            }
+        }
+    }
+
+    /// 위쪽 캡션은 개체 앞에, 나머지 캡션은 개체 뒤에 넣는다
+    fn write_caption(&mut self, blocks: &mut String, caption: Option<&Caption>, before: bool) {
+        if let Some(caption) = caption {
+            if matches!(caption.align, CaptionAlign::Top) == before {
+                self.write_paragraphs(blocks, &caption.paragraph_list.paragraphs);
+            }
+        }
+    }
+
+    fn write_table(&mut self, blocks: &mut String, table: &TableControl) {
+        let columns: Vec<i32> = table
+            .column_widths()
+            .iter()
+            .map(|width| to_twip(*width as i32))
+            .collect();
+
+        blocks.push_str(&format!(
+            "<w:tbl><w:tblPr><w:tblW w:w=\"{}\" w:type=\"dxa\"/><w:tblLayout w:type=\"fixed\"/></w:tblPr><w:tblGrid>",
+            columns.iter().sum::<i32>()
+        ));
+        for width in &columns {
+            blocks.push_str(&format!("<w:gridCol w:w=\"{}\"/>", width));
+        }
+        blocks.push_str("</w:tblGrid>");
+
+        for row in 0..table.record.rows {
+            let height = table
+                .cells
+                .iter()
+                .filter(|cell| cell.row == row && cell.row_span == 1)
+                .map(|cell| cell.height)
+                .max();
+
+            blocks.push_str("<w:tr>");
+            if let Some(height) = height {
+                blocks.push_str(&format!(
+                    "<w:trPr><w:trHeight w:val=\"{}\" w:hRule=\"atLeast\"/></w:trPr>",
+                    to_twip(height as i32)
+                ));
+            }
+
+            let mut column = 0;
+            while column < table.record.cols {
+                let cell = table.cells.iter().find(|cell| {
+                    cell.column == column && cell.row <= row && row < cell.row + cell.row_span
+                });
+
+                // NOTE: 워드는 세로로 병합된 셀도 행마다 칸을 두고 vMerge로 잇는다
+                match cell {
+                    Some(cell) => {
+                        let merge = match (cell.row_span > 1, cell.row == row) {
+                            (true, true) => "<w:vMerge w:val=\"restart\"/>",
+                            (true, false) => "<w:vMerge/>",
+                            _ => "",
+                        };
+                        blocks.push_str(&format!(
+                            "<w:tc><w:tcPr>{}</w:tcPr>",
+                            self.cell_properties(cell, &columns, merge)
+                        ));
+                        if cell.row == row {
+                            self.write_cell_content(blocks, cell);
+                        } else {
+                            blocks.push_str("<w:p/>");
+                        }
+                        blocks.push_str("</w:tc>");
+                        column += cell.col_span.max(1);
+                    }
+                    None => {
+                        let width = columns.get(column as usize).copied().unwrap_or_default();
+                        blocks.push_str(&format!(
+                            "<w:tc><w:tcPr><w:tcW w:w=\"{}\" w:type=\"dxa\"/></w:tcPr><w:p/></w:tc>",
+                            width
+                        ));
+                        column += 1;
+                    }
+                }
+            }
+            blocks.push_str("</w:tr>");
+        }
+
+        blocks.push_str("</w:tbl>");
+    }
+
+    /// 셀은 문단으로 끝나야 한다
+    fn write_cell_content(&mut self, blocks: &mut String, cell: &Cell) {
+        let mut xml = String::new();
+        self.write_paragraphs(&mut xml, &cell.paragraph_list.paragraphs);
+        if !xml.ends_with("</w:p>") {
+            xml.push_str("<w:p/>");
+        }
+        blocks.push_str(&xml);
+    }
+
+    /// 셀의 `w:tcPr` 내용. 세로 병합(`w:vMerge`)은 칸 합치기 뒤, 테두리 앞에 넣는다
+    fn cell_properties(&self, cell: &Cell, columns: &[i32], merge: &str) -> String {
+        let start = cell.column as usize;
+        let end = (start + cell.col_span.max(1) as usize).min(columns.len());
+        let width: i32 = columns.get(start..end).unwrap_or_default().iter().sum();
+
+        let mut xml = format!("<w:tcW w:w=\"{}\" w:type=\"dxa\"/>", width);
+        if cell.col_span > 1 {
+            xml.push_str(&format!("<w:gridSpan w:val=\"{}\"/>", cell.col_span));
+        }
+
+        xml.push_str(merge);
+
+        let border_fill = self
+            .doc_info
+            .id_mappings
+            .border_fills
+            .get(cell.border_fill_id as usize);
+        if let Some(border_fill) = border_fill {
+            xml.push_str(&border_fill_properties(border_fill, "tcBorders"));
+        }
+        xml.push_str(&format!(
+            "<w:tcMar><w:top w:w=\"{}\" w:type=\"dxa\"/><w:left w:w=\"{}\" w:type=\"dxa\"/><w:bottom w:w=\"{}\" w:type=\"dxa\"/><w:right w:w=\"{}\" w:type=\"dxa\"/></w:tcMar>",
+            to_twip(cell.padding[2] as i32),
+            to_twip(cell.padding[0] as i32),
+            to_twip(cell.padding[3] as i32),
+            to_twip(cell.padding[1] as i32),
+        ));
+
+        xml
+    }
+
+    /// 본문과 같은 줄에 놓이는 그림
+    fn drawing(&mut self, picture: &PictureRecord, size: (i32, i32)) -> String {
+        let id = picture.image.bin_item_id;
+        let item = self
+            .doc_info
+            .id_mappings
+            .binary_data
+            .iter()
+            .find(|item| item.id == Some(id));
+        let name = match item.and_then(|item| item.cfb_file_name()) {
+            Some(name) => name,
+            None => return String::new(),
+        };
+        let extension = name.rsplit('.').next().unwrap_or_default();
+        if image_content_type(extension).is_none() {
+            return String::new();
+        }
+
+        if !self.images.contains(&id) {
+            self.images.push(id);
+        }
+        self.drawing_id += 1;
+
+        // 크기가 없다면 1인치로 나타낸다
+        let (width, height) = (to_emu(size.0.max(0)), to_emu(size.1.max(0)));
+        let (width, height) = (
+            if width > 0 { width } else { 914400 },
+            if height > 0 { height } else { 914400 },
+        );
+
+        format!(
+            concat!(
+                "<w:r><w:drawing><wp:inline distT=\"0\" distB=\"0\" distL=\"0\" distR=\"0\">",
+                "<wp:extent cx=\"{1}\" cy=\"{2}\"/><wp:docPr id=\"{0}\" name=\"{3}\"/>",
+                "<a:graphic><a:graphicData uri=\"http://schemas.openxmlformats.org/drawingml/2006/picture\">",
+                "<pic:pic><pic:nvPicPr><pic:cNvPr id=\"{0}\" name=\"{3}\"/><pic:cNvPicPr/></pic:nvPicPr>",
+                "<pic:blipFill><a:blip r:embed=\"image{4}\"/><a:stretch><a:fillRect/></a:stretch></pic:blipFill>",
+                "<pic:spPr><a:xfrm><a:off x=\"0\" y=\"0\"/><a:ext cx=\"{1}\" cy=\"{2}\"/></a:xfrm>",
+                "<a:prstGeom prst=\"rect\"><a:avLst/></a:prstGeom></pic:spPr></pic:pic>",
+                "</a:graphicData></a:graphic></wp:inline></w:drawing></w:r>"
+            ),
+            self.drawing_id,
+            width,
+            height,
+            name,
+            id
+        )
+    }
+}
+
+/// 문단 모양을 `w:pPr`의 내용으로 바꾼다. `w:pStyle`은 포함하지 않는다
+fn paragraph_shape_properties(shape: &ParagraphShape, numbering: Option<(u16, u8)>) -> String {
+    let mut xml = String::new();
+
+    if shape.keep_with_next {
+        xml.push_str("<w:keepNext/>");
+    }
+    if shape.keep_lines {
+        xml.push_str("<w:keepLines/>");
+    }
+    if shape.page_break_before {
+        xml.push_str("<w:pageBreakBefore/>");
+    }
+    xml.push_str(&format!(
+        "<w:widowControl w:val=\"{}\"/>",
+        shape.widow_orphan as u8
+    ));
+    if let Some((id, level)) = numbering {
+        xml.push_str(&format!(
+            "<w:numPr><w:ilvl w:val=\"{}\"/><w:numId w:val=\"{}\"/></w:numPr>",
+            level, id
+        ));
+    }
+
+    // NOTE: 문단 여백, 간격과 들여 쓰기는 HWPUNIT의 두 배로 기록되어 있다
+    let kind = shape
+        .line_spacing_kind
+        .as_ref()
+        .unwrap_or(&shape.line_space_kind_old);
+    let line_spacing = shape
+        .line_spacing
+        .map_or(shape.line_space_old, |line_spacing| line_spacing as i32);
+    let line = match kind {
+        LineSpacingKind::Percent => {
+            format!(
+                " w:line=\"{}\" w:lineRule=\"auto\"",
+                line_spacing * 240 / 100
+            )
+        }
+        LineSpacingKind::Fixed => {
+            format!(" w:line=\"{}\" w:lineRule=\"exact\"", to_twip(line_spacing))
+        }
+        LineSpacingKind::AtLeast => format!(
+            " w:line=\"{}\" w:lineRule=\"atLeast\"",
+            to_twip(line_spacing)
+        ),
+        LineSpacingKind::BetweenLine => String::new(),
+    };
+    xml.push_str(&format!(
+        "<w:spacing w:before=\"{}\" w:after=\"{}\"{}/>",
+        to_twip(shape.margin_top / 2).max(0),
+        to_twip(shape.margin_bottom / 2).max(0),
+        line
+    ));
+
+    // 내어 쓰기는 첫 줄을 뺀 나머지 줄을 들여 쓴다
+    let indent = to_twip(shape.indent / 2);
+    let mut left = to_twip(shape.padding_left / 2);
+    let first_line = if indent < 0 {
+        left -= indent;
+        format!(" w:hanging=\"{}\"", -indent)
+    } else {
+        format!(" w:firstLine=\"{}\"", indent)
+    };
+    xml.push_str(&format!(
+        "<w:ind w:left=\"{}\" w:right=\"{}\"{}/>",
+        left,
+        to_twip(shape.padding_right / 2),
+        first_line
+    ));
+
+    xml.push_str(&format!(
+        "<w:jc w:val=\"{}\"/>",
+        match shape.align {
+            Align::Left => "left",
+            Align::Right => "right",
+            Align::Center => "center",
+            Align::Justify => "both",
+            Align::Distributive | Align::DistributiveSpace => "distribute",
+        }
+    ));
+
+    if shape.heading_kind == ParagraphHeadingKind::Outline {
+        xml.push_str(&format!(
+            "<w:outlineLvl w:val=\"{}\"/>",
+            shape.heading_level.min(8)
+        ));
+    }
+
+    xml
+}
+
+/// 글자 모양을 `w:rPr`로 바꾼다
+fn run_properties(doc_info: &DocInfo, shape: &CharShape) -> String {
+    let id_mappings = &doc_info.id_mappings;
+
+    let mut xml = String::from("<w:rPr>");
+
+    // 라틴 문자는 영문 글꼴, 동아시아 문자는 한글 글꼴로 나타낸다
+    let english = id_mappings.english_fonts.get(shape.font_ids[1] as usize);
+    let korean = id_mappings.korean_fonts.get(shape.font_ids[0] as usize);
+    let mut fonts = String::new();
+    if let Some(font) = english {
+        fonts.push_str(&format!(
+            " w:ascii=\"{0}\" w:hAnsi=\"{0}\"",
+            escape(&font.name)
+        ));
+    }
+    if let Some(font) = korean {
+        fonts.push_str(&format!(" w:eastAsia=\"{}\"", escape(&font.name)));
+    }
+    if !fonts.is_empty() {
+        xml.push_str(&format!("<w:rFonts{}/>", fonts));
+    }
+
+    if shape.bold {
+        xml.push_str("<w:b/>");
+    }
+    if shape.italic {
+        xml.push_str("<w:i/>");
+    }
+    if shape.strike {
+        xml.push_str("<w:strike/>");
+    }
+    xml.push_str(&format!(
+        "<w:color w:val=\"{}\"/>",
+        hex(&shape.color.to_hex())
+    ));
+
+    // 기준 크기에 한글의 상대 크기를 적용한다
+    let size = shape.base_size * shape.font_sizes[0] as i32 / 100;
+    if shape.font_spacings[0] != 0 {
+        // 자간은 글자 크기에 대한 백분율이다
+        xml.push_str(&format!(
+            "<w:spacing w:val=\"{}\"/>",
+            size * 20 * shape.font_spacings[0] as i32 / 100 / 100
+        ));
+    }
+    if shape.font_scales[0] != 100 {
+        xml.push_str(&format!("<w:w w:val=\"{}\"/>", shape.font_scales[0]));
+    }
+    xml.push_str(&format!(
+        "<w:sz w:val=\"{0}\"/><w:szCs w:val=\"{0}\"/>",
+        (size / 50).max(1)
+    ));
+
+    // NOTE: 워드는 윗줄을 나타낼 수 없어 밑줄만 옮긴다
+    if shape.underline_kind == UnderlineKind::Bottom {
+        xml.push_str(&format!(
+            "<w:u w:val=\"{}\" w:color=\"{}\"/>",
+            underline(&shape.underline_shape),
+            hex(&shape.underline_color.to_hex())
+        ));
+    }
+    if shape.shade_color.to_hex() != "#FFFFFF" {
+        xml.push_str(&format!(
+            "<w:shd w:val=\"clear\" w:color=\"auto\" w:fill=\"{}\"/>",
+            hex(&shape.shade_color.to_hex())
+        ));
+    }
+
+    if shape.supscript {
+        xml.push_str("<w:vertAlign w:val=\"superscript\"/>");
+    } else if shape.subscript {
+        xml.push_str("<w:vertAlign w:val=\"subscript\"/>");
+    }
+
+    xml.push_str("</w:rPr>");
+
+    xml
+}
+
+/// 테두리/배경을 셀의 `w:tcBorders`와 `w:shd`로 바꾼다
+fn border_fill_properties(border_fill: &BorderFill, element: &str) -> String {
+    let mut xml = format!("<w:{}>", element);
+
+    // NOTE: 테두리는 왼쪽, 오른쪽, 위, 아래 순서로 기록되어 있지만 워드는 위, 왼쪽, 아래, 오른쪽 순서로 써야 한다
+    for (side, index) in [("top", 2), ("left", 0), ("bottom", 3), ("right", 1)] {
+        let border = match border_fill.borders.get(index) {
+            Some(border) => border,
+            None => continue,
+        };
+        let kind = border_style(&border.kind);
+        if kind == "nil" {
+            xml.push_str(&format!("<w:{} w:val=\"nil\"/>", side));
+        } else {
+            // 선 굵기는 1/8pt 단위이다
+            let size = (border_width(border.width) * 72.0 / 25.4 * 8.0).round() as i32;
+            xml.push_str(&format!(
+                "<w:{} w:val=\"{}\" w:sz=\"{}\" w:space=\"0\" w:color=\"{}\"/>",
+                side,
+                kind,
+                size.clamp(2, 96),
+                hex(&border.color.to_hex())
+            ));
+        }
+    }
+    xml.push_str(&format!("</w:{}>", element));
+
+    // NOTE: 워드는 그러데이션 셀 배경을 나타낼 수 없어 첫 색으로 채운다
+    let fill = match &border_fill.fill.content {
+        FillContent::Color(color) => Some(color.background_color.to_hex()),
+        FillContent::Gradation(gradation) => gradation.colors.first().map(|c| c.to_hex()),
+        _ => None,
+    };
+    if let Some(fill) = fill {
+        xml.push_str(&format!(
+            "<w:shd w:val=\"clear\" w:color=\"auto\" w:fill=\"{}\"/>",
+            hex(&fill)
+        ));
+    }
+
+    xml
+}
+
+/// 문서의 스타일
+fn styles(doc_info: &DocInfo) -> String {
+    let id_mappings = &doc_info.id_mappings;
+
+    let mut xml = format!("<w:styles{}>", NAMESPACES);
+    xml.push_str("<w:docDefaults><w:rPrDefault><w:rPr><w:sz w:val=\"20\"/><w:lang w:eastAsia=\"ko-KR\"/></w:rPr></w:rPrDefault></w:docDefaults>");
+
+    let mut has_default = false;
+    for (id, style) in id_mappings.styles.iter().enumerate() {
+        let paragraph_style = style.kind == StyleKind::Para;
+        let default = paragraph_style && !has_default;
+        has_default |= default;
+
+        xml.push_str(&format!(
+            "<w:style w:type=\"{}\" w:styleId=\"s{}\"{}><w:name w:val=\"{}\"/>",
+            if paragraph_style {
+                "paragraph"
+            } else {
+                "character"
+            },
+            id,
+            if default { " w:default=\"1\"" } else { "" },
+            escape(&style.name)
+        ));
+        if paragraph_style
+            && id_mappings
+                .styles
+                .get(style.next_style_id as usize)
+                .is_some()
+        {
+            xml.push_str(&format!("<w:next w:val=\"s{}\"/>", style.next_style_id));
+        }
+        xml.push_str("<w:qFormat/>");
+
+        if paragraph_style {
+            if let Some(shape) = id_mappings
+                .paragraph_shapes
+                .get(style.paragraph_shape_id as usize)
+            {
+                xml.push_str(&format!(
+                    "<w:pPr>{}</w:pPr>",
+                    paragraph_shape_properties(shape, None)
+                ));
+            }
+        }
+        if let Some(shape) = id_mappings.char_shapes.get(style.char_shape_id as usize) {
+            xml.push_str(&run_properties(doc_info, shape));
+        }
+        xml.push_str("</w:style>");
+    }
+    xml.push_str("</w:styles>");
+
+    xml
+}
+
+/// 번호 문단과 글머리표 문단의 번호 정의
+///
+/// 번호 정의의 ID는 문단 번호 ID와 같고, 글머리표는 문단 번호 뒤에 이어서 매긴다
+fn numbering(doc_info: &DocInfo) -> String {
+    let id_mappings = &doc_info.id_mappings;
+
+    let mut xml = format!("<w:numbering{}>", NAMESPACES);
+    let mut count = 0;
+    for numbering in &id_mappings.numberings {
+        count += 1;
+        xml.push_str(&format!("<w:abstractNum w:abstractNumId=\"{}\">", count));
+        // 워드는 9 수준까지만 쓸 수 있다
+        for (level, head) in numbering.paragraph_heads.iter().take(9).enumerate() {
+            let start = head.start_number.unwrap_or(numbering.start as u32).max(1);
+            let text = number_format(&head.number_format);
+            xml.push_str(&format!(
+                "<w:lvl w:ilvl=\"{}\"><w:start w:val=\"{}\"/><w:numFmt w:val=\"{}\"/><w:lvlText w:val=\"{}\"/><w:lvlJc w:val=\"{}\"/></w:lvl>",
+                level,
+                start,
+                if text.is_empty() { "none" } else { "decimal" },
+                escape(&text),
+                head_align(head)
+            ));
+        }
+        xml.push_str("</w:abstractNum>");
+    }
+    for bullet in &id_mappings.bullets {
+        count += 1;
+        xml.push_str(&format!("<w:abstractNum w:abstractNumId=\"{}\">", count));
+
+        // NOTE: 기호 글꼴의 글머리표는 사용자 영역(PUA) 문자로 기록되어 있다
+        let fonts = if ('\u{F000}'..='\u{F0FF}').contains(&bullet.bullet_char) {
+            "<w:rPr><w:rFonts w:ascii=\"Wingdings\" w:hAnsi=\"Wingdings\"/></w:rPr>"
+        } else {
+            ""
+        };
+        for level in 0..9 {
+            xml.push_str(&format!(
+                "<w:lvl w:ilvl=\"{}\"><w:start w:val=\"1\"/><w:numFmt w:val=\"bullet\"/><w:lvlText w:val=\"{}\"/><w:lvlJc w:val=\"{}\"/>{}</w:lvl>",
+                level,
+                escape(&bullet.bullet_char.to_string()),
+                head_align(&bullet.paragraph_head),
+                fonts
+            ));
+        }
+        xml.push_str("</w:abstractNum>");
+    }
+    for id in 1..=count {
+        xml.push_str(&format!(
+            "<w:num w:numId=\"{0}\"><w:abstractNumId w:val=\"{0}\"/></w:num>",
+            id
+        ));
+    }
+    xml.push_str("</w:numbering>");
+
+    xml
+}
+
+/// 한/글의 번호 형식(`^1.`)을 워드의 번호 형식(`%1.`)으로 바꾼다
+///
+/// NOTE: 수준별 번호 모양(가, ①, i 등)은 해석하지 않아 모두 아라비아 숫자로 나타낸다
+fn number_format(format: &str) -> String {
+    let mut out = String::new();
+    let mut chars = format.chars().peekable();
+    while let Some(c) = chars.next() {
+        match (c, chars.peek()) {
+            ('^', Some(level)) if level.is_ascii_digit() => {
+                out.push('%');
+                out.push(chars.next().unwrap());
+            }
+            ('%', _) => out.push_str("%%"),
+            _ => out.push(c),
+        }
+    }
+
+    out
+}
+
+fn head_align(head: &ParagraphHead) -> &'static str {
+    match head.align {
+        ParagraphHeadAlign::Left => "left",
+        ParagraphHeadAlign::Center => "center",
+        ParagraphHeadAlign::Right => "right",
+    }
+}
+
+fn core_properties(summary_info: Option<&SummaryInfo>) -> String {
+    let mut xml = String::from(concat!(
+        "<cp:coreProperties",
+        " xmlns:cp=\"http://schemas.openxmlformats.org/package/2006/metadata/core-properties\"",
+        " xmlns:dc=\"http://purl.org/dc/elements/1.1/\">",
+    ));
+
+    if let Some(summary_info) = summary_info {
+        for (element, value) in [
+            ("dc:title", summary_info.title()),
+            ("dc:subject", summary_info.subject()),
+            ("dc:creator", summary_info.author()),
+            ("cp:keywords", summary_info.keywords()),
+            ("dc:description", summary_info.comments()),
+            ("cp:lastModifiedBy", summary_info.last_saved_by()),
+        ] {
+            if let Some(value) = value.filter(|value| !value.is_empty()) {
+                xml.push_str(&format!("<{0}>{1}</{0}>", element, escape(value)));
+            }
+        }
+    }
+    xml.push_str("</cp:coreProperties>");
+
+    xml
+}
+
+/// 글자를 `w:r`로 바꾼다. 탭과 줄 바꿈은 워드의 탭과 줄 바꿈으로 바꾼다
+fn text_run(properties: &str, text: &str) -> String {
+    if text.is_empty() {
+        return String::new();
+    }
+
+    let mut xml = format!("<w:r>{}", properties);
+    let mut segment = String::new();
+    for c in text.chars() {
+        let element = match c {
+            '\t' => "<w:tab/>",
+            '\n' => "<w:br/>",
+            _ => {
+                segment.push(c);
+                continue;
+            }
+        };
+        if !segment.is_empty() {
+            xml.push_str(&format!(
+                "<w:t xml:space=\"preserve\">{}</w:t>",
+                escape(&std::mem::take(&mut segment))
+            ));
+        }
+        xml.push_str(element);
+    }
+    if !segment.is_empty() {
+        xml.push_str(&format!(
+            "<w:t xml:space=\"preserve\">{}</w:t>",
+            escape(&segment)
+        ));
+    }
+    xml.push_str("</w:r>");
+
+    xml
+}
+
+fn zip_xml(zip: &mut ZipWriter, name: &str, xml: &str) {
+    zip.add_file(name, format!("{}{}", XML_DECLARATION, xml).as_bytes(), true);
+}
+
+fn zip_relationships(zip: &mut ZipWriter, name: &str, relationships: &[(String, &str, String)]) {
+    let mut xml = String::from(
+        "<Relationships xmlns=\"http://schemas.openxmlformats.org/package/2006/relationships\">",
+    );
+    for (id, kind, target) in relationships {
+        xml.push_str(&format!(
+            "<Relationship Id=\"{}\" Type=\"{}/{}\" Target=\"{}\"/>",
+            id,
+            RELATIONSHIP,
+            kind,
+            escape(target)
+        ));
+    }
+    xml.push_str("</Relationships>");
+
+    zip_xml(zip, name, &xml);
+}
+
+/// 워드에서 쓸 수 있는 그림의 MIME 형식
+fn image_content_type(extension: &str) -> Option<&'static str> {
+    match extension {
+        "png" => Some("image/png"),
+        "jpg" | "jpeg" => Some("image/jpeg"),
+        "gif" => Some("image/gif"),
+        "bmp" => Some("image/bmp"),
+        "tif" | "tiff" => Some("image/tiff"),
+        "wmf" => Some("image/x-wmf"),
+        "emf" => Some("image/x-emf"),
+        _ => None,
+    }
+}
+
+/// 워드 선 종류
+fn border_style(kind: &BorderKind) -> &'static str {
+    match LineStyle::from_border(kind) {
+        LineStyle::None => "nil",
+        LineStyle::Solid => "single",
+        LineStyle::Dash => "dashed",
+        LineStyle::Dot => "dotted",
+        LineStyle::DashDot => "dotDash",
+        LineStyle::DashDotDot => "dotDotDash",
+        LineStyle::Double => "double",
+        LineStyle::ThinThick => "thinThickSmallGap",
+        LineStyle::ThickThin => "thickThinSmallGap",
+        LineStyle::ThinThickThin => "thinThickThinSmallGap",
+        LineStyle::Wave => "wave",
+        LineStyle::DoubleWave => "doubleWave",
+        LineStyle::Emboss => "threeDEmboss",
+        LineStyle::Engrave => "threeDEngrave",
+    }
+}
+
+/// 워드 밑줄 모양
+fn underline(kind: &BorderKind) -> &'static str {
+    match kind {
+        BorderKind::Dash => "dash",
+        BorderKind::Dot | BorderKind::Circle => "dotted",
+        BorderKind::DashDot => "dotDash",
+        BorderKind::DashDotDot => "dotDotDash",
+        BorderKind::LongDash => "dashLong",
+        BorderKind::DoubleSlim
+        | BorderKind::SlimThick
+        | BorderKind::TickSlim
+        | BorderKind::SlimTickSlim => "double",
+        BorderKind::Wave => "wave",
+        BorderKind::DoubleWave => "wavyDouble",
+        _ => "single",
+    }
+}
+
+/// HWPUNIT(1/7200 인치)을 twip(1/1440 인치)으로 바꾼다
+fn to_twip(value: i32) -> i32 {
+    value / 5
+}
+
+/// HWPUNIT(1/7200 인치)을 EMU(1/914400 인치)로 바꾼다
+fn to_emu(value: i32) -> i64 {
+    value as i64 * 127
+}
+
+/// `#RRGGBB`에서 `#`을 뗀다
+fn hex(color: &str) -> &str {
+    color.trim_start_matches('#')
+}
@@ -0,0 +1,45 @@
+use std::fmt;
+
+use super::options::Limit;
+
+#[derive(Debug)]
+pub enum Error {
+    /// 복합 파일(CFB)이 아니거나 파일 헤더가 올바르지 않다
+    InvalidFile,
+    /// 암호가 걸린 문서로 암호가 필요하다
+    ///
+    /// NOTE: 암호를 받아 복호화 하는 기능은 지원하지 않는다
+    PasswordRequired,
+    /// 압축이나 암호화를 풀 수 없거나 레코드가 잘린 스트림 (경로)
+    InvalidStream(String),
+    /// 이미 배포용 문서라 배포용 문서로 만들 수 없다
+    AlreadyDistributed,
+    /// 압축되지 않은 문서라 배포용 문서로 만들 수 없다
+    NotCompressed,
+    /// 읽기 제한을 넘는 문서
+    LimitExceeded { limit: Limit, path: String },
+    /// 올바르지 않은 사용자 영역 문자 변환표 (줄 번호)
+    InvalidPuaTable(usize),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidFile => write!(f, "올바른 한글 파일이 아닙니다"),
+            Error::PasswordRequired => write!(f, "암호가 걸린 문서 입니다"),
+            Error::InvalidStream(path) => write!(f, "올바르지 않은 스트림 입니다: {}", path),
+            Error::AlreadyDistributed => write!(f, "이미 배포용 문서 입니다"),
+            Error::NotCompressed => {
+                write!(f, "압축되지 않은 문서는 배포용 문서로 만들 수 없습니다")
+            }
+            Error::LimitExceeded { limit, path } => {
+                write!(f, "읽기 제한을 넘었습니다: {:?} ({})", limit, path)
+            }
+            Error::InvalidPuaTable(line) => {
+                write!(f, "올바르지 않은 변환표 입니다: {}번째 줄", line)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
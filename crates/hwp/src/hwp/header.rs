@@ -1,4 +1,4 @@
-use std::io::{Read, Seek};
+use std::io::{Cursor, Read, Seek};
 
 use byteorder::{LittleEndian, ReadBytesExt};
 use cfb::CompoundFile;
@@ -6,11 +6,13 @@ use num::FromPrimitive;
 use num_derive::FromPrimitive;
 
 use super::{
+    storage::Storage,
     utils::bits::{get_flag, get_value_range},
     version::Version,
 };
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Header {
     pub version: Version,
     pub flags: Flags,
@@ -18,45 +20,67 @@ pub struct Header {
     pub encrypt_version: EncryptVersion,
     pub kogl: KOGL,
     pub signature: [u8; 32],
+    #[cfg_attr(feature = "serde", serde(with = "crate::hwp::utils::base64"))]
     pub reserved: [u8; 207],
 }
 
 const SIGNATURE_STR: &str = "HWP Document File";
 
+/// 파일 헤더에서 속성이 시작하는 위치 (signature 32 + version 4)
+const FLAGS_OFFSET: usize = 36;
+
 impl Header {
     pub fn from_cfb<T: Read + Seek>(cfb: &mut CompoundFile<T>) -> Self {
-        let mut stream = cfb.open_stream("/FileHeader").unwrap();
+        Self::try_from_cfb(cfb).expect("올바르지 않은 파일 헤더 입니다")
+    }
 
-        assert_eq!(stream.len(), 256, "헤더 사이즈가 맞지 않습니다");
+    /// 파일 헤더를 읽는다. 헤더가 없거나 시그니처가 맞지 않다면 None을 반환한다
+    pub fn try_from_cfb<T: Read + Seek>(cfb: &mut CompoundFile<T>) -> Option<Self> {
+        let mut stream = cfb.open_stream("/FileHeader").ok()?;
+
+        if stream.len() != 256 {
+            return None;
+        }
+
+        let mut data = vec![];
+        stream.read_to_end(&mut data).ok()?;
+
+        Self::from_bytes(&data)
+    }
+
+    /// 파일 헤더(`/FileHeader`) 스트림을 읽는다. 길이나 시그니처가 맞지 않다면 None을 반환한다
+    pub fn from_bytes(data: &[u8]) -> Option<Self> {
+        if data.len() != 256 {
+            return None;
+        }
+
+        let mut stream = Cursor::new(data);
 
         let mut signature = [0; 32];
-        stream.read(&mut signature).unwrap();
+        stream.read_exact(&mut signature).ok()?;
 
-        assert_eq!(
-            String::from_utf8(signature[0..17].to_vec()).unwrap_or_default(),
-            SIGNATURE_STR,
-            "파일 시그니처가 맞지 않습니다"
-        );
+        if String::from_utf8(signature[0..17].to_vec()).unwrap_or_default() != SIGNATURE_STR {
+            return None;
+        }
 
         let mut version = [0; 4];
-        stream.read(&mut version).unwrap();
+        stream.read_exact(&mut version).ok()?;
         let version = Version::from_bytes(version);
 
         // Flags
-        let flags = stream.read_u32::<LittleEndian>().unwrap();
+        let flags = stream.read_u32::<LittleEndian>().ok()?;
         let flags = Flags::from_bits(flags);
 
-        let license = stream.read_u32::<LittleEndian>().unwrap();
+        let license = stream.read_u32::<LittleEndian>().ok()?;
         let license = License::from_bits(license);
 
-        let encrypt_version =
-            EncryptVersion::from_u32(stream.read_u32::<LittleEndian>().unwrap()).unwrap();
-        let kogl = KOGL::from_u8(stream.read_u8().unwrap()).unwrap();
+        let encrypt_version = EncryptVersion::from_u32(stream.read_u32::<LittleEndian>().ok()?)?;
+        let kogl = KOGL::from_u8(stream.read_u8().ok()?)?;
 
         let mut reserved: [u8; 207] = [0; 207];
-        stream.read(&mut reserved).unwrap();
+        stream.read_exact(&mut reserved).ok()?;
 
-        Self {
+        Some(Self {
             version,
             flags,
             license,
@@ -64,12 +88,13 @@ impl Header {
             kogl,
             signature,
             reserved,
-        }
+        })
     }
 }
 
 #[repr(u32)]
 #[derive(Debug, PartialEq, Eq, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum EncryptVersion {
     None,
     /// 한/글 2.5 버전 이하
@@ -79,18 +104,22 @@ pub enum EncryptVersion {
     /// 한/글 3.0 버전 Old
     HWP3Old,
     /// 한/글 7.0 버전 이후
+    ///
+    /// NOTE: 암호화 방식이 공개되지 않아 복호화 하지 않는다
     HWP7,
 }
 
 #[repr(u8)]
 #[derive(Debug, PartialEq, Eq, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum KOGL {
     None,
     KOR = 6,
     US = 15,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Flags {
     pub compressed: bool,
     pub encrypted: bool,
@@ -99,6 +128,9 @@ pub struct Flags {
     pub drm: bool,
     pub has_xml_template_storage: bool,
     pub vcs: bool,
+    /// 전자 서명 여부
+    ///
+    /// NOTE: 서명 저장소의 형식이 공개되지 않아 서명은 읽거나 검증하지 않는다
     pub has_electron_signature: bool,
     pub certificate_encryption: bool,
     pub prepare_signature: bool,
@@ -139,9 +171,62 @@ impl Flags {
             reserved,
         }
     }
+
+    fn to_bits(&self) -> u32 {
+        let flags = [
+            self.compressed,
+            self.encrypted,
+            self.distributed,
+            self.has_script,
+            self.drm,
+            self.has_xml_template_storage,
+            self.vcs,
+            self.has_electron_signature,
+            self.certificate_encryption,
+            self.prepare_signature,
+            self.certificate_drm,
+            self.ccl,
+            self.mobile_optimized,
+            self.is_privacy_security_document,
+            self.tracking_changes,
+            self.kogl,
+            self.has_video_control,
+            self.has_order_field_control,
+        ];
+
+        // NOTE: reserved는 14번째 비트부터 담고 있으므로 앞의 속성으로 덮어쓴다
+        let mut bits = self.reserved << 14;
+        for (position, flag) in flags.iter().enumerate() {
+            bits &= !(1 << position);
+            bits |= (*flag as u32) << position;
+        }
+
+        bits
+    }
+
+    /// 파일 헤더(`/FileHeader`) 스트림에서 속성을 읽는다. 길이가 모자라다면 None을 반환한다
+    pub(crate) fn from_file_header(data: &[u8]) -> Option<Self> {
+        let bits = data.get(FLAGS_OFFSET..FLAGS_OFFSET + 4)?;
+
+        Some(Self::from_bits(u32::from_le_bytes([
+            bits[0], bits[1], bits[2], bits[3],
+        ])))
+    }
+}
+
+/// 저장소의 파일 헤더(`/FileHeader`) 속성을 고친다
+pub(crate) fn update_flags<F: FnOnce(&mut Flags)>(storage: &mut Storage, f: F) {
+    let mut file_header = storage.get("/FileHeader").unwrap().to_vec();
+
+    let mut flags = Flags::from_file_header(&file_header).expect("올바르지 않은 파일 헤더 입니다");
+    f(&mut flags);
+    file_header[FLAGS_OFFSET..FLAGS_OFFSET + 4].copy_from_slice(&flags.to_bits().to_le_bytes());
+
+    storage.set("/FileHeader", file_header);
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct License {
     pub ccl: bool,
     pub replication_restrictions: bool,
@@ -0,0 +1,586 @@
+use std::ops::Range;
+
+use super::{
+    bin_data::File,
+    doc_info::{
+        border_fill::{BorderFill, BorderKind, FillContent, GradationKind},
+        char_shape::{CharShape, UnderlineKind},
+        paragraph_shape::{Align, LineSpacingKind, ParagraphShape},
+        DocInfo,
+    },
+    paragraph::{
+        control::{
+            common_properties::{Caption, CaptionAlign},
+            shape_object::picture::PictureRecord,
+            table::TableControl,
+            Control,
+        },
+        text_options::TextOptions,
+        Paragraph,
+    },
+    section::Section,
+    utils::{
+        base64,
+        xml::{border_width, escape, to_pt, LineStyle},
+    },
+};
+
+/// HTML로 바꾸는 방법
+#[derive(Debug, Clone)]
+pub struct HtmlOptions {
+    /// 문단의 글자를 바꾸는 방법. 강제 줄 나눔은 `<br>`로 바꾼다
+    ///
+    /// 기본값은 머리말/꼬리말을 넣는다
+    pub text: TextOptions,
+    /// 그림 파일 이름 앞에 붙일 경로. 없다면 그림을 data URI로 넣는다 (기본값 `None`)
+    pub image_path: Option<String>,
+}
+
+impl Default for HtmlOptions {
+    fn default() -> Self {
+        Self {
+            text: TextOptions {
+                headers_footers: true,
+                ..TextOptions::default()
+            },
+            image_path: None,
+        }
+    }
+}
+
+/// 구역들을 스타일을 포함한 하나의 HTML 문서로 바꾼다
+///
+/// 문단 모양, 글자 모양, 테두리/배경은 각각 `ps{ID}`, `cs{ID}`, `bf{ID}` 클래스로 나타낸다.
+pub(crate) fn sections_to_html(
+    sections: &[Section],
+    doc_info: &DocInfo,
+    bin_data: &[File],
+    title: Option<&str>,
+    options: &HtmlOptions,
+) -> String {
+    let mut writer = HtmlWriter {
+        doc_info,
+        bin_data,
+        options,
+        headers: vec![],
+        footers: vec![],
+        notes: vec![],
+    };
+
+    let mut body = String::new();
+    for section in sections {
+        body.push_str("<section>\n");
+        writer.write_paragraphs(&mut body, &section.paragraphs);
+        body.push_str("</section>\n");
+    }
+
+    let mut out = String::new();
+    out.push_str("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n");
+    out.push_str(&format!(
+        "<title>{}</title>\n",
+        escape(title.unwrap_or_default())
+    ));
+    out.push_str("<style>\n");
+    out.push_str(&style_sheet(doc_info));
+    out.push_str("</style>\n</head>\n<body>\n");
+
+    for header in &writer.headers {
+        out.push_str(&format!("<header>\n{}</header>\n", header));
+    }
+    out.push_str(&body);
+    if !writer.notes.is_empty() {
+        out.push_str("<ol class=\"notes\">\n");
+        for (index, note) in writer.notes.iter().enumerate() {
+            out.push_str(&format!("<li id=\"note{}\">\n{}</li>\n", index + 1, note));
+        }
+        out.push_str("</ol>\n");
+    }
+    for footer in &writer.footers {
+        out.push_str(&format!("<footer>\n{}</footer>\n", footer));
+    }
+
+    out.push_str("</body>\n</html>\n");
+
+    out
+}
+
+struct HtmlWriter<'a> {
+    doc_info: &'a DocInfo,
+    bin_data: &'a [File],
+    options: &'a HtmlOptions,
+    /// 문서 앞에 넣을 머리말
+    headers: Vec<String>,
+    /// 문서 뒤에 넣을 꼬리말
+    footers: Vec<String>,
+    /// 문서 끝에 모을 각주/미주
+    notes: Vec<String>,
+}
+
+impl HtmlWriter<'_> {
+    fn write_paragraphs(&mut self, out: &mut String, paragraphs: &[Paragraph]) {
+        for paragraph in paragraphs {
+            self.write_paragraph(out, paragraph);
+        }
+    }
+
+    fn write_paragraph(&mut self, out: &mut String, paragraph: &Paragraph) {
+        let mut ranges: Vec<(Range<u32>, Option<u32>)> = paragraph
+            .char_shape_ranges()
+            .into_iter()
+            .map(|(range, shape_id)| (range, Some(shape_id)))
+            .collect();
+        if ranges.is_empty() {
+            ranges.push((0..u32::MAX, None));
+        }
+
+        let mut inline = String::new();
+        let mut blocks = String::new();
+        for (range, shape_id) in ranges {
+            let mut raw = String::new();
+            let mut text = String::new();
+            let options = &self.options.text;
+            paragraph.write_text_range(&mut raw, options, range, |raw, control| {
+                text.push_str(&escape_text(&std::mem::take(raw)));
+                self.write_control(&mut text, &mut blocks, control);
+            });
+            text.push_str(&escape_text(&raw));
+
+            if text.is_empty() {
+                continue;
+            }
+            match shape_id {
+                Some(shape_id) => {
+                    inline.push_str(&format!("<span class=\"cs{}\">{}</span>", shape_id, text))
+                }
+                None => inline.push_str(&text),
+            }
+        }
+
+        // NOTE: 표 등 블록으로 나타내는 개체는 문단 안의 위치와 관계없이 문단 뒤에 넣는다
+        if !inline.is_empty() || blocks.is_empty() {
+            if inline.is_empty() {
+                inline.push_str("<br>");
+            }
+            out.push_str(&format!(
+                "<p class=\"ps{}\">{}</p>\n",
+                paragraph.header.paragraph_shape_id, inline
+            ));
+        }
+        out.push_str(&blocks);
+    }
+
+    fn write_control(&mut self, text: &mut String, blocks: &mut String, control: &Control) {
+        match control {
+            Control::AutoNumber(auto_number) if self.options.text.controls => {
+                text.push_str(&escape(&auto_number.to_string()));
+            }
+            Control::Footnote(note) | Control::Endnote(note) if self.options.text.notes => {
+                let index = self.notes.len();
+                self.notes.push(String::new());
+
+                let mut note_html = String::new();
+                self.write_paragraphs(&mut note_html, &note.paragraph_list.paragraphs);
+                self.notes[index] = note_html;
+
+                text.push_str(&format!(
+                    "<sup><a href=\"#note{0}\">{0}</a></sup>",
+                    index + 1
+                ));
+            }
+            Control::Header(header_footer) | Control::Footer(header_footer)
+                if self.options.text.headers_footers =>
+            {
+                let mut html = String::new();
+                self.write_paragraphs(&mut html, &header_footer.paragraph_list.paragraphs);
+
+                let list = match control {
+                    Control::Header(_) => &mut self.headers,
+                    _ => &mut self.footers,
+                };
+                // 양쪽/홀수/짝수 쪽에 같은 내용이 있다면 한번만 넣는다
+                if !list.contains(&html) {
+                    list.push(html);
+                }
+            }
+            _ => {
+                let properties = control.common_properties();
+                let caption = properties.and_then(|properties| properties.caption.as_ref());
+
+                self.write_caption(blocks, caption, true);
+                match control {
+                    Control::Table(table) => self.write_table(blocks, table),
+                    Control::Equation(equation) => {
+                        // NOTE: 한/글 수식 스크립트는 MathML 등으로 바꾸지 않고 그대로 넣는다
+                        let script = equation.record.script.split_whitespace();
+                        text.push_str(&format!(
+                            "<span class=\"equation\">{}</span>",
+                            escape(&script.collect::<Vec<_>>().join(" "))
+                        ));
+                    }
+                    _ => {}
+                }
+
+                let pictures = control.pictures();
+                for picture in &pictures {
+                    let src = match self.image_source(picture) {
+                        Some(src) => src,
+                        None => continue,
+                    };
+
+                    // 묶음 개체가 아니라면 개체의 크기로 나타낸다
+                    let style = match properties {
+                        Some(properties) if pictures.len() == 1 => format!(
+                            " style=\"width:{};height:{}\"",
+                            to_pt(properties.width as i32),
+                            to_pt(properties.height as i32)
+                        ),
+                        _ => String::new(),
+                    };
+                    text.push_str(&format!("<img src=\"{}\" alt=\"\"{}>", src, style));
+                }
+
+                for draw_text in control.draw_texts() {
+                    blocks.push_str("<div class=\"draw-text\">\n");
+                    self.write_paragraphs(blocks, &draw_text.paragraph_list.paragraphs);
+                    blocks.push_str("</div>\n");
+                }
+                self.write_caption(blocks, caption, false);
+            }
+        }
+    }
+
+    /// 위쪽 캡션은 개체 앞에, 나머지 캡션은 개체 뒤에 넣는다
+    fn write_caption(&mut self, blocks: &mut String, caption: Option<&Caption>, before: bool) {
+        if let Some(caption) = caption {
+            if matches!(caption.align, CaptionAlign::Top) == before {
+                blocks.push_str("<div class=\"caption\">\n");
+                self.write_paragraphs(blocks, &caption.paragraph_list.paragraphs);
+                blocks.push_str("</div>\n");
+            }
+        }
+    }
+
+    fn write_table(&mut self, blocks: &mut String, table: &TableControl) {
+        let mut cells: Vec<_> = table.cells.iter().collect();
+        cells.sort_by_key(|cell| (cell.row, cell.column));
+
+        // NOTE: 표의 테두리/배경 ID는 1부터 시작한다
+        match table.record.border_fill_id.checked_sub(1) {
+            Some(id) => blocks.push_str(&format!("<table class=\"bf{}\">\n", id)),
+            None => blocks.push_str("<table>\n"),
+        }
+
+        let mut row = None;
+        for cell in cells {
+            if row != Some(cell.row) {
+                if row.is_some() {
+                    blocks.push_str("</tr>\n");
+                }
+                blocks.push_str("<tr>\n");
+                row = Some(cell.row);
+            }
+
+            let mut attributes = format!(" class=\"bf{}\"", cell.border_fill_id);
+            if cell.col_span > 1 {
+                attributes.push_str(&format!(" colspan=\"{}\"", cell.col_span));
+            }
+            if cell.row_span > 1 {
+                attributes.push_str(&format!(" rowspan=\"{}\"", cell.row_span));
+            }
+            attributes.push_str(&format!(
+                " style=\"width:{};height:{};padding:{} {} {} {}\"",
+                to_pt(cell.width as i32),
+                to_pt(cell.height as i32),
+                to_pt(cell.padding[2] as i32),
+                to_pt(cell.padding[1] as i32),
+                to_pt(cell.padding[3] as i32),
+                to_pt(cell.padding[0] as i32),
+            ));
+
+            blocks.push_str(&format!("<td{}>\n", attributes));
+            self.write_paragraphs(blocks, &cell.paragraph_list.paragraphs);
+            blocks.push_str("</td>\n");
+        }
+        if row.is_some() {
+            blocks.push_str("</tr>\n");
+        }
+
+        blocks.push_str("</table>\n");
+    }
+
+    fn image_source(&self, picture: &PictureRecord) -> Option<String> {
+        let item = self
+            .doc_info
+            .id_mappings
+            .binary_data
+            .iter()
+            .find(|item| item.id == Some(picture.image.bin_item_id))?;
+
+        let name = match item.cfb_file_name() {
+            Some(name) => name,
+            None => return item.relative_path.as_deref().map(escape),
+        };
+
+        if let Some(image_path) = &self.options.image_path {
+            return Some(escape(&format!("{}{}", image_path, name)));
+        }
+
+        // NOTE: 확장자는 문서에 기록된 값이므로 알려진 그림 형식만 넣는다
+        let extension = name.rsplit('.').next().unwrap_or_default();
+        let mime = image_media_type(&extension.to_ascii_lowercase())?;
+        let file = self.bin_data.iter().find(|file| file.name == name)?;
+
+        Some(escape(&format!(
+            "data:{};base64,{}",
+            mime,
+            base64::encode(&file.data)
+        )))
+    }
+}
+
+/// 브라우저에서 쓸 수 있는 그림의 MIME 형식
+fn image_media_type(extension: &str) -> Option<&'static str> {
+    match extension {
+        "png" => Some("image/png"),
+        "jpg" | "jpeg" => Some("image/jpeg"),
+        "gif" => Some("image/gif"),
+        "bmp" => Some("image/bmp"),
+        "svg" => Some("image/svg+xml"),
+        "tif" | "tiff" => Some("image/tiff"),
+        "webp" => Some("image/webp"),
+        _ => None,
+    }
+}
+
+/// 문단 모양, 글자 모양, 테두리/배경의 스타일
+fn style_sheet(doc_info: &DocInfo) -> String {
+    let id_mappings = &doc_info.id_mappings;
+
+    let mut out = String::new();
+    out.push_str("p { margin: 0; white-space: pre-wrap; }\n");
+    out.push_str("table { border-collapse: collapse; }\n");
+    out.push_str("td { vertical-align: middle; }\n");
+
+    for (id, shape) in id_mappings.paragraph_shapes.iter().enumerate() {
+        out.push_str(&format!(".ps{} {{ {} }}\n", id, paragraph_style(shape)));
+    }
+    for (id, shape) in id_mappings.char_shapes.iter().enumerate() {
+        out.push_str(&format!(
+            ".cs{} {{ {} }}\n",
+            id,
+            char_style(doc_info, shape)
+        ));
+    }
+    for (id, border_fill) in id_mappings.border_fills.iter().enumerate() {
+        out.push_str(&format!(
+            ".bf{} {{ {} }}\n",
+            id,
+            border_fill_style(border_fill)
+        ));
+    }
+
+    out
+}
+
+fn paragraph_style(shape: &ParagraphShape) -> String {
+    let mut style = vec![];
+
+    style.push(format!(
+        "text-align: {}",
+        match shape.align {
+            Align::Left => "left",
+            Align::Right => "right",
+            Align::Center => "center",
+            _ => "justify",
+        }
+    ));
+    if matches!(shape.align, Align::Distributive | Align::DistributiveSpace) {
+        style.push("text-align-last: justify".to_string());
+    }
+
+    // NOTE: 문단 여백, 간격과 들여 쓰기는 HWPUNIT의 두 배로 기록되어 있다
+    style.push(format!(
+        "margin: {} {} {} {}",
+        to_pt(shape.margin_top / 2),
+        to_pt(shape.padding_right / 2),
+        to_pt(shape.margin_bottom / 2),
+        to_pt(shape.padding_left / 2),
+    ));
+
+    // 내어 쓰기는 첫 줄을 뺀 나머지 줄을 들여 쓴다
+    if shape.indent < 0 {
+        style.push(format!("padding-left: {}", to_pt(-shape.indent / 2)));
+    }
+    style.push(format!("text-indent: {}", to_pt(shape.indent / 2)));
+
+    let kind = shape
+        .line_spacing_kind
+        .as_ref()
+        .unwrap_or(&shape.line_space_kind_old);
+    let line_spacing = shape
+        .line_spacing
+        .map_or(shape.line_space_old, |line_spacing| line_spacing as i32);
+    match kind {
+        LineSpacingKind::Percent => style.push(format!("line-height: {}%", line_spacing)),
+        LineSpacingKind::Fixed => style.push(format!("line-height: {}", to_pt(line_spacing))),
+        _ => {}
+    }
+
+    style.join("; ")
+}
+
+fn char_style(doc_info: &DocInfo, shape: &CharShape) -> String {
+    let id_mappings = &doc_info.id_mappings;
+
+    let mut style = vec![];
+
+    // 영문 글꼴을 먼저 쓰고 나머지 글자는 한글 글꼴로 나타낸다
+    let mut fonts: Vec<&str> = vec![];
+    for (fonts_by_lang, id) in [
+        (&id_mappings.english_fonts, shape.font_ids[1]),
+        (&id_mappings.korean_fonts, shape.font_ids[0]),
+    ] {
+        if let Some(font) = fonts_by_lang.get(id as usize) {
+            if !fonts.contains(&font.name.as_str()) {
+                fonts.push(&font.name);
+            }
+        }
+    }
+    let fonts: Vec<String> = fonts
+        .iter()
+        .map(|font| css_font_name(font))
+        .filter(|font| !font.is_empty())
+        .map(|font| format!("\"{}\"", font))
+        .collect();
+    if !fonts.is_empty() {
+        style.push(format!("font-family: {}", fonts.join(", ")));
+    }
+
+    // 기준 크기에 한글의 상대 크기를 적용한다
+    let size = shape.base_size * shape.font_sizes[0] as i32 / 100;
+    style.push(format!("font-size: {}", to_pt(size)));
+    if shape.font_spacings[0] != 0 {
+        style.push(format!(
+            "letter-spacing: {}em",
+            shape.font_spacings[0] as f32 / 100.0
+        ));
+    }
+
+    if shape.bold {
+        style.push("font-weight: bold".to_string());
+    }
+    if shape.italic {
+        style.push("font-style: italic".to_string());
+    }
+    style.push(format!("color: {}", shape.color.to_hex()));
+    if shape.shade_color.to_hex() != "#FFFFFF" {
+        style.push(format!("background-color: {}", shape.shade_color.to_hex()));
+    }
+
+    let mut decorations = vec![];
+    match shape.underline_kind {
+        UnderlineKind::Bottom => decorations.push("underline"),
+        UnderlineKind::Top => decorations.push("overline"),
+        UnderlineKind::None => {}
+    }
+    if shape.strike {
+        decorations.push("line-through");
+    }
+    if !decorations.is_empty() {
+        style.push(format!("text-decoration: {}", decorations.join(" ")));
+        if shape.underline_kind != UnderlineKind::None {
+            style.push(format!(
+                "text-decoration-style: {}",
+                decoration_style(&shape.underline_shape)
+            ));
+            style.push(format!(
+                "text-decoration-color: {}",
+                shape.underline_color.to_hex()
+            ));
+        }
+    }
+
+    if shape.supscript {
+        style.push("vertical-align: super".to_string());
+    } else if shape.subscript {
+        style.push("vertical-align: sub".to_string());
+    }
+
+    style.join("; ")
+}
+
+fn border_fill_style(border_fill: &BorderFill) -> String {
+    let mut style = vec![];
+
+    for (side, border) in ["left", "right", "top", "bottom"]
+        .iter()
+        .zip(&border_fill.borders)
+    {
+        let kind = LineStyle::from_border(&border.kind).to_css();
+        if kind == "none" {
+            style.push(format!("border-{}: none", side));
+        } else {
+            style.push(format!(
+                "border-{}: {}mm {} {}",
+                side,
+                border_width(border.width),
+                kind,
+                border.color.to_hex()
+            ));
+        }
+    }
+
+    match &border_fill.fill.content {
+        FillContent::Color(color) => {
+            style.push(format!(
+                "background-color: {}",
+                color.background_color.to_hex()
+            ));
+        }
+        FillContent::Gradation(gradation) => {
+            let colors: Vec<String> = gradation.colors.iter().map(|c| c.to_hex()).collect();
+            let gradient = match gradation.kind {
+                GradationKind::Linear => format!(
+                    "linear-gradient({}deg, {})",
+                    gradation.angle,
+                    colors.join(", ")
+                ),
+                _ => format!("radial-gradient({})", colors.join(", ")),
+            };
+            style.push(format!("background-image: {}", gradient));
+        }
+        _ => {}
+    }
+
+    style.join("; ")
+}
+
+/// CSS 밑줄 모양
+fn decoration_style(kind: &BorderKind) -> &'static str {
+    match kind {
+        BorderKind::Dash | BorderKind::DashDot | BorderKind::DashDotDot | BorderKind::LongDash => {
+            "dashed"
+        }
+        BorderKind::Dot | BorderKind::Circle => "dotted",
+        BorderKind::DoubleSlim
+        | BorderKind::SlimThick
+        | BorderKind::TickSlim
+        | BorderKind::SlimTickSlim => "double",
+        BorderKind::Wave | BorderKind::DoubleWave => "wavy",
+        _ => "solid",
+    }
+}
+
+/// 글꼴 이름에서 글자, 숫자, 공백과 일부 기호만 남긴다
+///
+/// NOTE: 글꼴 이름은 문서에 기록된 값이라 `<`, `;`, `{` 등으로 `<style>`을 벗어날 수 있다
+fn css_font_name(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_alphanumeric() || matches!(c, ' ' | '-' | '_' | '.' | '(' | ')' | '@'))
+        .collect()
+}
+
+/// 본문 글자를 바꾼다. 줄 바꿈은 `<br>`로 바꾼다
+fn escape_text(text: &str) -> String {
+    escape(text).replace('\n', "<br>")
+}
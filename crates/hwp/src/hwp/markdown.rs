@@ -0,0 +1,441 @@
+use std::ops::Range;
+
+use super::{
+    doc_info::{paragraph_shape::ParagraphHeadingKind, DocInfo},
+    paragraph::{
+        control::{
+            common_properties::{Caption, CaptionAlign},
+            shape_object::picture::PictureRecord,
+            table::TableControl,
+            Control,
+        },
+        text_options::TextOptions,
+        Paragraph,
+    },
+    section::Section,
+};
+
+/// 마크다운으로 바꾸는 방법
+#[derive(Debug, Clone)]
+pub struct MarkdownOptions {
+    /// 문단의 글자를 바꾸는 방법. 강제 줄 나눔은 마크다운의 줄 바꿈(`\`)으로 바꾼다
+    pub text: TextOptions,
+    /// 그림 파일 이름 앞에 붙일 경로 (기본값 `BinData/`)
+    ///
+    /// 그림은 [`crate::HWP::bin_data`]의 파일 이름으로 참조한다
+    pub image_path: String,
+}
+
+impl Default for MarkdownOptions {
+    fn default() -> Self {
+        Self {
+            text: TextOptions::default(),
+            image_path: "BinData/".to_string(),
+        }
+    }
+}
+
+/// 구역들을 마크다운(CommonMark, GFM 표와 각주)으로 바꾼다
+pub(crate) fn sections_to_markdown(
+    sections: &[Section],
+    doc_info: &DocInfo,
+    options: &MarkdownOptions,
+) -> String {
+    let mut writer = MarkdownWriter {
+        doc_info,
+        options,
+        blocks: vec![],
+        lists: vec![],
+        notes: vec![],
+        in_cell: false,
+    };
+
+    for section in sections {
+        writer.write_paragraphs(&section.paragraphs);
+    }
+
+    let mut out = writer.blocks.join("\n\n");
+    for (index, note) in writer.notes.iter().enumerate() {
+        if !out.is_empty() {
+            out.push_str("\n\n");
+        }
+        // 각주의 두번째 블록부터는 들여 써야 같은 각주로 본다
+        out.push_str(&format!(
+            "[^{}]: {}",
+            index + 1,
+            note.replace('\n', "\n    ")
+        ));
+    }
+
+    if !out.is_empty() {
+        out.push('\n');
+    }
+
+    out
+}
+
+/// 글자 강조
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct Emphasis {
+    bold: bool,
+    italic: bool,
+    strike: bool,
+}
+
+struct MarkdownWriter<'a> {
+    doc_info: &'a DocInfo,
+    options: &'a MarkdownOptions,
+    blocks: Vec<String>,
+    /// 수준별 목록 번호
+    lists: Vec<u32>,
+    /// 문서 끝에 모을 각주/미주
+    notes: Vec<String>,
+    /// 표의 셀 안인지 여부. 셀 안에서는 블록을 `<br>`로 잇는다
+    in_cell: bool,
+}
+
+impl MarkdownWriter<'_> {
+    fn write_paragraphs(&mut self, paragraphs: &[Paragraph]) {
+        for paragraph in paragraphs {
+            self.write_paragraph(paragraph);
+        }
+    }
+
+    fn write_paragraph(&mut self, paragraph: &Paragraph) {
+        let heading = self.heading_level(paragraph);
+
+        let mut inline = String::new();
+        let mut blocks = vec![];
+        for (range, mut emphasis) in self.runs(paragraph) {
+            // 제목은 대부분 진하게 쓰므로 따로 표시하지 않는다
+            if heading.is_some() {
+                emphasis.bold = false;
+            }
+
+            let mut raw = String::new();
+            let mut text = String::new();
+            let options = &self.options.text;
+            paragraph.write_text_range(&mut raw, options, range, |raw, control| {
+                text.push_str(&escape(&std::mem::take(raw)));
+                self.write_control(&mut text, &mut blocks, control);
+            });
+            text.push_str(&escape(&raw));
+
+            inline.push_str(&emphasize(&text, emphasis));
+        }
+
+        // NOTE: 표 등 블록으로 나타내는 개체는 문단 안의 위치와 관계없이 문단 뒤에 넣는다
+        let lines: Vec<String> = inline
+            .split('\n')
+            .map(escape_line_start)
+            .filter(|line| !line.is_empty())
+            .collect();
+
+        if !lines.is_empty() {
+            let block = if self.in_cell {
+                lines.join("<br>")
+            } else if let Some(level) = heading {
+                self.lists.clear();
+                format!("{} {}", "#".repeat(level.min(6)), lines.join(" "))
+            } else if let Some(marker) = self.list_marker(paragraph) {
+                marker + &lines.join("\\\n")
+            } else {
+                self.lists.clear();
+                lines.join("\\\n")
+            };
+            self.blocks.push(block);
+        }
+
+        self.blocks.extend(blocks);
+    }
+
+    fn write_control(&mut self, text: &mut String, blocks: &mut Vec<String>, control: &Control) {
+        match control {
+            Control::AutoNumber(auto_number) if self.options.text.controls => {
+                text.push_str(&escape(&auto_number.to_string()));
+            }
+            Control::Footnote(note) | Control::Endnote(note) if self.options.text.notes => {
+                let index = self.notes.len();
+                self.notes.push(String::new());
+
+                let note_blocks = self.render(&note.paragraph_list.paragraphs, false);
+                self.notes[index] = note_blocks.join("\n\n");
+                text.push_str(&format!("[^{}]", index + 1));
+            }
+            Control::Header(header_footer) | Control::Footer(header_footer)
+                if self.options.text.headers_footers =>
+            {
+                let paragraphs = &header_footer.paragraph_list.paragraphs;
+                blocks.extend(self.render(paragraphs, self.in_cell));
+            }
+            _ => {
+                let caption = control
+                    .common_properties()
+                    .and_then(|properties| properties.caption.as_ref());
+
+                self.write_caption(blocks, caption, true);
+                match control {
+                    Control::Table(table) => {
+                        let table = self.write_table(table);
+                        if !table.is_empty() {
+                            blocks.push(table);
+                        }
+                    }
+                    Control::Equation(equation) => {
+                        // NOTE: 한/글 수식 스크립트는 LaTeX와 문법이 달라 그대로 넣는다
+                        let script = equation.record.script.split_whitespace();
+                        text.push_str(&format!("${}$", script.collect::<Vec<_>>().join(" ")));
+                    }
+                    _ => {}
+                }
+                for picture in control.pictures() {
+                    if let Some(path) = self.image_path(picture) {
+                        text.push_str(&format!("![]({})", path));
+                    }
+                }
+                for draw_text in control.draw_texts() {
+                    let paragraphs = &draw_text.paragraph_list.paragraphs;
+                    blocks.extend(self.render(paragraphs, self.in_cell));
+                }
+                self.write_caption(blocks, caption, false);
+            }
+        }
+    }
+
+    /// 위쪽 캡션은 개체 앞에, 나머지 캡션은 개체 뒤에 넣는다
+    fn write_caption(&mut self, blocks: &mut Vec<String>, caption: Option<&Caption>, before: bool) {
+        if let Some(caption) = caption {
+            if matches!(caption.align, CaptionAlign::Top) == before {
+                let paragraphs = &caption.paragraph_list.paragraphs;
+                blocks.extend(self.render(paragraphs, self.in_cell));
+            }
+        }
+    }
+
+    /// 첫 행을 머리글로 하는 GFM 표로 바꾼다. 내용이 없는 표는 빈 문자열을 반환한다
+    ///
+    /// NOTE: 마크다운 표는 셀 병합을 나타낼 수 없어 병합된 셀은 왼쪽 위 칸에만 내용을 넣는다.
+    /// 셀 안의 표는 셀의 내용을 이어 붙인다.
+    fn write_table(&mut self, table: &TableControl) -> String {
+        let mut cells: Vec<_> = table.cells.iter().collect();
+        cells.sort_by_key(|cell| (cell.row, cell.column));
+
+        if self.in_cell {
+            let texts: Vec<String> = cells
+                .iter()
+                .map(|cell| {
+                    self.render(&cell.paragraph_list.paragraphs, true)
+                        .join("<br>")
+                })
+                .filter(|text| !text.is_empty())
+                .collect();
+            return texts.join(" ");
+        }
+
+        let rows = table.record.rows as usize;
+        let cols = table.record.cols as usize;
+        let mut grid = vec![vec![String::new(); cols]; rows];
+        for cell in cells {
+            let text = self
+                .render(&cell.paragraph_list.paragraphs, true)
+                .join("<br>");
+            if let Some(column) = grid
+                .get_mut(cell.row as usize)
+                .and_then(|row| row.get_mut(cell.column as usize))
+            {
+                *column = text;
+            }
+        }
+
+        if grid.iter().flatten().all(String::is_empty) {
+            return String::new();
+        }
+
+        let mut lines = vec![];
+        for (index, row) in grid.iter().enumerate() {
+            lines.push(format!("| {} |", row.join(" | ")));
+            if index == 0 {
+                lines.push(format!("|{}", " --- |".repeat(cols)));
+            }
+        }
+
+        lines.join("\n")
+    }
+
+    /// 문단들을 따로 블록으로 바꾼다. 목록 번호는 이어지지 않는다
+    fn render(&mut self, paragraphs: &[Paragraph], in_cell: bool) -> Vec<String> {
+        let blocks = std::mem::take(&mut self.blocks);
+        let lists = std::mem::take(&mut self.lists);
+        let parent_in_cell = std::mem::replace(&mut self.in_cell, in_cell);
+
+        self.write_paragraphs(paragraphs);
+
+        self.in_cell = parent_in_cell;
+        self.lists = lists;
+        std::mem::replace(&mut self.blocks, blocks)
+    }
+
+    fn image_path(&self, picture: &PictureRecord) -> Option<String> {
+        let item = self
+            .doc_info
+            .id_mappings
+            .binary_data
+            .iter()
+            .find(|item| item.id == Some(picture.image.bin_item_id))?;
+
+        let path = match item.cfb_file_name() {
+            Some(name) => format!("{}{}", self.options.image_path, name),
+            None => item.relative_path.clone()?,
+        };
+
+        if path.contains(' ') {
+            Some(format!("<{}>", path))
+        } else {
+            Some(path)
+        }
+    }
+
+    /// 개요 문단 모양이나 개요 스타일이라면 제목 수준(1부터)을 반환한다
+    fn heading_level(&self, paragraph: &Paragraph) -> Option<usize> {
+        let id_mappings = &self.doc_info.id_mappings;
+
+        let shape = id_mappings
+            .paragraph_shapes
+            .get(paragraph.header.paragraph_shape_id as usize)?;
+        if shape.heading_kind == ParagraphHeadingKind::Outline {
+            return Some(shape.heading_level as usize + 1);
+        }
+
+        let style = id_mappings.styles.get(paragraph.header.style_id as usize)?;
+        style
+            .english_name
+            .strip_prefix("Outline ")
+            .and_then(|level| level.parse().ok())
+    }
+
+    /// 번호 문단, 글머리표 문단이라면 들여쓰기를 포함한 목록 표시를 반환한다
+    fn list_marker(&mut self, paragraph: &Paragraph) -> Option<String> {
+        let shape = self
+            .doc_info
+            .id_mappings
+            .paragraph_shapes
+            .get(paragraph.header.paragraph_shape_id as usize)?;
+
+        let ordered = match shape.heading_kind {
+            ParagraphHeadingKind::Number => true,
+            ParagraphHeadingKind::Bullet => false,
+            _ => return None,
+        };
+
+        // 바로 위 수준의 항목이 없다면 한 수준만 들여 쓴다
+        let level = (shape.heading_level as usize).min(self.lists.len());
+        self.lists.truncate(level + 1);
+        if self.lists.len() == level {
+            self.lists.push(0);
+        }
+        self.lists[level] += 1;
+
+        let indent = "    ".repeat(level);
+        if ordered {
+            Some(format!("{}{}. ", indent, self.lists[level]))
+        } else {
+            Some(format!("{}- ", indent))
+        }
+    }
+
+    /// 글자 모양이 같은 구간으로 나눈다
+    fn runs(&self, paragraph: &Paragraph) -> Vec<(Range<u32>, Emphasis)> {
+        let char_shapes = &self.doc_info.id_mappings.char_shapes;
+
+        let mut runs: Vec<(Range<u32>, Emphasis)> = vec![];
+        for (range, shape_id) in paragraph.char_shape_ranges() {
+            let emphasis =
+                char_shapes
+                    .get(shape_id as usize)
+                    .map_or(Emphasis::default(), |char_shape| Emphasis {
+                        bold: char_shape.bold,
+                        italic: char_shape.italic,
+                        strike: char_shape.strike,
+                    });
+
+            match runs.last_mut() {
+                Some((last_range, last)) if *last == emphasis => last_range.end = range.end,
+                _ => runs.push((range, emphasis)),
+            }
+        }
+
+        if runs.is_empty() {
+            runs.push((0..u32::MAX, Emphasis::default()));
+        }
+
+        runs
+    }
+}
+
+/// 마크다운 문법으로 해석될 수 있는 문자 앞에 `\`를 붙인다
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(
+            c,
+            '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '|' | '~' | '$'
+        ) {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+
+    out
+}
+
+/// 줄 앞뒤의 공백을 지우고 제목, 목록 등으로 해석될 수 있는 줄의 시작을 이스케이프 한다
+fn escape_line_start(line: &str) -> String {
+    let line = line.trim_matches(|c| c == ' ' || c == '\t');
+
+    if line.starts_with(['#', '-', '+', '=']) {
+        return format!("\\{}", line);
+    }
+
+    // 숫자 뒤에 `.`이나 `)`와 공백이 오면 번호 목록이 된다
+    let digits = line.len() - line.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+    let rest = &line[digits..];
+    if digits > 0
+        && rest.starts_with(['.', ')'])
+        && matches!(rest[1..].chars().next(), None | Some(' ' | '\t'))
+    {
+        return format!("{}\\{}", &line[..digits], rest);
+    }
+
+    line.to_string()
+}
+
+/// 앞뒤 공백을 밖으로 빼고 강조 표시로 감싼다
+fn emphasize(text: &str, emphasis: Emphasis) -> String {
+    let core = text.trim();
+    if core.is_empty() || emphasis == Emphasis::default() {
+        return text.to_string();
+    }
+
+    let mut open = String::new();
+    if emphasis.strike {
+        open.push_str("~~");
+    }
+    if emphasis.bold {
+        open.push_str("**");
+    }
+    if emphasis.italic {
+        open.push('*');
+    }
+    let close: String = open.chars().rev().collect();
+
+    let start = text.len() - text.trim_start().len();
+    let end = start + core.len();
+    format!(
+        "{}{}{}{}{}",
+        &text[..start],
+        open,
+        core,
+        close,
+        &text[end..]
+    )
+}
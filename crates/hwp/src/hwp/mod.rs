@@ -1,31 +1,72 @@
 pub mod bin_data;
 pub mod body;
 pub mod color_ref;
+pub mod distribute;
+pub mod doc_history;
 pub mod doc_info;
+pub mod docx;
+pub mod error;
 pub mod header;
+pub mod html;
+pub mod markdown;
+pub mod odt;
+pub mod options;
 pub mod paragraph;
+pub mod preview;
+pub mod privacy;
+pub mod redact;
+pub mod sanitize;
+pub mod scripts;
+pub mod scrub;
 pub mod section;
+pub mod storage;
+pub mod summary_info;
+pub mod triage;
 pub mod version;
 pub mod unknown;
 
 mod parameter_set;
 mod record;
+mod text;
 mod utils;
 
-use self::{bin_data::File, body::Body, doc_info::DocInfo, header::Header};
+use self::{
+    bin_data::File,
+    body::Body,
+    distribute::DistributionInfo,
+    doc_history::DocHistory,
+    doc_info::DocInfo,
+    docx::{sections_to_docx, DocxOptions},
+    error::Error,
+    header::Header,
+    html::{sections_to_html, HtmlOptions},
+    markdown::{sections_to_markdown, MarkdownOptions},
+    odt::{sections_to_odt, OdtOptions},
+    options::{invalid_stream, Limiter, ParseOptions},
+    paragraph::text_options::TextOptions,
+    preview::{Preview, PreviewImage},
+    scripts::Scripts,
+    summary_info::SummaryInfo,
+    text::sections_to_text,
+};
 
-use std::io::{Cursor, Read};
+use std::io::{Cursor, Read, Seek};
 
 use cfb::CompoundFile;
-use flate2::read::DeflateDecoder;
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HWP {
     pub header: Header,
     pub body_texts: Body,
     pub view_texts: Option<Body>,
+    pub distribution_info: Option<DistributionInfo>,
     pub doc_info: DocInfo,
     pub bin_data: Vec<File>,
+    pub summary_info: Option<SummaryInfo>,
+    pub preview: Preview,
+    pub scripts: Option<Scripts>,
+    pub doc_history: Option<DocHistory>,
 }
 
 impl HWP {
@@ -34,12 +75,48 @@ impl HWP {
         let mut cfb = CompoundFile::open(cursor).unwrap();
 
         let header = Header::from_cfb(&mut cfb);
+        assert!(!header.flags.encrypted, "암호가 걸린 문서 입니다");
 
-        let doc_info = DocInfo::from_cfb(&mut cfb, &header);
+        Self::from_cfb(&mut cfb, header, &ParseOptions::unlimited())
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// 제한을 적용하여 문서를 연다
+    ///
+    /// 제한을 넘는 문서는 제한보다 많은 메모리를 할당하기 전에 `Error::LimitExceeded`를 반환한다.
+    /// 압축이나 암호화를 풀 수 없는 스트림은 `Error::InvalidStream`을 반환한다.
+    /// 암호가 걸린 문서는 `Error::PasswordRequired`를 반환한다.
+    ///
+    /// NOTE: 한/글 7.0 이후의 암호화 방식은 공개된 명세가 없어 복호화 하지 않는다
+    pub fn from_bytes_with_options(bytes: &[u8], options: &ParseOptions) -> Result<Self, Error> {
+        let cursor = Cursor::new(bytes);
+        let mut cfb = CompoundFile::open(cursor).map_err(|_| Error::InvalidFile)?;
+
+        let header = Header::try_from_cfb(&mut cfb).ok_or(Error::InvalidFile)?;
+        if header.flags.encrypted {
+            return Err(Error::PasswordRequired);
+        }
+
+        Self::from_cfb(&mut cfb, header, options)
+    }
+
+    fn from_cfb<T: Read + Seek>(
+        cfb: &mut CompoundFile<T>,
+        header: Header,
+        options: &ParseOptions,
+    ) -> Result<Self, Error> {
+        let mut limiter = Limiter::new(options);
+
+        let doc_info = DocInfo::read(cfb, &header, &limiter)?;
 
-        let body_texts = Body::from_cfb(&mut cfb, &header);
+        let body_texts = Body::read(cfb, &header, false, &mut limiter)?;
         let view_texts = if header.flags.distributed {
-            Some(Body::from_distributed(&mut cfb, &header))
+            Some(Body::read(cfb, &header, true, &mut limiter)?)
+        } else {
+            None
+        };
+        let distribution_info = if header.flags.distributed {
+            DistributionInfo::from_cfb(cfb)
         } else {
             None
         };
@@ -50,30 +127,135 @@ impl HWP {
             let file_name = item.cfb_file_name();
             if file_name.is_some() {
                 let name = file_name.unwrap();
-                let mut stream = cfb.open_stream(format!("BinData/{}", name)).unwrap();
-                let mut buffer = vec![];
-                stream.read_to_end(&mut buffer).unwrap();
-
-                let data = if item.compressed(&header) {
-                    let cursor = Cursor::new(buffer);
-                    let mut reader = DeflateDecoder::new(cursor);
-                    let mut result = vec![];
-                    reader.read_to_end(&mut result).unwrap();
-                    result
-                } else {
-                    buffer
-                };
+                let path = format!("/BinData/{}", name);
+                let data = limiter
+                    .read_raw_stream(cfb, &path)?
+                    .ok_or_else(|| invalid_stream(&path))?;
+                let data = limiter.decode_stream(data, &path, item.compressed(&header), false)?;
 
                 bin_data.push(File { name, data });
             }
         }
 
-        Self {
+        let summary_info = SummaryInfo::read(cfb, &limiter)?;
+        let preview = Preview::read(cfb, &limiter)?;
+        let scripts = Scripts::read(cfb, &header, &limiter)?;
+        let doc_history = DocHistory::read(cfb, &header, &limiter)?;
+
+        Ok(Self {
             header,
             doc_info,
             body_texts,
             view_texts,
+            distribution_info,
             bin_data,
-        }
+            summary_info,
+            preview,
+            scripts,
+            doc_history,
+        })
+    }
+
+    /// 미리보기 텍스트
+    pub fn preview_text(&self) -> Option<&str> {
+        self.preview.text.as_deref()
+    }
+
+    /// 미리보기 이미지
+    pub fn preview_image(&self) -> Option<&PreviewImage> {
+        self.preview.image.as_ref()
+    }
+
+    /// 스크립트 (매크로)
+    pub fn scripts(&self) -> Option<&Scripts> {
+        self.scripts.as_ref()
+    }
+
+    /// 본문을 읽는 순서대로 문자열로 바꾼다
+    ///
+    /// 배포용 문서라면 `ViewText`의 본문을 사용한다.
+    pub fn to_text(&self) -> String {
+        self.to_text_with_options(&TextOptions::default())
+    }
+
+    pub fn to_text_with_options(&self, options: &TextOptions) -> String {
+        let body = self.view_texts.as_ref().unwrap_or(&self.body_texts);
+
+        sections_to_text(&body.sections, options)
+    }
+
+    /// 본문을 마크다운으로 바꾼다
+    ///
+    /// 개요 문단은 제목, 번호/글머리표 문단은 목록, 표는 GFM 표, 각주/미주는 GFM 각주(`[^1]`)로 바꾼다.
+    /// 그림은 `bin_data`의 파일 이름으로 참조한다.
+    pub fn to_markdown(&self) -> String {
+        self.to_markdown_with_options(&MarkdownOptions::default())
+    }
+
+    pub fn to_markdown_with_options(&self, options: &MarkdownOptions) -> String {
+        let body = self.view_texts.as_ref().unwrap_or(&self.body_texts);
+
+        sections_to_markdown(&body.sections, &self.doc_info, options)
+    }
+
+    /// 본문을 스타일을 포함한 하나의 HTML 문서로 바꾼다
+    ///
+    /// 문단 모양과 글자 모양, 테두리/배경은 CSS 클래스로 나타내고 그림은 data URI로 넣는다.
+    pub fn to_html(&self) -> String {
+        self.to_html_with_options(&HtmlOptions::default())
+    }
+
+    pub fn to_html_with_options(&self, options: &HtmlOptions) -> String {
+        let body = self.view_texts.as_ref().unwrap_or(&self.body_texts);
+        let title = self
+            .summary_info
+            .as_ref()
+            .and_then(|summary_info| summary_info.title());
+
+        sections_to_html(
+            &body.sections,
+            &self.doc_info,
+            &self.bin_data,
+            title,
+            options,
+        )
+    }
+
+    /// 본문을 워드 문서(DOCX)로 바꾼다
+    ///
+    /// 용지 설정, 스타일, 문단/글자 모양, 번호, 표, 그림, 머리말/꼬리말, 각주/미주와 책갈피를 옮긴다.
+    pub fn to_docx(&self) -> Vec<u8> {
+        self.to_docx_with_options(&DocxOptions::default())
+    }
+
+    pub fn to_docx_with_options(&self, options: &DocxOptions) -> Vec<u8> {
+        let body = self.view_texts.as_ref().unwrap_or(&self.body_texts);
+
+        sections_to_docx(
+            &body.sections,
+            &self.doc_info,
+            &self.bin_data,
+            self.summary_info.as_ref(),
+            options,
+        )
+    }
+
+    /// 본문을 ODF 텍스트 문서(ODT)로 바꾼다
+    ///
+    /// 용지 설정, 스타일, 문단/글자 모양, 번호, 표, 그림, 글상자, 머리말/꼬리말, 각주/미주와 책갈피를 옮긴다.
+    pub fn to_odt(&self) -> Vec<u8> {
+        self.to_odt_with_options(&OdtOptions::default())
+    }
+
+    pub fn to_odt_with_options(&self, options: &OdtOptions) -> Vec<u8> {
+        let body = self.view_texts.as_ref().unwrap_or(&self.body_texts);
+
+        sections_to_odt(
+            &body.sections,
+            &self.doc_info,
+            &self.bin_data,
+            self.summary_info.as_ref(),
+            options,
+        )
     }
 }
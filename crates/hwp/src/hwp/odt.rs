@@ -0,0 +1,1171 @@
+use std::ops::Range;
+
+use super::{
+    bin_data::File,
+    doc_info::{
+        border_fill::{BorderFill, BorderKind, FillContent},
+        char_shape::{CharShape, UnderlineKind},
+        numbering::Numbering,
+        paragraph_shape::{Align, LineSpacingKind, ParagraphHeadingKind, ParagraphShape},
+        style::StyleKind,
+        DocInfo,
+    },
+    paragraph::{
+        control::{
+            common_properties::{
+                Caption, CaptionAlign, CommonProperties, HorizontalRelativeTo, TextWrap,
+                VerticalRelativeTo,
+            },
+            page_definition::{Landscape, PageDefinition},
+            shape_object::picture::PictureRecord,
+            table::{Cell, TableControl},
+            Control,
+        },
+        text_options::TextOptions,
+        Paragraph,
+    },
+    section::Section,
+    summary_info::SummaryInfo,
+    utils::{
+        xml::{border_width, escape, to_pt, LineStyle},
+        zip::ZipWriter,
+    },
+};
+
+const NAMESPACES: &str = concat!(
+    " xmlns:office=\"urn:oasis:names:tc:opendocument:xmlns:office:1.0\"",
+    " xmlns:style=\"urn:oasis:names:tc:opendocument:xmlns:style:1.0\"",
+    " xmlns:text=\"urn:oasis:names:tc:opendocument:xmlns:text:1.0\"",
+    " xmlns:table=\"urn:oasis:names:tc:opendocument:xmlns:table:1.0\"",
+    " xmlns:draw=\"urn:oasis:names:tc:opendocument:xmlns:drawing:1.0\"",
+    " xmlns:fo=\"urn:oasis:names:tc:opendocument:xmlns:xsl-fo-compatible:1.0\"",
+    " xmlns:svg=\"urn:oasis:names:tc:opendocument:xmlns:svg-compatible:1.0\"",
+    " xmlns:xlink=\"http://www.w3.org/1999/xlink\"",
+    " xmlns:dc=\"http://purl.org/dc/elements/1.1/\"",
+    " xmlns:meta=\"urn:oasis:names:tc:opendocument:xmlns:meta:1.0\"",
+    " office:version=\"1.2\"",
+);
+const XML_DECLARATION: &str = "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n";
+const MIME_TYPE: &str = "application/vnd.oasis.opendocument.text";
+
+/// ODT로 바꾸는 방법
+#[derive(Debug, Clone)]
+pub struct OdtOptions {
+    /// 문단의 글자를 바꾸는 방법. 탭과 강제 줄 나눔은 ODF의 탭과 줄 바꿈으로 바꾼다
+    ///
+    /// 기본값은 머리말/꼬리말을 넣는다
+    pub text: TextOptions,
+}
+
+impl Default for OdtOptions {
+    fn default() -> Self {
+        Self {
+            text: TextOptions {
+                headers_footers: true,
+                ..TextOptions::default()
+            },
+        }
+    }
+}
+
+/// 구역들을 ODF 텍스트 문서(ODT) 패키지로 바꾼다
+///
+/// 문서의 스타일은 `S{ID}` 스타일로 옮기고, 문단 모양과 글자 모양, 표, 개체는 자동 스타일로 나타낸다.
+/// 구역마다 용지 설정과 머리말/꼬리말을 담은 마스터 페이지(`MP{n}`)를 만든다.
+pub(crate) fn sections_to_odt(
+    sections: &[Section],
+    doc_info: &DocInfo,
+    bin_data: &[File],
+    summary_info: Option<&SummaryInfo>,
+    options: &OdtOptions,
+) -> Vec<u8> {
+    let mut writer = OdtWriter {
+        doc_info,
+        options,
+        automatic_styles: vec![],
+        text_styles: vec![None; doc_info.id_mappings.char_shapes.len()],
+        master_pages: vec![],
+        master_page: None,
+        outline_numbering_id: None,
+        images: vec![],
+        notes: [0, 0],
+        tables: 0,
+        frames: 0,
+    };
+
+    let mut body = String::new();
+    for section in sections {
+        writer.begin_section(section);
+        writer.write_paragraphs(&mut body, &section.paragraphs);
+    }
+    if writer.master_pages.is_empty() {
+        writer.master_pages.push(MasterPage::default());
+    }
+
+    let automatic_styles = writer.automatic_styles();
+
+    let mut zip = ZipWriter::default();
+    // NOTE: mimetype은 패키지의 첫 파일이어야 하고 압축하지 않는다
+    zip.add_file("mimetype", MIME_TYPE.as_bytes(), false);
+
+    zip_xml(
+        &mut zip,
+        "content.xml",
+        &format!(
+            "<office:document-content{}><office:automatic-styles>{}</office:automatic-styles><office:body><office:text>{}</office:text></office:body></office:document-content>",
+            NAMESPACES, automatic_styles, body
+        ),
+    );
+    zip_xml(&mut zip, "styles.xml", &writer.styles(&automatic_styles));
+    zip_xml(&mut zip, "meta.xml", &meta(summary_info));
+
+    let mut manifest = format!(
+        concat!(
+            "<manifest:manifest xmlns:manifest=\"urn:oasis:names:tc:opendocument:xmlns:manifest:1.0\" manifest:version=\"1.2\">",
+            "<manifest:file-entry manifest:full-path=\"/\" manifest:version=\"1.2\" manifest:media-type=\"{}\"/>",
+            "<manifest:file-entry manifest:full-path=\"content.xml\" manifest:media-type=\"text/xml\"/>",
+            "<manifest:file-entry manifest:full-path=\"styles.xml\" manifest:media-type=\"text/xml\"/>",
+            "<manifest:file-entry manifest:full-path=\"meta.xml\" manifest:media-type=\"text/xml\"/>",
+        ),
+        MIME_TYPE
+    );
+
+    // 실제로 쓰인 그림만 넣는다
+    for name in &writer.images {
+        let file = match bin_data.iter().find(|file| &file.name == name) {
+            Some(file) => file,
+            None => continue,
+        };
+        let extension = name.rsplit('.').next().unwrap_or_default();
+        let media_type = match image_media_type(extension) {
+            Some(media_type) => media_type,
+            None => continue,
+        };
+        zip.add_file(&format!("Pictures/{}", name), &file.data, false);
+
+        manifest.push_str(&format!(
+            "<manifest:file-entry manifest:full-path=\"Pictures/{}\" manifest:media-type=\"{}\"/>",
+            escape(name),
+            media_type
+        ));
+    }
+    manifest.push_str("</manifest:manifest>");
+    zip_xml(&mut zip, "META-INF/manifest.xml", &manifest);
+
+    zip.finish()
+}
+
+/// 구역의 용지 설정과 머리말/꼬리말
+#[derive(Default)]
+struct MasterPage<'a> {
+    page_definition: Option<&'a PageDefinition>,
+    header: Option<String>,
+    footer: Option<String>,
+}
+
+struct OdtWriter<'a> {
+    doc_info: &'a DocInfo,
+    options: &'a OdtOptions,
+    /// 자동 스타일의 (이름, 계열, 스타일 속성, 내용)
+    automatic_styles: Vec<(String, &'static str, String, String)>,
+    /// 글자 모양별 자동 스타일 이름
+    text_styles: Vec<Option<String>>,
+    master_pages: Vec<MasterPage<'a>>,
+    /// 다음 문단에 적용할 마스터 페이지
+    master_page: Option<usize>,
+    /// 문서의 개요 번호 ID (1부터)
+    outline_numbering_id: Option<u16>,
+    /// 문서에서 참조한 그림 파일 이름
+    images: Vec<String>,
+    /// 각주와 미주의 수
+    notes: [u32; 2],
+    tables: u32,
+    frames: u32,
+}
+
+impl<'a> OdtWriter<'a> {
+    /// 구역 정의 컨트롤을 찾아 마스터 페이지를 만든다
+    fn begin_section(&mut self, section: &'a Section) {
+        let control = section
+            .paragraphs
+            .iter()
+            .flat_map(|paragraph| paragraph.controls.iter())
+            .find_map(|control| match control {
+                Control::SectionDefinition(control) => Some(control),
+                _ => None,
+            });
+
+        // NOTE: ODF 문서는 개요 번호를 하나만 가질 수 있어 첫 구역의 개요 번호를 사용한다
+        if self.master_pages.is_empty() {
+            self.outline_numbering_id = control
+                .map(|control| control.numbering_id)
+                .filter(|id| *id > 0);
+        }
+
+        self.master_pages.push(MasterPage {
+            page_definition: control.map(|control| &control.page_definition),
+            header: None,
+            footer: None,
+        });
+        self.master_page = Some(self.master_pages.len() - 1);
+    }
+
+    /// 속성이 같은 자동 스타일이 있다면 그 이름을, 없다면 새로 만든 이름을 반환한다
+    fn automatic_style(
+        &mut self,
+        family: &'static str,
+        attributes: String,
+        properties: String,
+    ) -> String {
+        let existing = self
+            .automatic_styles
+            .iter()
+            .find(|style| style.1 == family && style.2 == attributes && style.3 == properties);
+        if let Some(style) = existing {
+            return style.0.clone();
+        }
+
+        let prefix = match family {
+            "paragraph" => "P",
+            "text" => "T",
+            "table" => "Tb",
+            "table-column" => "Co",
+            "table-row" => "Ro",
+            "table-cell" => "Ce",
+            _ => "fr",
+        };
+        let count = self
+            .automatic_styles
+            .iter()
+            .filter(|style| style.1 == family)
+            .count();
+        let name = format!("{}{}", prefix, count + 1);
+        self.automatic_styles
+            .push((name.clone(), family, attributes, properties));
+
+        name
+    }
+
+    fn text_style(&mut self, shape_id: u32) -> Option<String> {
+        if let Some(Some(name)) = self.text_styles.get(shape_id as usize) {
+            return Some(name.clone());
+        }
+
+        let shape = self
+            .doc_info
+            .id_mappings
+            .char_shapes
+            .get(shape_id as usize)?;
+        let properties = format!(
+            "<style:text-properties{}/>",
+            text_properties(self.doc_info, shape)
+        );
+        let name = self.automatic_style("text", String::new(), properties);
+        self.text_styles[shape_id as usize] = Some(name.clone());
+
+        Some(name)
+    }
+
+    fn write_paragraphs(&mut self, out: &mut String, paragraphs: &[Paragraph]) {
+        for paragraph in paragraphs {
+            self.write_paragraph(out, paragraph);
+        }
+    }
+
+    fn write_paragraph(&mut self, out: &mut String, paragraph: &Paragraph) {
+        // 머리말, 표 등 안쪽 문단보다 먼저 마스터 페이지를 가져온다
+        let master_page = self.master_page.take();
+
+        let mut ranges: Vec<(Range<u32>, Option<u32>)> = paragraph
+            .char_shape_ranges()
+            .into_iter()
+            .map(|(range, shape_id)| (range, Some(shape_id)))
+            .collect();
+        if ranges.is_empty() {
+            ranges.push((0..u32::MAX, None));
+        }
+
+        let mut inline = String::new();
+        let mut blocks = String::new();
+        for (range, shape_id) in ranges {
+            let style = shape_id.and_then(|shape_id| self.text_style(shape_id));
+
+            let mut raw = String::new();
+            let mut text = String::new();
+            let options = &self.options.text;
+            paragraph.write_text_range(&mut raw, options, range, |raw, control| {
+                text.push_str(&escape_text(&std::mem::take(raw)));
+                self.write_control(&mut text, &mut blocks, control);
+            });
+            text.push_str(&escape_text(&raw));
+
+            if text.is_empty() {
+                continue;
+            }
+            match style {
+                Some(style) => inline.push_str(&format!(
+                    "<text:span text:style-name=\"{}\">{}</text:span>",
+                    style, text
+                )),
+                None => inline.push_str(&text),
+            }
+        }
+
+        // NOTE: 표 등 블록으로 나타내는 개체는 문단 안의 위치와 관계없이 문단 뒤에 넣는다
+        // 구역의 첫 문단은 마스터 페이지를 적용하기 위해 항상 넣는다
+        if inline.is_empty() && !blocks.is_empty() && master_page.is_none() {
+            out.push_str(&blocks);
+            return;
+        }
+
+        let id_mappings = &self.doc_info.id_mappings;
+        let shape = id_mappings
+            .paragraph_shapes
+            .get(paragraph.header.paragraph_shape_id as usize);
+
+        let mut attributes = String::new();
+        let style_id = paragraph.header.style_id as usize;
+        if matches!(id_mappings.styles.get(style_id), Some(style) if style.kind == StyleKind::Para)
+        {
+            attributes.push_str(&format!(" style:parent-style-name=\"S{}\"", style_id));
+        }
+        if let Some(index) = master_page {
+            attributes.push_str(&format!(" style:master-page-name=\"MP{}\"", index + 1));
+        }
+        let properties = shape.map(paragraph_properties).unwrap_or_default();
+        let style = self.automatic_style("paragraph", attributes, properties);
+
+        let (heading_kind, level) = shape.map_or((&ParagraphHeadingKind::None, 0), |shape| {
+            (&shape.heading_kind, shape.heading_level as usize)
+        });
+        match heading_kind {
+            ParagraphHeadingKind::Outline => {
+                out.push_str(&format!(
+                    "<text:h text:style-name=\"{}\" text:outline-level=\"{}\">{}</text:h>",
+                    style,
+                    level + 1,
+                    inline
+                ));
+            }
+            ParagraphHeadingKind::Number | ParagraphHeadingKind::Bullet => {
+                // 번호 목록은 같은 목록 스타일의 앞 목록에 이어서 번호를 매긴다
+                let list = list_style_name(self.doc_info, shape.unwrap());
+                let level = level.min(9);
+                out.push_str(&format!(
+                    "<text:list text:style-name=\"{}\" text:continue-numbering=\"true\"><text:list-item>",
+                    list
+                ));
+                out.push_str(&"<text:list><text:list-item>".repeat(level));
+                out.push_str(&format!(
+                    "<text:p text:style-name=\"{}\">{}</text:p>",
+                    style, inline
+                ));
+                out.push_str(&"</text:list-item></text:list>".repeat(level + 1));
+            }
+            ParagraphHeadingKind::None => {
+                out.push_str(&format!(
+                    "<text:p text:style-name=\"{}\">{}</text:p>",
+                    style, inline
+                ));
+            }
+        }
+        out.push_str(&blocks);
+    }
+
+    fn write_control(&mut self, text: &mut String, blocks: &mut String, control: &Control) {
+        match control {
+            Control::AutoNumber(auto_number) if self.options.text.controls => {
+                text.push_str(&escape(&auto_number.to_string()));
+            }
+            Control::Bookmark(bookmark) => {
+                text.push_str(&format!(
+                    "<text:bookmark text:name=\"{}\"/>",
+                    escape(&bookmark.name)
+                ));
+            }
+            Control::Footnote(note) | Control::Endnote(note) if self.options.text.notes => {
+                // 각주와 미주는 따로 번호를 매긴다
+                let (class, index) = match control {
+                    Control::Footnote(_) => ("footnote", 0),
+                    _ => ("endnote", 1),
+                };
+                self.notes[index] += 1;
+                let number = self.notes[index];
+
+                let mut body = String::new();
+                self.write_paragraphs(&mut body, &note.paragraph_list.paragraphs);
+
+                text.push_str(&format!(
+                    "<text:note text:id=\"{0}{1}\" text:note-class=\"{0}\"><text:note-citation>{1}</text:note-citation><text:note-body>{2}</text:note-body></text:note>",
+                    class, number, body
+                ));
+            }
+            Control::Header(header_footer) | Control::Footer(header_footer)
+                if self.options.text.headers_footers =>
+            {
+                let mut xml = String::new();
+                self.write_paragraphs(&mut xml, &header_footer.paragraph_list.paragraphs);
+
+                // NOTE: 마스터 페이지는 종류별로 하나의 머리말/꼬리말만 가질 수 있어
+                // 구역의 첫 머리말/꼬리말만 사용한다
+                if let Some(master_page) = self.master_pages.last_mut() {
+                    let slot = match control {
+                        Control::Header(_) => &mut master_page.header,
+                        _ => &mut master_page.footer,
+                    };
+                    if slot.is_none() {
+                        *slot = Some(xml);
+                    }
+                }
+            }
+            _ => {
+                let properties = control.common_properties();
+                let caption = properties.and_then(|properties| properties.caption.as_ref());
+
+                self.write_caption(blocks, caption, true);
+                match control {
+                    Control::Table(table) => self.write_table(blocks, table),
+                    Control::Equation(equation) => {
+                        // NOTE: 한/글 수식 스크립트는 MathML로 바꾸지 않고 글자로 넣는다
+                        let script = equation.record.script.split_whitespace();
+                        text.push_str(&escape(&script.collect::<Vec<_>>().join(" ")));
+                    }
+                    _ => {}
+                }
+
+                // NOTE: 묶음 개체는 하위 개체의 위치를 알 수 없어 하위 개체를 글자처럼 차례로 넣는다
+                let pictures = control.pictures();
+                let draw_texts = control.draw_texts();
+                let single = pictures.len() + draw_texts.len() == 1;
+                for picture in &pictures {
+                    let frame = match properties {
+                        Some(properties) if single => self.frame_attributes(properties),
+                        _ => self.inline_frame_attributes(
+                            picture.rect.right_bottom.x - picture.rect.left_top.x,
+                            picture.rect.right_bottom.y - picture.rect.left_top.y,
+                        ),
+                    };
+                    if let Some(image) = self.image(picture) {
+                        text.push_str(&format!("<draw:frame{}>{}</draw:frame>", frame, image));
+                    }
+                }
+                for draw_text in draw_texts {
+                    let frame = match properties {
+                        Some(properties) if single => self.frame_attributes(properties),
+                        Some(properties) => self.inline_frame_attributes(
+                            properties.width as i32,
+                            properties.height as i32,
+                        ),
+                        None => continue,
+                    };
+
+                    let mut content = String::new();
+                    self.write_paragraphs(&mut content, &draw_text.paragraph_list.paragraphs);
+                    text.push_str(&format!(
+                        "<draw:frame{}><draw:text-box>{}</draw:text-box></draw:frame>",
+                        frame, content
+                    ));
+                }
+                self.write_caption(blocks, caption, false);
+            }
+        }
+    }
+
+    /// 위쪽 캡션은 개체 앞에, 나머지 캡션은 개체 뒤에 넣는다
+    fn write_caption(&mut self, blocks: &mut String, caption: Option<&Caption>, before: bool) {
+        if let Some(caption) = caption {
+            if matches!(caption.align, CaptionAlign::Top) == before {
+                self.write_paragraphs(blocks, &caption.paragraph_list.paragraphs);
+            }
+        }
+    }
+
+    fn write_table(&mut self, blocks: &mut String, table: &TableControl) {
+        let columns = table.column_widths();
+
+        self.tables += 1;
+        let style = self.automatic_style(
+            "table",
+            String::new(),
+            format!(
+                "<style:table-properties style:width=\"{}\" table:align=\"left\"/>",
+                to_pt(columns.iter().sum::<u32>() as i32)
+            ),
+        );
+        blocks.push_str(&format!(
+            "<table:table table:name=\"표{}\" table:style-name=\"{}\">",
+            self.tables, style
+        ));
+        for width in &columns {
+            let style = self.automatic_style(
+                "table-column",
+                String::new(),
+                format!(
+                    "<style:table-column-properties style:column-width=\"{}\"/>",
+                    to_pt(*width as i32)
+                ),
+            );
+            blocks.push_str(&format!(
+                "<table:table-column table:style-name=\"{}\"/>",
+                style
+            ));
+        }
+
+        for row in 0..table.record.rows {
+            let height = table
+                .cells
+                .iter()
+                .filter(|cell| cell.row == row && cell.row_span == 1)
+                .map(|cell| cell.height)
+                .max();
+            match height {
+                Some(height) => {
+                    let style = self.automatic_style(
+                        "table-row",
+                        String::new(),
+                        format!(
+                            "<style:table-row-properties style:min-row-height=\"{}\"/>",
+                            to_pt(height as i32)
+                        ),
+                    );
+                    blocks.push_str(&format!("<table:table-row table:style-name=\"{}\">", style));
+                }
+                None => blocks.push_str("<table:table-row>"),
+            }
+
+            // NOTE: 병합되어 가려진 칸도 covered-table-cell로 자리를 채워야 한다
+            for column in 0..table.record.cols {
+                let cell = table.cells.iter().find(|cell| {
+                    cell.column <= column
+                        && column < cell.column + cell.col_span.max(1)
+                        && cell.row <= row
+                        && row < cell.row + cell.row_span.max(1)
+                });
+                match cell {
+                    Some(cell) if cell.row == row && cell.column == column => {
+                        self.write_cell(blocks, cell);
+                    }
+                    Some(_) => blocks.push_str("<table:covered-table-cell/>"),
+                    None => blocks.push_str("<table:table-cell><text:p/></table:table-cell>"),
+                }
+            }
+            blocks.push_str("</table:table-row>");
+        }
+
+        blocks.push_str("</table:table>");
+    }
+
+    fn write_cell(&mut self, blocks: &mut String, cell: &Cell) {
+        let border_fill = self
+            .doc_info
+            .id_mappings
+            .border_fills
+            .get(cell.border_fill_id as usize);
+
+        let mut properties = border_fill.map(border_fill_properties).unwrap_or_default();
+        properties.push_str(&format!(
+            " fo:padding-left=\"{}\" fo:padding-right=\"{}\" fo:padding-top=\"{}\" fo:padding-bottom=\"{}\"",
+            to_pt(cell.padding[0] as i32),
+            to_pt(cell.padding[1] as i32),
+            to_pt(cell.padding[2] as i32),
+            to_pt(cell.padding[3] as i32),
+        ));
+        let style = self.automatic_style(
+            "table-cell",
+            String::new(),
+            format!("<style:table-cell-properties{}/>", properties),
+        );
+
+        let mut attributes = format!(" table:style-name=\"{}\"", style);
+        if cell.col_span > 1 {
+            attributes.push_str(&format!(
+                " table:number-columns-spanned=\"{}\"",
+                cell.col_span
+            ));
+        }
+        if cell.row_span > 1 {
+            attributes.push_str(&format!(" table:number-rows-spanned=\"{}\"", cell.row_span));
+        }
+
+        blocks.push_str(&format!(
+            "<table:table-cell{} office:value-type=\"string\">",
+            attributes
+        ));
+        self.write_paragraphs(blocks, &cell.paragraph_list.paragraphs);
+        blocks.push_str("</table:table-cell>");
+    }
+
+    /// 개체 공통 속성의 위치와 배치 방식을 따르는 틀의 속성
+    fn frame_attributes(&mut self, properties: &CommonProperties) -> String {
+        if properties.treat_as_char {
+            return self.inline_frame_attributes(properties.width as i32, properties.height as i32);
+        }
+
+        let vertical = match properties.vertical_relative_to {
+            VerticalRelativeTo::Paper => "page",
+            VerticalRelativeTo::Page => "page-content",
+            VerticalRelativeTo::Paragraph => "paragraph",
+        };
+        let horizontal = match properties.horizontal_relative_to {
+            HorizontalRelativeTo::Paper => "page",
+            HorizontalRelativeTo::Page => "page-content",
+            HorizontalRelativeTo::Column | HorizontalRelativeTo::Paragraph => "paragraph",
+        };
+        let wrap = match properties.text_wrap {
+            Some(TextWrap::TopAndBottom) => " style:wrap=\"none\"",
+            Some(TextWrap::BehindText) => {
+                " style:wrap=\"run-through\" style:run-through=\"background\""
+            }
+            Some(TextWrap::InFrontOfText) => {
+                " style:wrap=\"run-through\" style:run-through=\"foreground\""
+            }
+            _ => " style:wrap=\"parallel\"",
+        };
+        let style = self.automatic_style(
+            "graphic",
+            String::new(),
+            format!(
+                "<style:graphic-properties{} style:vertical-pos=\"from-top\" style:vertical-rel=\"{}\" style:horizontal-pos=\"from-left\" style:horizontal-rel=\"{}\"/>",
+                wrap, vertical, horizontal
+            ),
+        );
+
+        // NOTE: 개체의 위치는 부호 있는 값이지만 부호 없는 값으로 읽고 있다
+        self.frames += 1;
+        format!(
+            " draw:style-name=\"{}\" draw:name=\"개체{}\" text:anchor-type=\"paragraph\" svg:x=\"{}\" svg:y=\"{}\" svg:width=\"{}\" svg:height=\"{}\" draw:z-index=\"{}\"",
+            style,
+            self.frames,
+            to_pt(properties.offset.horizontal as i32),
+            to_pt(properties.offset.vertical as i32),
+            to_pt(properties.width as i32),
+            to_pt(properties.height as i32),
+            properties.z_order.max(0)
+        )
+    }
+
+    /// 글자처럼 취급하는 틀의 속성
+    fn inline_frame_attributes(&mut self, width: i32, height: i32) -> String {
+        let style = self.automatic_style(
+            "graphic",
+            String::new(),
+            "<style:graphic-properties style:vertical-pos=\"top\" style:vertical-rel=\"baseline\"/>"
+                .to_string(),
+        );
+
+        self.frames += 1;
+        format!(
+            " draw:style-name=\"{}\" draw:name=\"개체{}\" text:anchor-type=\"as-char\" svg:width=\"{}\" svg:height=\"{}\"",
+            style,
+            self.frames,
+            to_pt(width.max(0)),
+            to_pt(height.max(0))
+        )
+    }
+
+    fn image(&mut self, picture: &PictureRecord) -> Option<String> {
+        let item = self
+            .doc_info
+            .id_mappings
+            .binary_data
+            .iter()
+            .find(|item| item.id == Some(picture.image.bin_item_id))?;
+        let name = item.cfb_file_name()?;
+        // NOTE: 확장자는 문서에 기록된 값이므로 알려진 그림 형식만 넣는다
+        image_media_type(name.rsplit('.').next().unwrap_or_default())?;
+
+        let href = format!("Pictures/{}", name);
+        if !self.images.contains(&name) {
+            self.images.push(name);
+        }
+
+        Some(format!(
+            "<draw:image xlink:href=\"{}\" xlink:type=\"simple\" xlink:show=\"embed\" xlink:actuate=\"onLoad\"/>",
+            escape(&href)
+        ))
+    }
+
+    /// 자동 스타일과 목록 스타일
+    ///
+    /// NOTE: 머리말/꼬리말은 styles.xml의 자동 스타일만 참조할 수 있어 content.xml과 styles.xml에 같은 자동 스타일을 넣는다
+    fn automatic_styles(&self) -> String {
+        let mut xml = String::new();
+        for (name, family, attributes, properties) in &self.automatic_styles {
+            xml.push_str(&format!(
+                "<style:style style:name=\"{}\" style:family=\"{}\"{}>{}</style:style>",
+                name, family, attributes, properties
+            ));
+        }
+
+        let id_mappings = &self.doc_info.id_mappings;
+        for (index, numbering) in id_mappings.numberings.iter().enumerate() {
+            xml.push_str(&format!("<text:list-style style:name=\"N{}\">", index + 1));
+            xml.push_str(&number_levels(numbering, "text:list-level-style-number"));
+            xml.push_str("</text:list-style>");
+        }
+        for (index, bullet) in id_mappings.bullets.iter().enumerate() {
+            xml.push_str(&format!("<text:list-style style:name=\"B{}\">", index + 1));
+
+            // NOTE: 기호 글꼴의 글머리표는 사용자 영역(PUA) 문자로 기록되어 있다
+            let font = if ('\u{F000}'..='\u{F0FF}').contains(&bullet.bullet_char) {
+                "<style:text-properties fo:font-family=\"Wingdings\"/>"
+            } else {
+                ""
+            };
+            for level in 1..=10 {
+                xml.push_str(&format!(
+                    "<text:list-level-style-bullet text:level=\"{}\" text:bullet-char=\"{}\">{}</text:list-level-style-bullet>",
+                    level,
+                    escape(&bullet.bullet_char.to_string()),
+                    font
+                ));
+            }
+            xml.push_str("</text:list-style>");
+        }
+
+        xml
+    }
+
+    /// 기본 스타일, 문서의 스타일, 개요 번호와 구역별 마스터 페이지
+    fn styles(&self, automatic_styles: &str) -> String {
+        let id_mappings = &self.doc_info.id_mappings;
+
+        let mut xml = format!("<office:document-styles{}><office:styles>", NAMESPACES);
+        xml.push_str("<style:default-style style:family=\"paragraph\"><style:text-properties fo:font-size=\"10pt\" style:font-size-asian=\"10pt\" fo:language=\"ko\" fo:country=\"KR\" style:language-asian=\"ko\" style:country-asian=\"KR\"/></style:default-style>");
+
+        for (id, style) in id_mappings.styles.iter().enumerate() {
+            let paragraph_style = style.kind == StyleKind::Para;
+            xml.push_str(&format!(
+                "<style:style style:name=\"S{}\" style:display-name=\"{}\" style:family=\"{}\">",
+                id,
+                escape(&style.name),
+                if paragraph_style { "paragraph" } else { "text" }
+            ));
+            if paragraph_style {
+                if let Some(shape) = id_mappings
+                    .paragraph_shapes
+                    .get(style.paragraph_shape_id as usize)
+                {
+                    xml.push_str(&paragraph_properties(shape));
+                }
+            }
+            if let Some(shape) = id_mappings.char_shapes.get(style.char_shape_id as usize) {
+                xml.push_str(&format!(
+                    "<style:text-properties{}/>",
+                    text_properties(self.doc_info, shape)
+                ));
+            }
+            xml.push_str("</style:style>");
+        }
+
+        let outline = self
+            .outline_numbering_id
+            .and_then(|id| id_mappings.numberings.get(id as usize - 1));
+        if let Some(numbering) = outline {
+            xml.push_str("<text:outline-style style:name=\"Outline\">");
+            xml.push_str(&number_levels(numbering, "text:outline-level-style"));
+            xml.push_str("</text:outline-style>");
+        }
+        xml.push_str("</office:styles>");
+
+        xml.push_str("<office:automatic-styles>");
+        xml.push_str(automatic_styles);
+        for (index, master_page) in self.master_pages.iter().enumerate() {
+            xml.push_str(&page_layout(index + 1, master_page));
+        }
+        xml.push_str("</office:automatic-styles>");
+
+        xml.push_str("<office:master-styles>");
+        for (index, master_page) in self.master_pages.iter().enumerate() {
+            xml.push_str(&format!(
+                "<style:master-page style:name=\"MP{0}\" style:page-layout-name=\"PL{0}\">",
+                index + 1
+            ));
+            if let Some(header) = &master_page.header {
+                xml.push_str(&format!("<style:header>{}</style:header>", header));
+            }
+            if let Some(footer) = &master_page.footer {
+                xml.push_str(&format!("<style:footer>{}</style:footer>", footer));
+            }
+            xml.push_str("</style:master-page>");
+        }
+        xml.push_str("</office:master-styles></office:document-styles>");
+
+        xml
+    }
+}
+
+/// 번호 문단은 `N{ID}`, 글머리표 문단은 `B{ID}` 목록 스타일을 사용한다
+fn list_style_name(doc_info: &DocInfo, shape: &ParagraphShape) -> String {
+    let id = shape.numbering_bullet_id.max(1);
+    match shape.heading_kind {
+        ParagraphHeadingKind::Bullet if (id as usize) <= doc_info.id_mappings.bullets.len() => {
+            format!("B{}", id)
+        }
+        _ => format!("N{}", id),
+    }
+}
+
+/// 한/글의 번호 형식(`^1.^2.`)을 ODF 번호 수준으로 바꾼다
+///
+/// NOTE: 수준별 번호 모양(가, ①, i 등)은 해석하지 않아 모두 아라비아 숫자로 나타낸다.
+/// 번호 사이의 글자는 ODF에서 `.`으로만 나타낼 수 있다.
+fn number_levels(numbering: &Numbering, element: &str) -> String {
+    let mut xml = String::new();
+    for (index, head) in numbering.paragraph_heads.iter().take(10).enumerate() {
+        let format = &head.number_format;
+        let levels = format.matches('^').count();
+        let prefix = format.split('^').next().unwrap_or_default();
+        let suffix = format
+            .rfind('^')
+            .map(|position| format[position + 1..].trim_start_matches(|c: char| c.is_ascii_digit()))
+            .unwrap_or(format);
+        let start = head.start_number.unwrap_or(numbering.start as u32).max(1);
+
+        xml.push_str(&format!(
+            "<{} text:level=\"{}\" style:num-prefix=\"{}\" style:num-suffix=\"{}\" style:num-format=\"{}\" text:display-levels=\"{}\" text:start-value=\"{}\"/>",
+            element,
+            index + 1,
+            escape(if levels == 0 { "" } else { prefix }),
+            escape(suffix),
+            if levels == 0 { "" } else { "1" },
+            levels.max(1),
+            start
+        ));
+    }
+
+    xml
+}
+
+/// 구역의 용지 설정
+///
+/// NOTE: 한/글의 위쪽/아래쪽 여백은 머리말/꼬리말 여백을 뺀 값이다.
+/// ODF는 머리말이 있을 때만 머리말 영역을 여백 안에 두므로 머리말이 없다면 두 여백을 더한다.
+fn page_layout(id: usize, master_page: &MasterPage) -> String {
+    let page = match master_page.page_definition {
+        Some(page) => page,
+        None => return format!("<style:page-layout style:name=\"PL{}\"/>", id),
+    };
+
+    let (mut width, mut height) = (page.width, page.height);
+    let landscape = page.landscape == Landscape::Widely;
+    if landscape {
+        std::mem::swap(&mut width, &mut height);
+    }
+
+    let padding = &page.padding;
+    let (top, bottom) = (
+        padding.top
+            + if master_page.header.is_some() {
+                0
+            } else {
+                padding.header
+            },
+        padding.bottom
+            + if master_page.footer.is_some() {
+                0
+            } else {
+                padding.footer
+            },
+    );
+
+    format!(
+        concat!(
+            "<style:page-layout style:name=\"PL{}\">",
+            "<style:page-layout-properties fo:page-width=\"{}\" fo:page-height=\"{}\" style:print-orientation=\"{}\" ",
+            "fo:margin-top=\"{}\" fo:margin-bottom=\"{}\" fo:margin-left=\"{}\" fo:margin-right=\"{}\"/>",
+            "<style:header-style><style:header-footer-properties fo:min-height=\"{}\" fo:margin-bottom=\"0pt\"/></style:header-style>",
+            "<style:footer-style><style:header-footer-properties fo:min-height=\"{}\" fo:margin-top=\"0pt\"/></style:footer-style>",
+            "</style:page-layout>"
+        ),
+        id,
+        to_pt(width as i32),
+        to_pt(height as i32),
+        if landscape { "landscape" } else { "portrait" },
+        to_pt(top as i32),
+        to_pt(bottom as i32),
+        to_pt((padding.left + padding.binding) as i32),
+        to_pt(padding.right as i32),
+        to_pt(padding.header as i32),
+        to_pt(padding.footer as i32),
+    )
+}
+
+/// 문단 모양을 `style:paragraph-properties`로 바꾼다
+fn paragraph_properties(shape: &ParagraphShape) -> String {
+    let mut properties = String::new();
+
+    properties.push_str(&format!(
+        " fo:text-align=\"{}\"",
+        match shape.align {
+            Align::Left => "start",
+            Align::Right => "end",
+            Align::Center => "center",
+            _ => "justify",
+        }
+    ));
+    if matches!(shape.align, Align::Distributive | Align::DistributiveSpace) {
+        properties.push_str(" fo:text-align-last=\"justify\"");
+    }
+
+    // NOTE: 문단 여백, 간격과 들여 쓰기는 HWPUNIT의 두 배로 기록되어 있다
+    // 내어 쓰기는 첫 줄을 뺀 나머지 줄을 들여 쓴다
+    let indent = shape.indent / 2;
+    properties.push_str(&format!(
+        " fo:margin-left=\"{}\" fo:margin-right=\"{}\" fo:margin-top=\"{}\" fo:margin-bottom=\"{}\" fo:text-indent=\"{}\"",
+        to_pt(shape.padding_left / 2 + (-indent).max(0)),
+        to_pt(shape.padding_right / 2),
+        to_pt(shape.margin_top / 2),
+        to_pt(shape.margin_bottom / 2),
+        to_pt(indent),
+    ));
+
+    let kind = shape
+        .line_spacing_kind
+        .as_ref()
+        .unwrap_or(&shape.line_space_kind_old);
+    let line_spacing = shape
+        .line_spacing
+        .map_or(shape.line_space_old, |line_spacing| line_spacing as i32);
+    match kind {
+        LineSpacingKind::Percent => {
+            properties.push_str(&format!(" fo:line-height=\"{}%\"", line_spacing))
+        }
+        LineSpacingKind::Fixed => {
+            properties.push_str(&format!(" fo:line-height=\"{}\"", to_pt(line_spacing)))
+        }
+        LineSpacingKind::BetweenLine => {
+            properties.push_str(&format!(" style:line-spacing=\"{}\"", to_pt(line_spacing)))
+        }
+        LineSpacingKind::AtLeast => properties.push_str(&format!(
+            " style:line-height-at-least=\"{}\"",
+            to_pt(line_spacing)
+        )),
+    }
+
+    if shape.keep_with_next {
+        properties.push_str(" fo:keep-with-next=\"always\"");
+    }
+    if shape.keep_lines {
+        properties.push_str(" fo:keep-together=\"always\"");
+    }
+    if shape.page_break_before {
+        properties.push_str(" fo:break-before=\"page\"");
+    }
+    let lines = if shape.widow_orphan { 2 } else { 0 };
+    properties.push_str(&format!(" fo:widows=\"{0}\" fo:orphans=\"{0}\"", lines));
+
+    format!("<style:paragraph-properties{}/>", properties)
+}
+
+/// 글자 모양을 `style:text-properties`의 속성으로 바꾼다
+fn text_properties(doc_info: &DocInfo, shape: &CharShape) -> String {
+    let id_mappings = &doc_info.id_mappings;
+
+    let mut properties = String::new();
+
+    // 라틴 문자는 영문 글꼴, 동아시아 문자는 한글 글꼴로 나타낸다
+    if let Some(font) = id_mappings.english_fonts.get(shape.font_ids[1] as usize) {
+        properties.push_str(&format!(" fo:font-family=\"{}\"", font_family(&font.name)));
+    }
+    if let Some(font) = id_mappings.korean_fonts.get(shape.font_ids[0] as usize) {
+        properties.push_str(&format!(
+            " style:font-family-asian=\"{}\"",
+            font_family(&font.name)
+        ));
+    }
+
+    // 기준 크기에 한글의 상대 크기를 적용한다
+    let size = shape.base_size * shape.font_sizes[0] as i32 / 100;
+    properties.push_str(&format!(
+        " fo:font-size=\"{0}\" style:font-size-asian=\"{0}\"",
+        to_pt(size)
+    ));
+    if shape.font_spacings[0] != 0 {
+        // 자간은 글자 크기에 대한 백분율이다
+        properties.push_str(&format!(
+            " fo:letter-spacing=\"{}\"",
+            to_pt(size * shape.font_spacings[0] as i32 / 100)
+        ));
+    }
+    if shape.font_scales[0] != 100 {
+        properties.push_str(&format!(" style:text-scale=\"{}%\"", shape.font_scales[0]));
+    }
+
+    if shape.bold {
+        properties.push_str(" fo:font-weight=\"bold\" style:font-weight-asian=\"bold\"");
+    }
+    if shape.italic {
+        properties.push_str(" fo:font-style=\"italic\" style:font-style-asian=\"italic\"");
+    }
+    properties.push_str(&format!(" fo:color=\"{}\"", shape.color.to_hex()));
+    if shape.shade_color.to_hex() != "#FFFFFF" {
+        properties.push_str(&format!(
+            " fo:background-color=\"{}\"",
+            shape.shade_color.to_hex()
+        ));
+    }
+
+    let line = match shape.underline_kind {
+        UnderlineKind::Bottom => Some("underline"),
+        UnderlineKind::Top => Some("overline"),
+        UnderlineKind::None => None,
+    };
+    if let Some(line) = line {
+        let (style, kind) = line_style(&shape.underline_shape);
+        properties.push_str(&format!(
+            " style:text-{0}-style=\"{1}\" style:text-{0}-type=\"{2}\" style:text-{0}-width=\"auto\" style:text-{0}-color=\"{3}\"",
+            line,
+            style,
+            kind,
+            shape.underline_color.to_hex()
+        ));
+    }
+    if shape.strike {
+        properties.push_str(" style:text-line-through-style=\"solid\"");
+    }
+
+    if shape.supscript {
+        properties.push_str(" style:text-position=\"super 58%\"");
+    } else if shape.subscript {
+        properties.push_str(" style:text-position=\"sub 58%\"");
+    }
+
+    properties
+}
+
+/// 테두리/배경을 `style:table-cell-properties`의 속성으로 바꾼다
+fn border_fill_properties(border_fill: &BorderFill) -> String {
+    let mut properties = String::new();
+
+    for (side, border) in ["left", "right", "top", "bottom"]
+        .iter()
+        .zip(&border_fill.borders)
+    {
+        let kind = LineStyle::from_border(&border.kind).to_css();
+        if kind == "none" {
+            properties.push_str(&format!(" fo:border-{}=\"none\"", side));
+        } else {
+            properties.push_str(&format!(
+                " fo:border-{}=\"{}mm {} {}\"",
+                side,
+                border_width(border.width),
+                kind,
+                border.color.to_hex()
+            ));
+        }
+    }
+
+    // NOTE: ODF는 셀 배경에 그러데이션을 쓸 수 없어 첫 색으로 채운다
+    let fill = match &border_fill.fill.content {
+        FillContent::Color(color) => Some(color.background_color.to_hex()),
+        FillContent::Gradation(gradation) => gradation.colors.first().map(|c| c.to_hex()),
+        _ => None,
+    };
+    if let Some(fill) = fill {
+        properties.push_str(&format!(" fo:background-color=\"{}\"", fill));
+    }
+
+    properties
+}
+
+fn meta(summary_info: Option<&SummaryInfo>) -> String {
+    let mut xml = format!("<office:document-meta{}><office:meta>", NAMESPACES);
+
+    if let Some(summary_info) = summary_info {
+        for (element, value) in [
+            ("dc:title", summary_info.title()),
+            ("dc:subject", summary_info.subject()),
+            ("meta:initial-creator", summary_info.author()),
+            ("meta:keyword", summary_info.keywords()),
+            ("dc:description", summary_info.comments()),
+            ("dc:creator", summary_info.last_saved_by()),
+        ] {
+            if let Some(value) = value.filter(|value| !value.is_empty()) {
+                xml.push_str(&format!("<{0}>{1}</{0}>", element, escape(value)));
+            }
+        }
+    }
+    xml.push_str("</office:meta></office:document-meta>");
+
+    xml
+}
+
+fn zip_xml(zip: &mut ZipWriter, name: &str, xml: &str) {
+    zip.add_file(name, format!("{}{}", XML_DECLARATION, xml).as_bytes(), true);
+}
+
+/// ODF에서 쓸 수 있는 그림의 MIME 형식
+fn image_media_type(extension: &str) -> Option<&'static str> {
+    match extension {
+        "png" => Some("image/png"),
+        "jpg" | "jpeg" => Some("image/jpeg"),
+        "gif" => Some("image/gif"),
+        "bmp" => Some("image/bmp"),
+        "svg" => Some("image/svg+xml"),
+        "tif" | "tiff" => Some("image/tiff"),
+        "wmf" => Some("image/x-wmf"),
+        "emf" => Some("image/x-emf"),
+        _ => None,
+    }
+}
+
+/// 글꼴 이름에 공백이 있다면 따옴표로 감싼다
+fn font_family(name: &str) -> String {
+    let name = escape(&name.replace(['\'', '"'], ""));
+    if name.contains(' ') {
+        format!("'{}'", name)
+    } else {
+        name
+    }
+}
+
+/// ODF 밑줄 모양과 종류(한 줄, 두 줄)
+fn line_style(kind: &BorderKind) -> (&'static str, &'static str) {
+    match kind {
+        BorderKind::Dash => ("dash", "single"),
+        BorderKind::Dot | BorderKind::Circle => ("dotted", "single"),
+        BorderKind::DashDot => ("dot-dash", "single"),
+        BorderKind::DashDotDot => ("dot-dot-dash", "single"),
+        BorderKind::LongDash => ("long-dash", "single"),
+        BorderKind::DoubleSlim
+        | BorderKind::SlimThick
+        | BorderKind::TickSlim
+        | BorderKind::SlimTickSlim => ("solid", "double"),
+        BorderKind::Wave => ("wave", "single"),
+        BorderKind::DoubleWave => ("wave", "double"),
+        _ => ("solid", "single"),
+    }
+}
+
+/// 본문 글자를 바꾼다
+///
+/// 탭과 줄 바꿈은 `text:tab`, `text:line-break`로 바꾸고, ODF는 이어진 공백을 하나로 줄이므로
+/// 공백 뒤의 공백은 `text:s`로 바꾼다.
+fn escape_text(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut spaces = 0;
+    let mut after_space = true;
+    for c in text.chars() {
+        if c == ' ' && after_space {
+            spaces += 1;
+            continue;
+        }
+        if spaces > 0 {
+            out.push_str(&format!("<text:s text:c=\"{}\"/>", spaces));
+            spaces = 0;
+        }
+
+        match c {
+            '\t' => out.push_str("<text:tab/>"),
+            '\n' => out.push_str("<text:line-break/>"),
+            _ => out.push_str(&escape(&c.to_string())),
+        }
+        after_space = matches!(c, ' ' | '\t' | '\n');
+    }
+    if spaces > 0 {
+        out.push_str(&format!("<text:s text:c=\"{}\"/>", spaces));
+    }
+
+    out
+}
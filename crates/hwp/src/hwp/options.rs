@@ -0,0 +1,240 @@
+use std::io::{Cursor, Read, Seek};
+
+use byteorder::LittleEndian;
+use cfb::CompoundFile;
+use flate2::read::DeflateDecoder;
+
+use super::{
+    error::Error,
+    header::Header,
+    record::{reader::RecordReader, tags::BodyTextRecord, Record},
+    utils::distribute::try_decrypt_distributed,
+};
+
+/// 문서를 읽을 때 적용할 제한
+///
+/// 압축 폭탄이나 조작된 크기로 메모리를 고갈시키는 문서를 열지 않도록 한다.
+/// `HWP::from_bytes_with_options`, `Preview::from_bytes`, `Storage::from_bytes`, `triage::analyze`에서
+/// 같은 의미로 적용하며, 스트림의 크기는 압축을 풀기 전과 푼 뒤에 모두 확인한다.
+#[derive(Debug, Clone)]
+pub struct ParseOptions {
+    /// 압축을 푼 스트림의 최대 크기 (바이트)
+    pub max_stream_size: u64,
+    /// 레코드 하나의 최대 크기 (바이트)
+    pub max_record_size: u32,
+    /// 레코드의 최대 깊이 (level)
+    pub max_level: u32,
+    /// 문서 전체의 최대 문단 수
+    pub max_paragraphs: usize,
+    /// 문서 전체의 최대 컨트롤 수
+    pub max_controls: usize,
+    /// 압축을 푼 바이너리 데이터의 최대 크기 (바이트)
+    pub max_bin_data_size: u64,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self {
+            max_stream_size: 256 * 1024 * 1024,
+            max_record_size: 16 * 1024 * 1024,
+            max_level: 64,
+            max_paragraphs: 1_000_000,
+            max_controls: 1_000_000,
+            max_bin_data_size: 256 * 1024 * 1024,
+        }
+    }
+}
+
+impl ParseOptions {
+    /// 제한 없이 읽는다
+    pub fn unlimited() -> Self {
+        Self {
+            max_stream_size: u64::MAX,
+            max_record_size: u32::MAX,
+            max_level: u32::MAX,
+            max_paragraphs: usize::MAX,
+            max_controls: usize::MAX,
+            max_bin_data_size: u64::MAX,
+        }
+    }
+}
+
+/// 넘어선 제한
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Limit {
+    StreamSize,
+    RecordSize,
+    Level,
+    Paragraphs,
+    Controls,
+    BinDataSize,
+}
+
+/// 문서를 읽으면서 제한을 확인한다
+///
+/// 문단과 컨트롤의 수는 문서 전체에서 세므로 하나의 문서를 읽는 동안 같은 값을 사용한다.
+pub(crate) struct Limiter {
+    options: ParseOptions,
+    paragraphs: usize,
+    controls: usize,
+}
+
+impl Limiter {
+    pub fn new(options: &ParseOptions) -> Self {
+        Self {
+            options: options.clone(),
+            paragraphs: 0,
+            controls: 0,
+        }
+    }
+
+    pub fn unlimited() -> Self {
+        Self::new(&ParseOptions::unlimited())
+    }
+
+    pub fn options(&self) -> &ParseOptions {
+        &self.options
+    }
+
+    /// 스트림의 배포용 문서 암호화와 압축을 풀어서 읽는다. 스트림이 없다면 None을 반환한다
+    pub fn read_stream<T: Read + Seek>(
+        &self,
+        cfb: &mut CompoundFile<T>,
+        header: &Header,
+        path: &str,
+        distributed: bool,
+    ) -> Result<Option<Vec<u8>>, Error> {
+        match self.read_raw_stream(cfb, path)? {
+            Some(data) => self
+                .decode_stream(data, path, header.flags.compressed, distributed)
+                .map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// 스트림을 그대로 읽는다. 스트림이 없다면 None을 반환한다
+    pub fn read_raw_stream<T: Read + Seek>(
+        &self,
+        cfb: &mut CompoundFile<T>,
+        path: &str,
+    ) -> Result<Option<Vec<u8>>, Error> {
+        let mut stream = match cfb.open_stream(path) {
+            Ok(stream) => stream,
+            Err(_) => return Ok(None),
+        };
+
+        let (limit, kind) = self.stream_limit(path);
+        read_to_end_limited(&mut stream, limit, kind, path).map(Some)
+    }
+
+    /// 읽은 스트림의 배포용 문서 암호화와 압축을 푼다
+    pub fn decode_stream(
+        &self,
+        mut data: Vec<u8>,
+        path: &str,
+        compressed: bool,
+        distributed: bool,
+    ) -> Result<Vec<u8>, Error> {
+        if distributed {
+            data = try_decrypt_distributed(&data).ok_or_else(|| invalid_stream(path))?;
+        }
+
+        if compressed {
+            let (limit, kind) = self.stream_limit(path);
+            let mut decoder = DeflateDecoder::new(Cursor::new(data));
+            data = read_to_end_limited(&mut decoder, limit, kind, path)?;
+        }
+
+        Ok(data)
+    }
+
+    /// NOTE: 바이너리 데이터는 본문과 따로 `max_bin_data_size`로 제한한다
+    fn stream_limit(&self, path: &str) -> (u64, Limit) {
+        if path.starts_with("/BinData/") {
+            (self.options.max_bin_data_size, Limit::BinDataSize)
+        } else {
+            (self.options.max_stream_size, Limit::StreamSize)
+        }
+    }
+
+    /// 레코드를 순서대로 읽으며 크기와 깊이를 확인한다
+    pub fn read_records(&self, data: &[u8], path: &str) -> Result<Vec<Record>, Error> {
+        let mut reader = Cursor::new(data);
+        let mut records = vec![];
+
+        while reader.position() < data.len() as u64 {
+            let (tag_id, level, size) = reader
+                .read_record_meta::<LittleEndian>()
+                .map_err(|_| invalid_stream(path))?;
+
+            if size > self.options.max_record_size {
+                return Err(limit_exceeded(Limit::RecordSize, path));
+            }
+            if level > self.options.max_level {
+                return Err(limit_exceeded(Limit::Level, path));
+            }
+
+            // NOTE: 잘린 마지막 레코드는 남은 데이터만 읽는다
+            let mut buf = vec![];
+            (&mut reader)
+                .take(size as u64)
+                .read_to_end(&mut buf)
+                .map_err(|_| invalid_stream(path))?;
+
+            records.push(Record::new(tag_id, level, size, buf));
+        }
+
+        Ok(records)
+    }
+
+    /// 본문의 문단과 컨트롤 수를 더하고 제한을 넘는지 확인한다
+    pub fn count_section(&mut self, records: &[Record], path: &str) -> Result<(), Error> {
+        for record in records {
+            if record.tag_id == BodyTextRecord::HWPTAG_PARA_HEADER as u32 {
+                self.paragraphs += 1;
+            } else if record.tag_id == BodyTextRecord::HWPTAG_CTRL_HEADER as u32 {
+                self.controls += 1;
+            }
+        }
+
+        if self.paragraphs > self.options.max_paragraphs {
+            return Err(limit_exceeded(Limit::Paragraphs, path));
+        }
+        if self.controls > self.options.max_controls {
+            return Err(limit_exceeded(Limit::Controls, path));
+        }
+
+        Ok(())
+    }
+}
+
+/// 압축을 풀되 제한을 넘으면 멈춘다
+pub(crate) fn read_to_end_limited<T: Read>(
+    reader: &mut T,
+    limit: u64,
+    kind: Limit,
+    path: &str,
+) -> Result<Vec<u8>, Error> {
+    let mut data = vec![];
+    reader
+        .take(limit.saturating_add(1))
+        .read_to_end(&mut data)
+        .map_err(|_| invalid_stream(path))?;
+
+    if data.len() as u64 > limit {
+        return Err(limit_exceeded(kind, path));
+    }
+
+    Ok(data)
+}
+
+fn limit_exceeded(limit: Limit, path: &str) -> Error {
+    Error::LimitExceeded {
+        limit,
+        path: path.to_string(),
+    }
+}
+
+pub(crate) fn invalid_stream(path: &str) -> Error {
+    Error::InvalidStream(path.to_string())
+}
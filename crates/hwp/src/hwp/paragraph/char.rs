@@ -4,6 +4,7 @@ use byteorder::{LittleEndian, ReadBytesExt};
 
 #[repr(u16)]
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CharControls {
     Unusable = 0,
     LineBreak = 10,
@@ -21,6 +22,7 @@ pub enum CharControls {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Char {
     CharCode(u16),
     CharControl(CharControls),
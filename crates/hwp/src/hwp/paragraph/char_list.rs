@@ -3,6 +3,7 @@ use std::io::Cursor;
 use super::char::{read_char, Char, CharControls};
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CharList {
     pub chars: Vec<Char>,
 }
@@ -14,7 +15,8 @@ impl CharList {
     }
 
     pub fn from_data(data: Vec<u8>, count: usize) -> Self {
-        let mut chars = Vec::with_capacity(count as usize);
+        // NOTE: 글자 수는 문서에 기록된 값이므로 데이터 크기 이상 미리 할당하지 않는다
+        let mut chars = Vec::with_capacity(count.min(data.len() / 2));
         let mut reader = Cursor::new(data);
 
         let mut i = 0;
@@ -24,7 +26,7 @@ impl CharList {
             }
             let char = read_char(&mut reader);
             match char {
-                Char::CharCode(_) => {
+                Char::CharCode(_) | Char::CharControl(_) => {
                     i += 1;
                 }
                 _ => {
@@ -37,6 +39,44 @@ impl CharList {
         Self { chars }
     }
 
+    /// 글자마다 문단 안의 시작 위치를 반환. 컨트롤은 8로 세며, 마지막 값은 문단 끝이다
+    pub fn positions(&self) -> Vec<u32> {
+        let mut positions = Vec::with_capacity(self.chars.len() + 1);
+
+        let mut position = 0;
+        positions.push(position);
+        for char in &self.chars {
+            position += match char {
+                Char::CharCode(_) | Char::CharControl(_) => 1,
+                _ => 8,
+            };
+            positions.push(position);
+        }
+
+        positions
+    }
+
+    /// 문단 글자 레코드(`HWPTAG_PARA_TEXT`)와 같은 WCHAR 배열로 바꾼다
+    pub fn units(&self) -> Vec<u16> {
+        let mut units = Vec::with_capacity(self.chars.len());
+        for char in &self.chars {
+            match char {
+                Char::CharCode(code) => units.push(*code),
+                Char::CharControl(control) => units.push(control.clone() as u16),
+                Char::InlineControl(code, data) | Char::ExtendedControl(code, data) => {
+                    units.push(*code);
+                    units.extend(
+                        data.chunks_exact(2)
+                            .map(|pair| u16::from_le_bytes([pair[0], pair[1]])),
+                    );
+                    units.push(*code);
+                }
+            }
+        }
+
+        units
+    }
+
     /// 컨트롤 개수를 반환
     pub fn extend_control_count(&self) -> usize {
         self.chars.iter().fold(0, |result, char| match char {
@@ -3,6 +3,7 @@ use std::io::Read;
 use byteorder::{LittleEndian, ReadBytesExt};
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CharShape {
     /// 글자 모양이 바뀌는 시작 위치
     pub start_position: u32,
@@ -10,6 +10,7 @@ use crate::hwp::{
 
 /// 단 정의
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ColumnControl {
     pub ctrl_id: u32,
     /// 단 종류
@@ -70,6 +71,7 @@ impl ColumnControl {
 /// 단 방향
 #[repr(u16)]
 #[derive(Debug, Clone, PartialEq, Eq, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ColumnKind {
     /// 일반 다단
     Normal,
@@ -81,6 +83,7 @@ pub enum ColumnKind {
 
 #[repr(u16)]
 #[derive(Debug, Clone, PartialEq, Eq, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ColumnDirection {
     /// 왼쪽부터
     Left,
@@ -14,6 +14,7 @@ use super::paragraph_list::ParagraphList;
 
 /// 개체 공통 속성
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CommonProperties {
     /// 컨트롤 ID
     pub ctrl_id: u32,
@@ -214,6 +215,7 @@ impl CommonProperties {
 /// 세로 위치의 기준
 #[repr(u8)]
 #[derive(Debug, Clone, PartialEq, Eq, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum VerticalRelativeTo {
     Paper,
     Page,
@@ -223,6 +225,7 @@ pub enum VerticalRelativeTo {
 /// 배열 방식
 #[repr(u8)]
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Align {
     Top,
     Center,
@@ -255,6 +258,7 @@ fn map_to_align(value: u32, rel_to: u8) -> Align {
 /// 가로 배열 방식
 #[repr(u8)]
 #[derive(Debug, Clone, PartialEq, Eq, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum HorizontalRelativeTo {
     Paper,
     Page,
@@ -265,6 +269,7 @@ pub enum HorizontalRelativeTo {
 /// 오브젝트 폭의 기준
 #[repr(u8)]
 #[derive(Debug, Clone, PartialEq, Eq, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum WidthRelativeTo {
     Paper,
     Page,
@@ -276,6 +281,7 @@ pub enum WidthRelativeTo {
 /// 오브젝트 높이의 기준
 #[repr(u8)]
 #[derive(Debug, Clone, PartialEq, Eq, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum HeightRelativeTo {
     Paper,
     Page,
@@ -285,6 +291,7 @@ pub enum HeightRelativeTo {
 /// 오브젝트 주위를 텍스트가 어떻게 흘러갈지 지정하는 옵션
 #[repr(u8)]
 #[derive(Debug, Clone, PartialEq, Eq, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TextWrap {
     /// bound rect를 따라
     Square,
@@ -303,6 +310,7 @@ pub enum TextWrap {
 /// 오브젝트의 좌/우 어느 쪽에 글을 배치할지
 #[repr(u8)]
 #[derive(Debug, Clone, PartialEq, Eq, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TextFlow {
     BothSides,
     LeftOnly,
@@ -313,6 +321,7 @@ pub enum TextFlow {
 /// 이 개체가 속하는 번호 범주
 #[repr(u8)]
 #[derive(Debug, Clone, PartialEq, Eq, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum NumberingKind {
     None,
     Figure,
@@ -321,12 +330,14 @@ pub enum NumberingKind {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Offset {
     pub vertical: u32,
     pub horizontal: u32,
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Caption {
     /// 문단 리스트
     pub paragraph_list: ParagraphList,
@@ -377,6 +388,7 @@ impl Caption {
 
 #[repr(u32)]
 #[derive(Debug, Clone, PartialEq, Eq, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CaptionAlign {
     Left,
     Right,
@@ -10,6 +10,7 @@ use crate::hwp::{
 
 /// 글상자
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DrawText {
     /// 문단 리스트
     pub paragraph_list: ParagraphList,
@@ -24,6 +25,7 @@ pub struct DrawText {
     /// 텍스트 문자열의 최대 폭
     pub last_width: u32,
     /// 스펙에 정의되지 않은 바이트
+    #[cfg_attr(feature = "serde", serde(with = "crate::hwp::utils::base64"))]
     pub unknown: Vec<u8>,
 }
 
@@ -16,6 +16,7 @@ type TransformationMatrix = [[f64; 3]; 3];
 
 /// 개체 요소 속성
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ElementProperties {
     /// 컨트롤 ID
     pub ctrl_id: u32,
@@ -164,6 +165,7 @@ impl ElementProperties {
 
 /// 테두리선 정보
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Outline {
     /// 선 색상
     pub color: ColorRef,
@@ -231,6 +233,7 @@ impl Outline {
 
 #[repr(u8)]
 #[derive(Debug, Clone, PartialEq, Eq, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum EndCap {
     Round,
     Flat,
@@ -238,6 +241,7 @@ pub enum EndCap {
 
 #[repr(u8)]
 #[derive(Debug, Clone, PartialEq, Eq, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum OutlineStyle {
     Normal,
     Outer,
@@ -248,6 +252,7 @@ pub enum OutlineStyle {
 /// NOTE: 창모양은 문서에 누락되어있음. (HWPX참고)
 #[repr(u8)]
 #[derive(Debug, Clone, PartialEq, Eq, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ArrowStyle {
     /// 모양 없음
     None,
@@ -274,6 +279,7 @@ pub enum ArrowStyle {
 /// 화살표 사이즈
 #[repr(u8)]
 #[derive(Debug, Clone, PartialEq, Eq, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ArrowSize {
     /// 작은-작은
     SmallSmall,
@@ -296,6 +302,7 @@ pub enum ArrowSize {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Shadow {
     /// 그림자 종류
     pub kind: ShadowKind,
@@ -338,6 +345,7 @@ impl Shadow {
 
 #[repr(u8)]
 #[derive(Debug, Clone, PartialEq, Eq, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ShadowKind {
     /// 없음
     None,
@@ -9,6 +9,7 @@ use super::common_properties::CommonProperties;
 
 /// 한글 97 수식
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Equation {
     /// 개체 공통 속성
     pub common_properties: CommonProperties,
@@ -34,6 +35,7 @@ impl Equation {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EquationRecord {
     pub script: String,
     pub font: String,
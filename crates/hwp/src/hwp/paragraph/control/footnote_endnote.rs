@@ -4,6 +4,7 @@ use super::paragraph_list::ParagraphList;
 
 /// 머리말 / 꼬리말
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FootnoteEndnote {
     pub paragraph_list: ParagraphList,
 }
@@ -4,6 +4,7 @@ use super::paragraph_list::ParagraphList;
 
 /// 숨은 설명
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HiddenComment {
     pub paragraph_list: ParagraphList,
 }
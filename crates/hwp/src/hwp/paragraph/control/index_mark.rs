@@ -4,6 +4,7 @@ use crate::hwp::record::{Record, reader::RecordReader};
 
 /// 찾아보기 표식
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IndexMark {
     /// 컨트롤 ID
     pub ctrl_id: u32,
@@ -32,6 +32,8 @@ use crate::hwp::{
 use self::{
     book_mark::Bookmark,
     column::ColumnControl,
+    common_properties::CommonProperties,
+    draw_text::DrawText,
     equation::Equation,
     footnote_endnote::FootnoteEndnote,
     header_footer::HeaderFooter,
@@ -43,12 +45,21 @@ use self::{
     page_hiding::PageHiding,
     page_number_control::PageNumberControl,
     page_number_position::PageNumberPosition,
+    paragraph_list::ParagraphList,
     section::SectionControl,
     shape_object::{
-        arc::ShapeArcControl, container::ContainerControl, curve::ShapeCurveControl,
-        ellipse::ShapeEllipseControl, gen_shape_object::GenShapeObjectControl,
-        line::ShapeLineControl, ole::OleControl, picture::PictureControl,
-        polygon::ShapePolygonControl, rectangle::ShapeRectangleControl,
+        arc::ShapeArcControl,
+        container::{ContainerContent, ContainerControl},
+        content::ShapeObjectContent,
+        curve::ShapeCurveControl,
+        ellipse::ShapeEllipseControl,
+        gen_shape_object::GenShapeObjectControl,
+        line::ShapeLineControl,
+        ole::OleControl,
+        picture::{PictureControl, PictureRecord},
+        polygon::ShapePolygonControl,
+        rectangle::ShapeRectangleControl,
+        video::VideoControl,
     },
     sub_text::SubText,
     table::TableControl,
@@ -56,6 +67,7 @@ use self::{
 };
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Control {
     // 개체 공통 속성 컨트롤
     Table(TableControl),
@@ -71,6 +83,7 @@ pub enum Control {
     Ole(OleControl),
     Container(ContainerControl),
     ConnectLine(ShapeLineControl),
+    Video(VideoControl),
 
     // 개체 이외 컨트롤
     AutoNumber(AutoNumber),
@@ -96,6 +109,131 @@ pub enum Control {
     Unknown(UnknownControl),
 }
 
+impl Control {
+    /// 개체 공통 속성. 개체가 아닌 컨트롤이라면 None을 반환한다
+    pub fn common_properties(&self) -> Option<&CommonProperties> {
+        match self {
+            Control::Table(control) => Some(&control.common_properties),
+            Control::GenShapeObject(control) => Some(&control.common_properties),
+            Control::ShapeLine(control) | Control::ConnectLine(control) => {
+                Some(&control.common_properties)
+            }
+            Control::ShapeRectangle(control) => Some(&control.common_properties),
+            Control::ShapeEllipse(control) => Some(&control.common_properties),
+            Control::ShapeArc(control) => Some(&control.common_properties),
+            Control::ShapePolygon(control) => Some(&control.common_properties),
+            Control::ShapeCurve(control) => Some(&control.common_properties),
+            Control::Equation(control) => Some(&control.common_properties),
+            Control::Picture(control) => Some(&control.common_properties),
+            Control::Ole(control) => Some(&control.common_properties),
+            Control::Container(control) => Some(&control.common_properties),
+            Control::Video(control) => Some(&control.common_properties),
+            _ => None,
+        }
+    }
+
+    /// 그리기 개체의 글상자. 묶음 개체라면 하위 개체의 글상자를 모두 반환한다
+    pub fn draw_texts(&self) -> Vec<&DrawText> {
+        let draw_text = match self {
+            Control::GenShapeObject(control) => {
+                let mut draw_texts: Vec<&DrawText> = control.draw_text.iter().collect();
+                if let ShapeObjectContent::Container(content) = &control.content {
+                    collect_draw_texts(content, &mut draw_texts);
+                }
+                return draw_texts;
+            }
+            Control::ShapeLine(control) | Control::ConnectLine(control) => &control.draw_text,
+            Control::ShapeRectangle(control) => &control.draw_text,
+            Control::ShapeEllipse(control) => &control.draw_text,
+            Control::ShapeArc(control) => &control.draw_text,
+            Control::ShapePolygon(control) => &control.draw_text,
+            Control::ShapeCurve(control) => &control.draw_text,
+            Control::Container(control) => {
+                let mut draw_texts = vec![];
+                collect_draw_texts(&control.content, &mut draw_texts);
+                return draw_texts;
+            }
+            _ => return vec![],
+        };
+
+        draw_text.iter().collect()
+    }
+
+    /// 컨트롤 안의 문단 리스트. 캡션, 표의 셀, 글상자 등을 문서에 기록된 순서대로 반환한다
+    pub fn paragraph_lists(&self) -> Vec<&ParagraphList> {
+        let mut lists = vec![];
+        if let Some(caption) = self
+            .common_properties()
+            .and_then(|properties| properties.caption.as_ref())
+        {
+            lists.push(&caption.paragraph_list);
+        }
+
+        match self {
+            Control::Table(table) => {
+                lists.extend(table.cells.iter().map(|cell| &cell.paragraph_list));
+            }
+            Control::Header(header_footer) | Control::Footer(header_footer) => {
+                lists.push(&header_footer.paragraph_list);
+            }
+            Control::Footnote(note) | Control::Endnote(note) => {
+                lists.push(&note.paragraph_list);
+            }
+            Control::HiddenComment(comment) => lists.push(&comment.paragraph_list),
+            _ => {
+                lists.extend(
+                    self.draw_texts()
+                        .into_iter()
+                        .map(|draw_text| &draw_text.paragraph_list),
+                );
+            }
+        }
+
+        lists
+    }
+
+    /// 그림. 그리기 개체나 묶음 개체 안의 그림도 모두 반환한다
+    pub fn pictures(&self) -> Vec<&PictureRecord> {
+        match self {
+            Control::Picture(control) => vec![&control.content],
+            Control::GenShapeObject(control) => match &control.content {
+                ShapeObjectContent::Picture(picture) => vec![picture],
+                ShapeObjectContent::Container(content) => {
+                    let mut pictures = vec![];
+                    collect_pictures(content, &mut pictures);
+                    pictures
+                }
+                _ => vec![],
+            },
+            Control::Container(control) => {
+                let mut pictures = vec![];
+                collect_pictures(&control.content, &mut pictures);
+                pictures
+            }
+            _ => vec![],
+        }
+    }
+}
+
+fn collect_draw_texts<'a>(content: &'a ContainerContent, draw_texts: &mut Vec<&'a DrawText>) {
+    for child in &content.children {
+        draw_texts.extend(child.draw_text.as_ref());
+        if let ShapeObjectContent::Container(content) = &child.content {
+            collect_draw_texts(content, draw_texts);
+        }
+    }
+}
+
+fn collect_pictures<'a>(content: &'a ContainerContent, pictures: &mut Vec<&'a PictureRecord>) {
+    for child in &content.children {
+        match &child.content {
+            ShapeObjectContent::Picture(picture) => pictures.push(picture),
+            ShapeObjectContent::Container(content) => collect_pictures(content, pictures),
+            _ => {}
+        }
+    }
+}
+
 pub fn parse_control(cursor: &mut RecordCursor, version: &Version) -> Control {
     let mut record = cursor.current();
     assert_eq!(
@@ -110,93 +248,175 @@ pub fn parse_control(cursor: &mut RecordCursor, version: &Version) -> Control {
 
     // NOTE: (@hahnlee) 한글 표준 문서에는 누락된 컨트롤이 있다
     // https://www.hancom.com/board/devmanualList.do
-    match ctrl_id {
+    match control_kind(ctrl_id) {
         // 개체 공통 속성 컨트롤
-        make_4chid!('t', 'b', 'l', ' ') => {
+        Some(ControlKind::Table) => {
             Control::Table(TableControl::from_record(&mut record, cursor, version))
         }
-        make_4chid!('g', 's', 'o', ' ') => Control::GenShapeObject(
+        Some(ControlKind::GenShapeObject) => Control::GenShapeObject(
             GenShapeObjectControl::from_record(&mut record, cursor, version),
         ),
-        make_4chid!('$', 'l', 'i', 'n') => {
+        Some(ControlKind::ShapeLine) => {
             Control::ShapeLine(ShapeLineControl::from_record(&mut record, cursor, version))
         }
-        make_4chid!('$', 'r', 'e', 'c') => Control::ShapeRectangle(
+        Some(ControlKind::ShapeRectangle) => Control::ShapeRectangle(
             ShapeRectangleControl::from_record(&mut record, cursor, version),
         ),
-        make_4chid!('$', 'e', 'l', 'l') => Control::ShapeEllipse(ShapeEllipseControl::from_record(
+        Some(ControlKind::ShapeEllipse) => Control::ShapeEllipse(ShapeEllipseControl::from_record(
             &mut record,
             cursor,
             version,
         )),
-        make_4chid!('$', 'a', 'r', 'c') => {
+        Some(ControlKind::ShapeArc) => {
             Control::ShapeArc(ShapeArcControl::from_record(&mut record, cursor, version))
         }
-        make_4chid!('$', 'p', 'o', 'l') => Control::ShapePolygon(ShapePolygonControl::from_record(
+        Some(ControlKind::ShapePolygon) => Control::ShapePolygon(ShapePolygonControl::from_record(
             &mut record,
             cursor,
             version,
         )),
-        make_4chid!('$', 'c', 'u', 'r') => {
+        Some(ControlKind::ShapeCurve) => {
             Control::ShapeCurve(ShapeCurveControl::from_record(&mut record, cursor, version))
         }
-        make_4chid!('e', 'q', 'e', 'd') => {
+        Some(ControlKind::Equation) => {
             Control::Equation(Equation::from_record(&mut record, cursor, version))
         }
-        make_4chid!('$', 'p', 'i', 'c') => {
+        Some(ControlKind::Picture) => {
             Control::Picture(PictureControl::from_record(&mut record, cursor, version))
         }
-        make_4chid!('$', 'o', 'l', 'e') => {
+        Some(ControlKind::Ole) => {
             Control::Ole(OleControl::from_record(&mut record, cursor, version))
         }
-        make_4chid!('$', 'c', 'o', 'n') => {
+        Some(ControlKind::Container) => {
             Control::Container(ContainerControl::from_record(&mut record, cursor, version))
         }
-        make_4chid!('$', 'c', 'o', 'l') => {
+        Some(ControlKind::ConnectLine) => {
             Control::ConnectLine(ShapeLineControl::from_record(&mut record, cursor, version))
         }
-
-        make_4chid!('c', 'o', 'l', 'd') => Control::Column(ColumnControl::from_record(&mut record)),
-        make_4chid!('a', 't', 'n', 'o') => {
-            Control::AutoNumber(AutoNumber::from_record(&mut record))
-        }
-        make_4chid!('n', 'w', 'n', 'o') => Control::NewNumber(NewNumber::from_record(&mut record)),
-        make_4chid!('p', 'g', 'h', 'd') => {
-            Control::PageHiding(PageHiding::from_record(&mut record))
+        Some(ControlKind::Video) => {
+            Control::Video(VideoControl::from_record(&mut record, cursor, version))
         }
-        make_4chid!('p', 'g', 'c', 't') => {
+
+        // 개체 이외 컨트롤
+        Some(ControlKind::Column) => Control::Column(ColumnControl::from_record(&mut record)),
+        Some(ControlKind::AutoNumber) => Control::AutoNumber(AutoNumber::from_record(&mut record)),
+        Some(ControlKind::NewNumber) => Control::NewNumber(NewNumber::from_record(&mut record)),
+        Some(ControlKind::PageHiding) => Control::PageHiding(PageHiding::from_record(&mut record)),
+        Some(ControlKind::PageNumberControl) => {
             Control::PageNumberControl(PageNumberControl::from_record(&mut record))
         }
-        make_4chid!('p', 'g', 'n', 'p') => {
+        Some(ControlKind::PageNumberPosition) => {
             Control::PageNumberPosition(PageNumberPosition::from_record(&mut record))
         }
-        make_4chid!('i', 'd', 'x', 'm') => Control::IndexMark(IndexMark::from_record(&mut record)),
-        make_4chid!('b', 'o', 'k', 'm') => {
+        Some(ControlKind::IndexMark) => Control::IndexMark(IndexMark::from_record(&mut record)),
+        Some(ControlKind::Bookmark) => {
             Control::Bookmark(Bookmark::from_record(&mut record, cursor))
         }
-        make_4chid!('t', 'c', 'p', 's') => Control::OverType(OverType::from_record(&mut record)),
-        make_4chid!('t', 'd', 'u', 't') => Control::SubText(SubText::from_record(&mut record)),
+        Some(ControlKind::OverType) => Control::OverType(OverType::from_record(&mut record)),
+        Some(ControlKind::SubText) => Control::SubText(SubText::from_record(&mut record)),
 
         // 개체 이외 컨트롤 + 문단리스트
-        make_4chid!('s', 'e', 'c', 'd') => {
+        Some(ControlKind::SectionDefinition) => {
             Control::SectionDefinition(SectionControl::from_record(&mut record, cursor, version))
         }
-        make_4chid!('h', 'e', 'a', 'd') => {
+        Some(ControlKind::Header) => {
             Control::Header(HeaderFooter::from_record_cursor(cursor, version))
         }
-        make_4chid!('f', 'o', 'o', 't') => {
+        Some(ControlKind::Footer) => {
             Control::Footer(HeaderFooter::from_record_cursor(cursor, version))
         }
-        make_4chid!('f', 'n', ' ', ' ') => {
+        Some(ControlKind::Footnote) => {
             Control::Footnote(FootnoteEndnote::from_record_cursor(cursor, version))
         }
-        make_4chid!('e', 'n', ' ', ' ') => {
+        Some(ControlKind::Endnote) => {
             Control::Endnote(FootnoteEndnote::from_record_cursor(cursor, version))
         }
-        make_4chid!('t', 'c', 'm', 't') => {
+        Some(ControlKind::HiddenComment) => {
             Control::HiddenComment(HiddenComment::from_record_cursor(cursor, version))
         }
 
+        // 필드 컨트롤은 해석하지 않는다
+        Some(ControlKind::Field) | None => {
+            Control::Unknown(UnknownControl::from_record(&mut record, cursor))
+        }
+    }
+}
+
+/// 컨트롤 ID가 가리키는 컨트롤 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ControlKind {
+    Table,
+    GenShapeObject,
+    ShapeLine,
+    ShapeRectangle,
+    ShapeEllipse,
+    ShapeArc,
+    ShapePolygon,
+    ShapeCurve,
+    Equation,
+    Picture,
+    Ole,
+    Container,
+    ConnectLine,
+    Video,
+    Column,
+    AutoNumber,
+    NewNumber,
+    PageHiding,
+    PageNumberControl,
+    PageNumberPosition,
+    IndexMark,
+    Bookmark,
+    OverType,
+    SubText,
+    SectionDefinition,
+    Header,
+    Footer,
+    Footnote,
+    Endnote,
+    HiddenComment,
+    Field,
+}
+
+/// 컨트롤 ID로 컨트롤 종류를 찾는다. 한글 표준 문서에 없는 ID라면 None을 반환한다
+fn control_kind(ctrl_id: u32) -> Option<ControlKind> {
+    let kind = match ctrl_id {
+        // 개체 공통 속성 컨트롤
+        make_4chid!('t', 'b', 'l', ' ') => ControlKind::Table,
+        make_4chid!('g', 's', 'o', ' ') => ControlKind::GenShapeObject,
+        make_4chid!('$', 'l', 'i', 'n') => ControlKind::ShapeLine,
+        make_4chid!('$', 'r', 'e', 'c') => ControlKind::ShapeRectangle,
+        make_4chid!('$', 'e', 'l', 'l') => ControlKind::ShapeEllipse,
+        make_4chid!('$', 'a', 'r', 'c') => ControlKind::ShapeArc,
+        make_4chid!('$', 'p', 'o', 'l') => ControlKind::ShapePolygon,
+        make_4chid!('$', 'c', 'u', 'r') => ControlKind::ShapeCurve,
+        make_4chid!('e', 'q', 'e', 'd') => ControlKind::Equation,
+        make_4chid!('$', 'p', 'i', 'c') => ControlKind::Picture,
+        make_4chid!('$', 'o', 'l', 'e') => ControlKind::Ole,
+        make_4chid!('$', 'c', 'o', 'n') => ControlKind::Container,
+        make_4chid!('$', 'c', 'o', 'l') => ControlKind::ConnectLine,
+        make_4chid!('$', 'v', 'i', 'd') => ControlKind::Video,
+
+        // 개체 이외 컨트롤
+        make_4chid!('c', 'o', 'l', 'd') => ControlKind::Column,
+        make_4chid!('a', 't', 'n', 'o') => ControlKind::AutoNumber,
+        make_4chid!('n', 'w', 'n', 'o') => ControlKind::NewNumber,
+        make_4chid!('p', 'g', 'h', 'd') => ControlKind::PageHiding,
+        make_4chid!('p', 'g', 'c', 't') => ControlKind::PageNumberControl,
+        make_4chid!('p', 'g', 'n', 'p') => ControlKind::PageNumberPosition,
+        make_4chid!('i', 'd', 'x', 'm') => ControlKind::IndexMark,
+        make_4chid!('b', 'o', 'k', 'm') => ControlKind::Bookmark,
+        make_4chid!('t', 'c', 'p', 's') => ControlKind::OverType,
+        make_4chid!('t', 'd', 'u', 't') => ControlKind::SubText,
+
+        // 개체 이외 컨트롤 + 문단리스트
+        make_4chid!('s', 'e', 'c', 'd') => ControlKind::SectionDefinition,
+        make_4chid!('h', 'e', 'a', 'd') => ControlKind::Header,
+        make_4chid!('f', 'o', 'o', 't') => ControlKind::Footer,
+        make_4chid!('f', 'n', ' ', ' ') => ControlKind::Footnote,
+        make_4chid!('e', 'n', ' ', ' ') => ControlKind::Endnote,
+        make_4chid!('t', 'c', 'm', 't') => ControlKind::HiddenComment,
+
         // 필드 컨트롤
         make_4chid!('%', 'u', 'n', 'k')
         | make_4chid!('%', 'd', 't', 'e')
@@ -231,7 +451,17 @@ pub fn parse_control(cursor: &mut RecordCursor, version: &Version) -> Control {
         | make_4chid!('%', '%', 'm', 'r')
         | make_4chid!('%', '%', 'm', 'e')
         | make_4chid!('%', 'c', 'p', 'r')
-        | make_4chid!('%', 't', 'o', 'c')
-        | _ => Control::Unknown(UnknownControl::from_record(&mut record, cursor)),
-    }
+        | make_4chid!('%', 't', 'o', 'c') => ControlKind::Field,
+
+        _ => return None,
+    };
+
+    Some(kind)
+}
+
+/// 한글 표준 문서에 정의된 컨트롤 ID인지 확인한다
+///
+/// 해석하지 않고 `Control::Unknown`으로 남기는 필드 컨트롤도 알려진 ID로 본다
+pub fn is_known_ctrl_id(ctrl_id: u32) -> bool {
+    control_kind(ctrl_id).is_some()
 }
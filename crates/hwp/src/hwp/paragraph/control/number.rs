@@ -12,6 +12,7 @@ use super::section::{NumberShape, format_number_shape};
 /// 번호 종류
 #[repr(u32)]
 #[derive(Debug, Clone, PartialEq, Eq, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum NumberKind {
     /// 쪽 번호
     Page,
@@ -29,6 +30,7 @@ pub enum NumberKind {
 
 /// 자동 번호
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AutoNumber {
     /// 컨트롤 ID
     pub ctrl_id: u32,
@@ -86,6 +88,7 @@ impl AutoNumber {
 
 /// 새 번호 지정
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NewNumber {
     /// 컨트롤 ID
     pub ctrl_id: u32,
@@ -4,6 +4,7 @@ use crate::hwp::record::{reader::RecordReader, Record};
 
 /// 글자 겹침
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OverType {
     /// 컨트롤 ID
     pub ctrl_id: u32,
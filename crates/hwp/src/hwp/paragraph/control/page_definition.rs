@@ -9,6 +9,7 @@ use crate::hwp::{
 
 /// 페이지 정의
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PageDefinition {
     /// 용지 가로 크기
     pub width: u32,
@@ -59,6 +60,7 @@ impl PageDefinition {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Padding {
     pub left: u32,
     pub right: u32,
@@ -74,6 +76,7 @@ pub struct Padding {
 
 #[repr(u8)]
 #[derive(Debug, Clone, PartialEq, Eq, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Landscape {
     /// 좁게
     Narrowly,
@@ -83,6 +86,7 @@ pub enum Landscape {
 
 #[repr(u8)]
 #[derive(Debug, Clone, PartialEq, Eq, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum GutterKind {
     /// 한쪽 편집
     LeftOnly,
@@ -4,6 +4,7 @@ use crate::hwp::{record::Record, utils::bits::get_flag};
 
 /// 감추기
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PageHiding {
     /// 컨트롤 ID
     pub ctrl_id: u32,
@@ -9,6 +9,7 @@ use crate::hwp::record::Record;
 /// 쪽 번호를 홀수쪽, 짝수쪽, 양쪽 모두에 표시할지를 설정하는 요소
 /// https://www.hancom.com/board/devmanualList.do?artcl_seq=6139
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PageNumberControl {
     pub ctrl_id: u32,
     pub kind: PageNumberControlKind,
@@ -28,6 +29,7 @@ impl PageNumberControl {
 
 #[repr(u32)]
 #[derive(Debug, Clone, PartialEq, Eq, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PageNumberControlKind {
     /// 양 쪽
     Both,
@@ -8,6 +8,7 @@ use super::section::NumberShape;
 
 /// 페이지 번호 위치
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PageNumberPosition {
     /// 컨트롤 ID
     pub ctrl_id: u32,
@@ -52,6 +53,7 @@ impl PageNumberPosition {
 
 #[repr(u32)]
 #[derive(Debug, Clone, PartialEq, Eq, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DisplayPosition {
     /// 쪽 번호 없음
     None,
@@ -10,6 +10,7 @@ use crate::hwp::{
 
 /// 문단 리스트
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ParagraphList {
     pub header: ParagraphListHeader,
     pub paragraphs: Vec<Paragraph>,
@@ -36,6 +37,7 @@ impl ParagraphList {
 
 /// 문단 해더
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ParagraphListHeader {
     /// 문단 수
     pub count: u32,
@@ -68,6 +70,7 @@ impl ParagraphListHeader {
 
 #[repr(u8)]
 #[derive(Debug, Clone, PartialEq, Eq, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Direction {
     Horizontal,
     Vertical,
@@ -75,6 +78,7 @@ pub enum Direction {
 
 #[repr(u8)]
 #[derive(Debug, Clone, PartialEq, Eq, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum LineBreak {
     /// 일반적인 줄바꿈
     Normal,
@@ -86,6 +90,7 @@ pub enum LineBreak {
 
 #[repr(u8)]
 #[derive(Debug, Clone, PartialEq, Eq, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum VerticalAlign {
     Top,
     Center,
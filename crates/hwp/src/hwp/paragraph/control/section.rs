@@ -13,6 +13,7 @@ use crate::hwp::{
 };
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SectionControl {
     /// 컨트롤 ID
     pub ctrl_id: u32,
@@ -67,6 +68,7 @@ pub struct SectionControl {
     pub footnote_shape: FootnoteEndnoteShape,
     /// 미주 모양 정보
     pub endnote_shape: FootnoteEndnoteShape,
+    #[cfg_attr(feature = "serde", serde(with = "crate::hwp::utils::base64"))]
     pub unknown: Vec<u8>,
 }
 
@@ -175,6 +177,7 @@ impl SectionControl {
 
 #[repr(u32)]
 #[derive(Debug, Clone, PartialEq, Eq, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TextDirection {
     Horizontal,
     Vertical,
@@ -182,6 +185,7 @@ pub enum TextDirection {
 
 /// 각주 / 미주 모양
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FootnoteEndnoteShape {
     /// 번호 모양
     pub number_shape: NumberShape,
@@ -252,6 +256,7 @@ impl FootnoteEndnoteShape {
 /// 번호종류, hwpx 표준문서 참고
 #[repr(u32)]
 #[derive(Debug, Clone, PartialEq, Eq, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum NumberShape {
     /// 1, 2, 3
     Digit,
@@ -13,6 +13,7 @@ use crate::hwp::{
 
 /// 호
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ShapeArcControl {
     /// 개체 공통 속성
     pub common_properties: CommonProperties,
@@ -47,6 +48,7 @@ impl ShapeArcControl {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ArcRecord {
     /// 호(ARC)의 종류
     pub arc_kind: ArcKind,
@@ -87,6 +89,7 @@ impl ArcRecord {
 
 #[repr(u8)]
 #[derive(Debug, Clone, PartialEq, Eq, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ArcKind {
     /// 호
     Normal,
@@ -11,6 +11,7 @@ use super::content::{parse_content, ShapeObjectContent};
 
 /// 묶음 개체
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ContainerControl {
     /// 개체 공통 속성
     pub common_properties: CommonProperties,
@@ -35,6 +36,7 @@ impl ContainerControl {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ContainerContent {
     pub children: Vec<ContainerElement>,
 }
@@ -58,6 +60,7 @@ impl ContainerContent {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ContainerElement {
     /// 개체 요소 속성
     pub element_properties: ElementProperties,
@@ -8,10 +8,11 @@ use crate::hwp::{
 use super::{
     arc::ArcRecord, container::ContainerContent, curve::CurveRecord, ellipse::EllipseRecord,
     line::LineRecord, ole::OleRecord, picture::PictureRecord, polygon::PolygonRecord,
-    rectangle::RectangleRecord,
+    rectangle::RectangleRecord, video::VideoRecord,
 };
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ShapeObjectContent {
     Arc(ArcRecord),
     Container(ContainerContent),
@@ -23,6 +24,7 @@ pub enum ShapeObjectContent {
     Picture(PictureRecord),
     Polygon(PolygonRecord),
     Rectangle(RectangleRecord),
+    Video(VideoRecord),
     Unknown(UnknownRecord),
 }
 
@@ -62,6 +64,9 @@ pub fn parse_content(
         make_4chid!('$', 'c', 'o', 'l') => {
             ShapeObjectContent::ConnectLine(LineRecord::from_record_cursor(cursor))
         }
+        make_4chid!('$', 'v', 'i', 'd') => {
+            ShapeObjectContent::Video(VideoRecord::from_record_cursor(cursor))
+        }
         _ => ShapeObjectContent::Unknown(UnknownRecord::from_record_cursor(cursor)),
     }
 }
@@ -13,6 +13,7 @@ use crate::hwp::{
 
 /// 곡선
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ShapeCurveControl {
     /// 개체 공통 속성
     pub common_properties: CommonProperties,
@@ -47,6 +48,7 @@ impl ShapeCurveControl {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CurveRecord {
     /// 좌표
     pub points: Vec<Point>,
@@ -84,6 +86,7 @@ impl CurveRecord {
 
 #[repr(u8)]
 #[derive(Debug, Clone, PartialEq, Eq, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SegmentKind {
     Line,
     Curve,
@@ -16,6 +16,7 @@ use num::FromPrimitive;
 
 /// 타원
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ShapeEllipseControl {
     /// 개체 공통 속성
     pub common_properties: CommonProperties,
@@ -50,6 +51,7 @@ impl ShapeEllipseControl {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EllipseRecord {
     /// 호(ARC)로 바뀌었을 때, interval을 다시 계산해야 할 필요가 있는지 여부
     /// (interval - 원 위에 존재하는 두 점 사이의 거리)
@@ -11,6 +11,7 @@ use super::content::{parse_content, ShapeObjectContent};
 
 /// 그리기 객체
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GenShapeObjectControl {
     /// 개체 공통 속성
     pub common_properties: CommonProperties,
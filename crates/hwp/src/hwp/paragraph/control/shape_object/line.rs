@@ -13,6 +13,7 @@ use crate::hwp::{
 
 /// 선
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ShapeLineControl {
     /// 개체 공통 속성
     pub common_properties: CommonProperties,
@@ -47,6 +48,7 @@ impl ShapeLineControl {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LineRecord {
     /// 시작점 X 좌표
     pub start_x: i32,
@@ -60,6 +62,7 @@ pub struct LineRecord {
     /// 오른쪽(위쪽)으로 잡힘으로 인한 현상 때문에, 방향을 바로
     /// 잡아주기 위한 플래그.
     pub is_reverse_hs: Option<bool>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::hwp::utils::base64"))]
     pub unknown: Vec<u8>,
 }
 
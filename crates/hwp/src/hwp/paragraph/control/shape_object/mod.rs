@@ -9,3 +9,4 @@ pub mod ole;
 pub mod picture;
 pub mod polygon;
 pub mod rectangle;
+pub mod video;
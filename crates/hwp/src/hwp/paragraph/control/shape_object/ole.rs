@@ -8,6 +8,7 @@ use crate::hwp::{
 
 /// OLE
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OleControl {
     /// 개체 공통 속성
     pub common_properties: CommonProperties,
@@ -32,6 +33,7 @@ impl OleControl {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OleRecord {}
 
 impl OleRecord {
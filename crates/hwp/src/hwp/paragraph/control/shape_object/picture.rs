@@ -18,6 +18,7 @@ use crate::hwp::{
 
 /// 그림
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PictureControl {
     /// 개체 공통 속성
     pub common_properties: CommonProperties,
@@ -42,6 +43,7 @@ impl PictureControl {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PictureRecord {
     /// 테두리
     pub outline: PictureOutline,
@@ -136,6 +138,7 @@ impl PictureRecord {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PictureOutline {
     /// 선 색상
     pub color: ColorRef,
@@ -196,6 +199,7 @@ impl PictureOutline {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Rect {
     pub left_top: Point,
     pub right_top: Point,
@@ -215,6 +219,7 @@ impl Rect {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Point {
     pub x: i32,
     pub y: i32,
@@ -278,6 +283,7 @@ impl PictureEffect {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Shadow {
     /// 그림자 스타일
     pub style: ShadowStyle,
@@ -326,6 +332,7 @@ impl Shadow {
 
 #[repr(u8)]
 #[derive(Debug, Clone, PartialEq, Eq, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ShadowStyle {
     Outside,
     Inside,
@@ -333,6 +340,7 @@ pub enum ShadowStyle {
 
 #[repr(u8)]
 #[derive(Debug, Clone, PartialEq, Eq, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AlignStyle {
     TopLeft,
     Top,
@@ -346,6 +354,7 @@ pub enum AlignStyle {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EffectColor {
     /// 색상타입
     pub kind: EffectColorKind,
@@ -393,6 +402,7 @@ impl EffectColor {
 
 #[repr(u8)]
 #[derive(Debug, Clone, PartialEq, Eq, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum EffectColorKind {
     RGB,
     CMYK,
@@ -401,6 +411,7 @@ pub enum EffectColorKind {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum EffectColorValue {
     RGB(u32),
     CMYK(u32),
@@ -409,6 +420,7 @@ pub enum EffectColorValue {
 }
 
 #[derive(Debug, Clone, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum EffectColorEffectKind {
     Alpha,
     AlphaMod,
@@ -441,6 +453,7 @@ pub enum EffectColorEffectKind {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EffectColorEffect {
     /// 색상 효과 종류
     pub kind: EffectColorEffectKind,
@@ -459,6 +472,7 @@ impl EffectColorEffect {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Glow {
     /// 네온 투명도
     pub alpha: f32,
@@ -479,6 +493,7 @@ impl Glow {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SoftEdge {
     /// 부드러운 가장자리 반경
     pub radius: f32,
@@ -493,6 +508,7 @@ impl SoftEdge {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Reflection {
     /// 반사 스타일
     pub align: AlignStyle,
@@ -546,6 +562,7 @@ impl Reflection {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PictureAdditionalProperties {
     /// 그림 최초 생성 시 기준 이미지 너비
     pub width: u32,
@@ -11,6 +11,7 @@ use crate::hwp::{
 
 /// 다각형
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ShapePolygonControl {
     /// 개체 공통 속성
     pub common_properties: CommonProperties,
@@ -45,6 +46,7 @@ impl ShapePolygonControl {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PolygonRecord {
     /// 다각형 좌표
     pub points: Vec<Point>,
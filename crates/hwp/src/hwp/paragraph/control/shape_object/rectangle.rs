@@ -13,6 +13,7 @@ use super::picture::Point;
 
 /// 사각형
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ShapeRectangleControl {
     /// 개체 공통 속성
     pub common_properties: CommonProperties,
@@ -47,6 +48,7 @@ impl ShapeRectangleControl {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RectangleRecord {
     /// 사각형 모서리 곡률(%) 직각은 0, 둥근 모양은 20, 반원은 50,
     /// 그 외는 적당한 값을 % 단위로 사용한다.
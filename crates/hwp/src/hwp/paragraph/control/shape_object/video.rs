@@ -0,0 +1,137 @@
+use byteorder::{LittleEndian, ReadBytesExt};
+
+use crate::hwp::{
+    paragraph::control::{
+        common_properties::CommonProperties, element_properties::ElementProperties,
+    },
+    record::{reader::RecordReader, tags::BodyTextRecord, Record, RecordCursor},
+    version::Version,
+};
+
+/// 동영상
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VideoControl {
+    /// 개체 공통 속성
+    pub common_properties: CommonProperties,
+    /// 개체 요소 속성
+    pub element_properties: ElementProperties,
+    /// 내용
+    pub content: VideoRecord,
+}
+
+impl VideoControl {
+    pub fn from_record(record: &mut Record, cursor: &mut RecordCursor, version: &Version) -> Self {
+        let common_properties = CommonProperties::from_record(record, cursor, version);
+        let element_properties = ElementProperties::from_record_cursor(cursor, false);
+        let content = VideoRecord::from_record_cursor(cursor);
+
+        Self {
+            common_properties,
+            element_properties,
+            content,
+        }
+    }
+
+    /// 동영상의 폭
+    pub fn width(&self) -> u32 {
+        self.common_properties.width
+    }
+
+    /// 동영상의 높이
+    pub fn height(&self) -> u32 {
+        self.common_properties.height
+    }
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VideoRecord {
+    /// 동영상 타입
+    pub kind: VideoKind,
+    /// 비디오 파일이 사용하는 스토리지의 BinData ID (로컬 동영상일때만 사용)
+    pub video_bin_id: Option<u16>,
+    /// 웹 동영상 태그 (웹 동영상일때만 사용)
+    pub web_tag: Option<String>,
+    /// 썸네일 파일이 사용하는 스토리지의 BinData ID (알 수 없는 타입이라면 0)
+    pub thumbnail_bin_id: u16,
+}
+
+impl VideoRecord {
+    pub fn from_record_cursor(cursor: &mut RecordCursor) -> Self {
+        let record = cursor.current();
+        assert_eq!(record.tag_id, BodyTextRecord::HWPTAG_VIDEO_DATA as u32);
+
+        let mut reader = record.get_data_reader();
+
+        let kind = VideoKind::from_i32(reader.read_i32::<LittleEndian>().unwrap());
+
+        let video_bin_id = if kind == VideoKind::Local {
+            Some(reader.read_u16::<LittleEndian>().unwrap())
+        } else {
+            None
+        };
+
+        let web_tag = if kind == VideoKind::Web {
+            Some(reader.read_string::<LittleEndian>().unwrap())
+        } else {
+            None
+        };
+
+        // NOTE: 알 수 없는 타입은 뒤의 구조도 알 수 없어 썸네일을 읽지 않는다
+        let thumbnail_bin_id = match kind {
+            VideoKind::Unknown(_) => 0,
+            _ => reader.read_u16::<LittleEndian>().unwrap(),
+        };
+
+        Self {
+            kind,
+            video_bin_id,
+            web_tag,
+            thumbnail_bin_id,
+        }
+    }
+
+    /// 웹 동영상의 주소
+    ///
+    /// 태그가 `<iframe src="...">`처럼 HTML 태그라면 src 속성을, 아니라면 태그 전체를 반환한다
+    pub fn url(&self) -> Option<String> {
+        let tag = self.web_tag.as_ref()?;
+
+        let lower = tag.to_ascii_lowercase();
+        if let Some(start) = lower.find("src=") {
+            let rest = &tag[start + 4..];
+            let quote = rest.chars().next()?;
+            let url = if quote == '"' || quote == '\'' {
+                rest[1..].split(quote).next()?
+            } else {
+                rest.split(|c: char| c.is_whitespace() || c == '>').next()?
+            };
+
+            return Some(url.to_string());
+        }
+
+        Some(tag.trim().to_string())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum VideoKind {
+    /// 로컬 동영상
+    Local,
+    /// 웹 동영상
+    Web,
+    /// 알 수 없는 타입
+    Unknown(i32),
+}
+
+impl VideoKind {
+    pub fn from_i32(kind: i32) -> Self {
+        match kind {
+            0 => Self::Local,
+            1 => Self::Web,
+            _ => Self::Unknown(kind),
+        }
+    }
+}
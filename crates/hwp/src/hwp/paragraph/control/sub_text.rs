@@ -6,6 +6,7 @@ use crate::hwp::record::{reader::RecordReader, Record};
 
 /// 덧말
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SubText {
     /// 컨트롤 ID
     pub ctrl_id: u32,
@@ -50,6 +51,7 @@ impl SubText {
 
 #[repr(u8)]
 #[derive(Debug, Clone, PartialEq, Eq, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SubTextPosition {
     Top,
     Bottom,
@@ -58,6 +60,7 @@ pub enum SubTextPosition {
 
 #[repr(u8)]
 #[derive(Debug, Clone, PartialEq, Eq, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SubTextAlign {
     /// 양쪽 정렬
     Justify,
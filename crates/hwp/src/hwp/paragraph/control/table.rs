@@ -14,6 +14,7 @@ use super::{common_properties::CommonProperties, paragraph_list::ParagraphList};
 
 /// 표 컨트롤
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TableControl {
     /// 개체 공통 속성
     pub common_properties: CommonProperties,
@@ -49,9 +50,43 @@ impl TableControl {
             cells,
         }
     }
+
+    /// 셀 병합을 나타낼 수 있도록 표의 칸 너비를 정한다
+    ///
+    /// 한 칸짜리 셀의 너비를 먼저 쓰고, 합친 셀 중 너비를 모르는 칸이 하나뿐이라면 그 칸의 너비를 계산한다.
+    /// 끝내 너비를 알 수 없는 칸은 표의 너비를 칸 수로 나눈 값으로 한다.
+    pub fn column_widths(&self) -> Vec<u32> {
+        let count = self.record.cols as usize;
+        let mut widths: Vec<Option<u32>> = vec![None; count];
+
+        let mut cells: Vec<&Cell> = self.cells.iter().collect();
+        cells.sort_by_key(|cell| cell.col_span);
+        for _ in 0..2 {
+            for cell in &cells {
+                let start = cell.column as usize;
+                let end = (start + cell.col_span.max(1) as usize).min(count);
+                if start >= end {
+                    continue;
+                }
+
+                let unknown: Vec<usize> = (start..end).filter(|i| widths[*i].is_none()).collect();
+                if unknown.len() == 1 {
+                    let known: u32 = (start..end).filter_map(|i| widths[i]).sum();
+                    widths[unknown[0]] = Some(cell.width.saturating_sub(known).max(1));
+                }
+            }
+        }
+
+        let width = (self.common_properties.width / count.max(1) as u32).max(1);
+        widths
+            .into_iter()
+            .map(|column| column.unwrap_or(width))
+            .collect()
+    }
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TableRecord {
     pub page_break: PageBreak,
     pub repeat_header: bool,
@@ -68,6 +103,7 @@ pub struct TableRecord {
 
 #[repr(u32)]
 #[derive(Debug, Clone, PartialEq, Eq, FromPrimitive)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PageBreak {
     /// 나누지 않음
     None,
@@ -139,6 +175,7 @@ impl TableRecord {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ValidZone {
     /// 시작 열 주소
     pub start_column: u16,
@@ -165,6 +202,7 @@ impl ValidZone {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Cell {
     /// 문단 리스트
     pub paragraph_list: ParagraphList,
@@ -5,10 +5,12 @@ use byteorder::{LittleEndian, ReadBytesExt};
 use crate::hwp::record::{Record, RecordCursor};
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UnknownControl {
     /// 컨트롤 ID
     pub ctrl_id: u32,
     /// 데이터
+    #[cfg_attr(feature = "serde", serde(with = "crate::hwp::utils::base64"))]
     pub data: Vec<u8>,
     /// 레코드
     pub children: Vec<Record>,
@@ -5,6 +5,7 @@ use byteorder::{LittleEndian, ReadBytesExt};
 use crate::hwp::{utils::bits::get_flag, version::Version};
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ParagraphHeader {
     /// control mask
     pub ctrl_mask: CtrlMask,
@@ -86,6 +87,7 @@ impl ParagraphHeader {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CtrlMask {
     /// 구역/단 정의
     pub section_column_definition: bool,
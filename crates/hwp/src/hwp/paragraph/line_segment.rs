@@ -5,6 +5,7 @@ use byteorder::{LittleEndian, ReadBytesExt};
 use crate::hwp::utils::bits::get_flag;
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LineSegment {
     /// 텍스트 시작 위치
     pub start_position: u32,
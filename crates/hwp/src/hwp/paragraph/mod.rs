@@ -4,7 +4,11 @@ pub mod char_shape;
 pub mod control;
 pub mod header;
 pub mod line_segment;
+pub mod position;
+pub mod pua_map;
 pub mod range_tag;
+pub mod run;
+pub mod text_options;
 
 use self::{
     char::{Char, CharControls},
@@ -13,15 +17,25 @@ use self::{
     control::{parse_control, Control},
     header::ParagraphHeader,
     line_segment::LineSegment,
+    position::PositionMap,
     range_tag::RangeTag,
+    run::{Run, RunContent, RunStyle},
+    text_options::TextOptions,
 };
 
+use std::ops::Range;
+
 use super::{
+    doc_info::DocInfo,
     record::{tags::BodyTextRecord, Record, RecordCursor},
     version::Version,
 };
 
+/// 탭 (인라인 컨트롤)
+const TAB: u16 = 9;
+
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Paragraph {
     pub header: ParagraphHeader,
     pub char_list: CharList,
@@ -110,32 +124,218 @@ impl Paragraph {
     }
 
     pub fn to_string(&self) -> String {
-        let mut out = format!("");
+        self.to_text(&TextOptions::default())
+    }
+
+    /// 문단의 글자를 문자열로 바꾼다
+    ///
+    /// UTF-16 서로게이트 쌍을 한 글자로 합치며, 짝이 맞지 않는 서로게이트는 U+FFFD로 바꾼다.
+    /// 사용자 영역 문자 변환표가 있다면 변환표에 있는 문자를 바꾼다.
+    pub fn to_text(&self, options: &TextOptions) -> String {
+        let mut out = String::new();
+        self.write_text(&mut out, options, |out, control| {
+            if !options.controls {
+                return;
+            }
+
+            if let Control::AutoNumber(auto_number) = control {
+                out.push_str(&auto_number.to_string());
+            }
+        });
+
+        out
+    }
+
+    /// 글자 모양이 바뀌는 구간과 글자 모양 ID
+    ///
+    /// 첫 구간은 문단 처음부터, 마지막 구간은 문단 끝까지로 본다
+    pub(crate) fn char_shape_ranges(&self) -> Vec<(Range<u32>, u32)> {
+        self.char_shapes
+            .iter()
+            .enumerate()
+            .map(|(index, shape)| {
+                let start = if index == 0 { 0 } else { shape.start_position };
+                let end = self
+                    .char_shapes
+                    .get(index + 1)
+                    .map_or(u32::MAX, |next| next.start_position);
+
+                (start..end, shape.shape_id)
+            })
+            .collect()
+    }
+
+    /// 문단을 글자 모양이 같은 글자와 탭, 강제 줄 나눔, 확장 컨트롤로 나눈다
+    ///
+    /// 글자 모양은 `doc_info`에서 찾는다. 사용자 영역(PUA) 문자는 바꾸지 않는다.
+    pub fn runs<'a>(&'a self, doc_info: &'a DocInfo) -> Vec<Run<'a>> {
+        let options = TextOptions::default();
+        let ranges = self.char_shape_ranges();
+        let shape_id = |position: u32| {
+            ranges
+                .iter()
+                .find(|(range, _)| range.contains(&position))
+                .map(|(_, id)| *id)
+        };
+        let style = |position: u32| shape_id(position).and_then(|id| RunStyle::new(doc_info, id));
+
+        let mut runs = vec![];
+        let mut units = vec![];
+        let mut text = String::new();
+        // 모으고 있는 글자의 위치
+        let mut start = 0;
+        let mut end = 0;
+
+        let positions = self.char_list.positions();
 
         let mut i = 0;
-        for char in &self.char_list.chars {
-            match char {
+        for (index, char) in self.char_list.chars.iter().enumerate() {
+            let current = positions[index];
+            let position = positions[index + 1];
+
+            // 글자 모양이 바뀌면 모은 글자를 나눈다
+            if shape_id(start) != shape_id(current) {
+                push_units(&mut text, units.drain(..), &options);
+                push_text_run(&mut runs, &mut text, start..end, style(start));
+            }
+            if units.is_empty() && text.is_empty() {
+                start = current;
+            }
+
+            let content = match char {
                 Char::CharCode(code) => {
-                    out.push(char::from_u32((*code).into()).unwrap());
+                    units.push(*code);
+                    end = position;
+                    continue;
                 }
-
-                Char::CharControl(CharControls::LineBreak) => {
-                    out.push('\n');
+                Char::CharControl(CharControls::KeepWordSpace)
+                | Char::CharControl(CharControls::FixedWidthSpace)
+                | Char::CharControl(CharControls::Hyphen) => {
+                    push_units(&mut text, units.drain(..), &options);
+                    match char {
+                        Char::CharControl(CharControls::Hyphen) => text.push('\u{AD}'),
+                        _ => text.push('\u{A0}'),
+                    }
+                    end = position;
+                    continue;
                 }
+                Char::CharControl(CharControls::LineBreak) => RunContent::LineBreak,
+                Char::InlineControl(TAB, _) => RunContent::Tab,
                 Char::ExtendedControl(_, _) => {
-                    match &self.controls[i] {
-                        Control::AutoNumber(auto_number) => {
-                            out.push_str(&auto_number.to_string());
-                        }
-                        _ => {}
-                    };
-
+                    let control = self.controls.get(i);
                     i += 1;
+                    match control {
+                        Some(control) => RunContent::Control(control),
+                        None => continue,
+                    }
                 }
-                _ => {}
+                _ => continue,
             };
+
+            push_units(&mut text, units.drain(..), &options);
+            push_text_run(&mut runs, &mut text, start..end, style(start));
+            runs.push(Run {
+                range: current..position,
+                style: style(current),
+                content,
+            });
         }
+        push_units(&mut text, units, &options);
+        push_text_run(&mut runs, &mut text, start..end, style(start));
 
-        out
+        runs
+    }
+
+    /// 문단의 글자를 `out`에 붙인다. 확장 컨트롤은 위치에 맞게 `write_control`로 넘긴다
+    pub(crate) fn write_text<F: FnMut(&mut String, &Control)>(
+        &self,
+        out: &mut String,
+        options: &TextOptions,
+        write_control: F,
+    ) {
+        self.write_text_range(out, options, 0..u32::MAX, write_control);
+    }
+
+    /// 위치(`CharShape.start_position`와 같이 컨트롤은 8로 센다)가 `range` 안에 있는 글자만 `out`에 붙인다
+    pub(crate) fn write_text_range<F: FnMut(&mut String, &Control)>(
+        &self,
+        out: &mut String,
+        options: &TextOptions,
+        range: Range<u32>,
+        mut write_control: F,
+    ) {
+        let mut units = vec![];
+
+        let positions = self.char_list.positions();
+
+        let mut i = 0;
+        for (index, char) in self.char_list.chars.iter().enumerate() {
+            if !range.contains(&positions[index]) {
+                if let Char::ExtendedControl(_, _) = char {
+                    i += 1;
+                }
+                continue;
+            }
+
+            if let Char::CharCode(code) = char {
+                units.push(*code);
+                continue;
+            }
+
+            push_units(out, units.drain(..), options);
+
+            if let Char::ExtendedControl(_, _) = char {
+                if let Some(control) = self.controls.get(i) {
+                    write_control(out, control);
+                }
+
+                i += 1;
+            } else if let Some(text) = control_char(char, options) {
+                out.push(text);
+            }
+        }
+        push_units(out, units, options);
+    }
+
+    /// 문단의 위치와 `to_text(options)`로 바꾼 문자열의 위치를 서로 바꾸는 표를 만든다
+    pub fn position_map(&self, options: &TextOptions) -> PositionMap {
+        PositionMap::new(self, options)
+    }
+}
+
+/// 제어 문자를 나타낼 문자
+fn control_char(char: &Char, options: &TextOptions) -> Option<char> {
+    match char {
+        Char::CharControl(CharControls::LineBreak) => options.line_break,
+        Char::CharControl(CharControls::KeepWordSpace)
+        | Char::CharControl(CharControls::FixedWidthSpace) => options.non_breaking_space,
+        Char::CharControl(CharControls::Hyphen) => options.hyphen,
+        Char::InlineControl(TAB, _) => options.tab,
+        _ => None,
+    }
+}
+
+fn push_text_run<'a>(
+    runs: &mut Vec<Run<'a>>,
+    text: &mut String,
+    range: Range<u32>,
+    style: Option<RunStyle<'a>>,
+) {
+    if !text.is_empty() {
+        runs.push(Run {
+            range,
+            style,
+            content: RunContent::Text(std::mem::take(text)),
+        });
+    }
+}
+
+fn push_units<I: IntoIterator<Item = u16>>(out: &mut String, units: I, options: &TextOptions) {
+    for c in char::decode_utf16(units) {
+        let c = c.unwrap_or(char::REPLACEMENT_CHARACTER);
+        match &options.pua {
+            Some(pua) => pua.push(out, c),
+            None => out.push(c),
+        }
     }
 }
@@ -0,0 +1,151 @@
+use std::ops::Range;
+
+use super::{
+    char::Char, control::Control, control_char, push_units, text_options::TextOptions, Paragraph,
+};
+
+/// 문단 안의 한 글자의 위치
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Position {
+    /// `CharList.chars`에서의 순서
+    pub index: usize,
+    /// 문단 안의 위치 (`CharShape.start_position`와 같이 컨트롤은 8로 센다)
+    pub hwp: u32,
+    /// 문자열의 UTF-16 위치
+    pub utf16: usize,
+    /// 문자열의 UTF-8 바이트 위치
+    pub utf8: usize,
+}
+
+/// 문단의 위치와 `Paragraph::to_text`로 바꾼 문자열의 위치를 서로 바꾼다
+///
+/// 글자마다 시작 위치를 기록하며, 마지막 항목은 문단 끝이다. 문자열에 나타나지 않는 글자(컨트롤 등)는
+/// 다음 글자와 같은 문자열 위치를 갖는다.
+#[derive(Debug, Clone)]
+pub struct PositionMap {
+    positions: Vec<Position>,
+}
+
+impl PositionMap {
+    pub(crate) fn new(paragraph: &Paragraph, options: &TextOptions) -> Self {
+        let chars = &paragraph.char_list.chars;
+        let hwp = paragraph.char_list.positions();
+        let mut positions = Vec::with_capacity(chars.len() + 1);
+
+        let mut text = String::new();
+        let mut utf16 = 0;
+        let mut control_index = 0;
+
+        let mut index = 0;
+        while index < chars.len() {
+            positions.push(Position {
+                index,
+                hwp: hwp[index],
+                utf16,
+                utf8: text.len(),
+            });
+
+            let start = text.len();
+            match &chars[index] {
+                Char::CharCode(code) => {
+                    // 서로게이트 쌍은 앞 글자가 문자열을 갖는다
+                    let low = match chars.get(index + 1) {
+                        Some(Char::CharCode(low))
+                            if (0xD800..0xDC00).contains(code)
+                                && (0xDC00..0xE000).contains(low) =>
+                        {
+                            Some(*low)
+                        }
+                        _ => None,
+                    };
+                    match low {
+                        Some(low) => {
+                            push_units(&mut text, [*code, low], options);
+                            utf16 += text[start..].encode_utf16().count();
+
+                            index += 1;
+                            positions.push(Position {
+                                index,
+                                hwp: hwp[index],
+                                utf16,
+                                utf8: text.len(),
+                            });
+                        }
+                        None => {
+                            push_units(&mut text, [*code], options);
+                            utf16 += text[start..].encode_utf16().count();
+                        }
+                    }
+                }
+                char => {
+                    if let Char::ExtendedControl(_, _) = char {
+                        // NOTE: `to_text`와 같이 자동 번호만 문자열로 나타낸다
+                        if let Some(Control::AutoNumber(auto_number)) =
+                            paragraph.controls.get(control_index)
+                        {
+                            if options.controls {
+                                text.push_str(&auto_number.to_string());
+                            }
+                        }
+                        control_index += 1;
+                    } else if let Some(c) = control_char(char, options) {
+                        text.push(c);
+                    }
+                    utf16 += text[start..].encode_utf16().count();
+                }
+            }
+
+            index += 1;
+        }
+
+        positions.push(Position {
+            index: chars.len(),
+            hwp: hwp[chars.len()],
+            utf16,
+            utf8: text.len(),
+        });
+
+        Self { positions }
+    }
+
+    /// 문단 끝의 위치
+    pub fn end(&self) -> Position {
+        *self.positions.last().unwrap()
+    }
+
+    /// `chars`의 `index`번째 글자의 위치. `index`가 글자 수와 같다면 문단 끝
+    pub fn from_index(&self, index: usize) -> Option<Position> {
+        self.positions.get(index).copied()
+    }
+
+    /// 문단 안의 위치를 포함하는 글자의 위치
+    pub fn from_hwp(&self, hwp: u32) -> Position {
+        self.find(|position| position.hwp <= hwp)
+    }
+
+    /// 문자열의 UTF-16 위치를 포함하는 글자의 위치
+    pub fn from_utf16(&self, utf16: usize) -> Position {
+        self.find(|position| position.utf16 <= utf16)
+    }
+
+    /// 문자열의 UTF-8 바이트 위치를 포함하는 글자의 위치
+    pub fn from_utf8(&self, utf8: usize) -> Position {
+        self.find(|position| position.utf8 <= utf8)
+    }
+
+    /// 문단 안의 구간(`RangeTag` 등)을 문자열의 UTF-8 바이트 구간으로 바꾼다
+    pub fn hwp_to_utf8(&self, range: Range<u32>) -> Range<usize> {
+        self.from_hwp(range.start).utf8..self.from_hwp(range.end).utf8
+    }
+
+    /// 문자열의 UTF-8 바이트 구간을 문단 안의 구간으로 바꾼다
+    pub fn utf8_to_hwp(&self, range: Range<usize>) -> Range<u32> {
+        self.from_utf8(range.start).hwp..self.from_utf8(range.end).hwp
+    }
+
+    /// 조건을 만족하는 마지막 위치. 위치는 모두 정렬되어 있다
+    fn find<F: FnMut(&Position) -> bool>(&self, predicate: F) -> Position {
+        let index = self.positions.partition_point(predicate);
+        self.positions[index.max(1) - 1]
+    }
+}
@@ -0,0 +1,80 @@
+use std::collections::HashMap;
+
+use crate::hwp::error::Error;
+
+/// 사용자 영역(PUA) 문자를 표준 유니코드 문자열로 바꾸는 변환표
+///
+/// 한/글은 옛한글 음절과 일부 기호를 한컴 사용자 영역(HNC PUA) 문자로 저장한다.
+/// 옛한글 음절은 첫가끝 조합형 자모 (U+1100 ~ U+11FF, U+A960 ~ U+A97F, U+D7B0 ~ U+D7FF) 열로 바꿀 수 있다.
+///
+/// NOTE: 한컴 사용자 영역의 변환표는 공개된 명세가 없어 포함하지 않는다 (README의 지원하지 않는 기능).
+/// hypua2jamo 등 공개된 변환표를 [`PuaMap::from_table`] 형식으로 바꾸어 읽거나 [`PuaMap::insert`]로 채워야 한다.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PuaMap {
+    map: HashMap<u32, String>,
+}
+
+impl PuaMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 변환표를 읽는다
+    ///
+    /// 한 줄에 하나씩 사용자 영역 문자와 바꿀 문자들을 16진수 코드로 적는다. `#` 뒤는 주석이다.
+    ///
+    /// ```text
+    /// # 사용자 영역 문자    바꿀 문자열
+    /// E0BC    1100 119E
+    /// ```
+    pub fn from_table(table: &str) -> Result<Self, Error> {
+        let mut map = Self::new();
+
+        for (index, line) in table.lines().enumerate() {
+            let line = line.split('#').next().unwrap().trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let parse = |code: &str| u32::from_str_radix(code.trim_start_matches("U+"), 16).ok();
+            let mut codes = line.split_whitespace();
+            let code = codes.next().and_then(parse);
+            let value: Option<String> = codes
+                .map(|code| parse(code).and_then(char::from_u32))
+                .collect();
+
+            match (code, value) {
+                (Some(code), Some(value)) if !value.is_empty() => {
+                    map.insert(code, &value);
+                }
+                _ => return Err(Error::InvalidPuaTable(index + 1)),
+            }
+        }
+
+        Ok(map)
+    }
+
+    pub fn insert(&mut self, code: u32, value: &str) {
+        self.map.insert(code, value.to_string());
+    }
+
+    pub fn get(&self, code: u32) -> Option<&str> {
+        self.map.get(&code).map(String::as_str)
+    }
+
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// 변환표에 있는 문자를 바꾸어 붙인다
+    pub(crate) fn push(&self, out: &mut String, c: char) {
+        match self.get(c as u32) {
+            Some(value) => out.push_str(value),
+            None => out.push(c),
+        }
+    }
+}
@@ -3,6 +3,7 @@ use std::io::Read;
 use byteorder::{LittleEndian, ReadBytesExt};
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RangeTag {
     /// 영역 시작
     pub start_position: u32,
@@ -0,0 +1,86 @@
+use std::ops::Range;
+
+use crate::hwp::{
+    color_ref::ColorRef,
+    doc_info::{char_shape::CharShape, DocInfo},
+};
+
+use super::control::Control;
+
+/// 문단을 나눈 조각
+///
+/// 글자 모양이 같은 이어진 글자, 또는 탭, 강제 줄 나눔, 확장 컨트롤 하나를 담는다.
+#[derive(Debug, Clone)]
+pub struct Run<'a> {
+    /// 문단 안의 위치 (`CharShape.start_position`와 같이 컨트롤은 8로 센다)
+    pub range: Range<u32>,
+    /// 글자 모양. 문단에 글자 모양이 없거나 ID가 잘못되었다면 `None`
+    pub style: Option<RunStyle<'a>>,
+    /// 내용
+    pub content: RunContent<'a>,
+}
+
+#[derive(Debug, Clone)]
+pub enum RunContent<'a> {
+    /// 글자. 묶음 빈칸, 고정폭 빈칸은 U+00A0, 하이픈은 U+00AD로 바꾼다
+    Text(String),
+    /// 탭
+    Tab,
+    /// 강제 줄 나눔
+    LineBreak,
+    /// 확장 컨트롤 (표, 그림, 각주 등)
+    Control(&'a Control),
+}
+
+/// 문서 정보에서 찾은 글자 모양
+#[derive(Debug, Clone)]
+pub struct RunStyle<'a> {
+    /// 글자 모양 ID
+    pub id: u32,
+    /// 글자 모양
+    pub char_shape: &'a CharShape,
+    /// 언어별 글꼴 이름 (한글, 영어, 한자, 일어, 기타, 기호, 사용자)
+    pub font_names: [Option<&'a str>; 7],
+    /// 기준 크기 (pt)
+    pub size: f32,
+    /// 진하게 여부
+    pub bold: bool,
+    /// 기울임 여부
+    pub italic: bool,
+    /// 글자 색
+    pub color: &'a ColorRef,
+}
+
+impl<'a> RunStyle<'a> {
+    pub fn new(doc_info: &'a DocInfo, id: u32) -> Option<Self> {
+        let id_mappings = &doc_info.id_mappings;
+        let char_shape = id_mappings.char_shapes.get(id as usize)?;
+
+        let fonts = [
+            &id_mappings.korean_fonts,
+            &id_mappings.english_fonts,
+            &id_mappings.chinese_characters_fonts,
+            &id_mappings.japanese_fonts,
+            &id_mappings.etc_fonts,
+            &id_mappings.symbol_fonts,
+            &id_mappings.user_fonts,
+        ];
+        let mut font_names = [None; 7];
+        for (index, fonts) in fonts.iter().enumerate() {
+            font_names[index] = fonts
+                .get(char_shape.font_ids[index] as usize)
+                .map(|font| font.name.as_str());
+        }
+
+        Some(Self {
+            id,
+            char_shape,
+            font_names,
+            // NOTE: 기준 크기는 1/100pt 단위로 기록되어 있다
+            size: char_shape.base_size as f32 / 100.0,
+            bold: char_shape.bold,
+            italic: char_shape.italic,
+            color: &char_shape.color,
+        })
+    }
+}
@@ -0,0 +1,55 @@
+use super::pua_map::PuaMap;
+
+/// 문단을 문자열로 바꾸는 방법
+///
+/// 제어 문자를 나타낼 문자를 정한다. `None`이라면 문자열에 넣지 않는다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextOptions {
+    /// 강제 줄 나눔 (기본값 `\n`)
+    pub line_break: Option<char>,
+    /// 탭 (기본값 `\t`)
+    pub tab: Option<char>,
+    /// 묶음 빈칸, 고정폭 빈칸 (기본값 U+00A0)
+    pub non_breaking_space: Option<char>,
+    /// 하이픈 (기본값 U+00AD)
+    pub hyphen: Option<char>,
+    /// 자동 번호 등 내용을 문자열로 나타낼 수 있는 컨트롤을 넣을지 여부 (기본값 true)
+    pub controls: bool,
+    /// 사용자 영역(PUA) 문자 변환표. 없다면 그대로 둔다
+    pub pua: Option<PuaMap>,
+    /// 문서 전체를 바꿀 때 머리말/꼬리말을 넣을지 여부 (기본값 false)
+    pub headers_footers: bool,
+    /// 문서 전체를 바꿀 때 각주/미주를 번호로 표시하고 문서 끝에 모을지 여부 (기본값 true)
+    pub notes: bool,
+}
+
+impl Default for TextOptions {
+    fn default() -> Self {
+        Self {
+            line_break: Some('\n'),
+            tab: Some('\t'),
+            non_breaking_space: Some('\u{A0}'),
+            hyphen: Some('\u{AD}'),
+            controls: true,
+            pua: None,
+            headers_footers: false,
+            notes: true,
+        }
+    }
+}
+
+impl TextOptions {
+    /// 검색, 색인 등에 쓰기 위해 공백과 줄 바꿈만 남긴다
+    pub fn plain() -> Self {
+        Self {
+            line_break: Some('\n'),
+            tab: Some(' '),
+            non_breaking_space: Some(' '),
+            hyphen: None,
+            controls: true,
+            pua: None,
+            headers_footers: false,
+            notes: true,
+        }
+    }
+}
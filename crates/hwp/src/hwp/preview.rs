@@ -0,0 +1,104 @@
+use std::io::{Cursor, Read, Seek};
+
+use cfb::CompoundFile;
+
+use super::{
+    error::Error,
+    options::{Limiter, ParseOptions},
+};
+
+/// 미리보기 텍스트와 이미지
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Preview {
+    /// 미리보기 텍스트 (`PrvText`)
+    pub text: Option<String>,
+    /// 미리보기 이미지 (`PrvImage`)
+    pub image: Option<PreviewImage>,
+}
+
+impl Preview {
+    /// 본문을 해석하지 않고 미리보기 스트림만 읽는다
+    ///
+    /// 복합 파일이 아니라면 `Error::InvalidFile`, 스트림이 제한보다 크다면 `Error::LimitExceeded`를 반환한다.
+    pub fn from_bytes(bytes: &[u8], options: &ParseOptions) -> Result<Self, Error> {
+        let cursor = Cursor::new(bytes);
+        let mut cfb = CompoundFile::open(cursor).map_err(|_| Error::InvalidFile)?;
+
+        Self::read(&mut cfb, &Limiter::new(options))
+    }
+
+    /// 미리보기를 읽는다. 읽을 수 없는 스트림은 없는 것으로 본다
+    pub fn from_cfb<T: Read + Seek>(cfb: &mut CompoundFile<T>) -> Self {
+        Self::read(cfb, &Limiter::unlimited()).unwrap_or_default()
+    }
+
+    /// 제한을 확인하며 미리보기를 읽는다
+    pub(crate) fn read<T: Read + Seek>(
+        cfb: &mut CompoundFile<T>,
+        limiter: &Limiter,
+    ) -> Result<Self, Error> {
+        let text = limiter.read_raw_stream(cfb, "/PrvText")?.map(|data| {
+            let chars: Vec<u16> = data
+                .chunks_exact(2)
+                .map(|c| u16::from_le_bytes([c[0], c[1]]))
+                .take_while(|c| *c != 0)
+                .collect();
+
+            // NOTE: 미리보기는 길이 제한으로 잘려서 저장될 수 있어 손실을 허용한다
+            String::from_utf16_lossy(&chars)
+        });
+
+        let image = limiter
+            .read_raw_stream(cfb, "/PrvImage")?
+            .map(|data| PreviewImage {
+                kind: ImageKind::from_data(&data),
+                data,
+            });
+
+        Ok(Self { text, image })
+    }
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PreviewImage {
+    /// 이미지 형식
+    pub kind: ImageKind,
+    /// 이미지 데이터
+    #[cfg_attr(feature = "serde", serde(with = "crate::hwp::utils::base64"))]
+    pub data: Vec<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ImageKind {
+    Png,
+    Gif,
+    Bmp,
+    Unknown,
+}
+
+impl ImageKind {
+    fn from_data(data: &[u8]) -> Self {
+        if data.starts_with(&[0x89, b'P', b'N', b'G']) {
+            ImageKind::Png
+        } else if data.starts_with(b"GIF8") {
+            ImageKind::Gif
+        } else if data.starts_with(b"BM") {
+            ImageKind::Bmp
+        } else {
+            ImageKind::Unknown
+        }
+    }
+
+    /// 확장자
+    pub fn extension(&self) -> Option<&str> {
+        match self {
+            ImageKind::Png => Some("png"),
+            ImageKind::Gif => Some("gif"),
+            ImageKind::Bmp => Some("bmp"),
+            ImageKind::Unknown => None,
+        }
+    }
+}
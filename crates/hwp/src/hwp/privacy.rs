@@ -0,0 +1,391 @@
+use std::ops::Range;
+
+use super::{
+    error::Error,
+    options::ParseOptions,
+    paragraph::{control::Control, Paragraph},
+    record::{tags::BodyTextRecord, Record},
+    redact::{RedactSpan, Text},
+    HWP,
+};
+
+/// 개인 정보 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PersonalInfoKind {
+    /// 주민등록번호 (외국인등록번호 포함)
+    ResidentRegistrationNumber,
+    /// 전화번호
+    PhoneNumber,
+    /// 여권번호
+    PassportNumber,
+    /// 계좌번호
+    BankAccount,
+    /// 전자우편 주소
+    Email,
+}
+
+/// 문서 모델에서의 문단 위치
+///
+/// `sections[section].paragraphs[paragraph]`에서 시작하여 `nested`를 차례로 따라간다.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ParagraphLocation {
+    /// 구역 번호
+    pub section: usize,
+    /// 구역의 문단 번호
+    pub paragraph: usize,
+    /// 컨트롤 안의 문단이라면 바깥 컨트롤부터 차례로 담는다
+    pub nested: Vec<NestedParagraph>,
+}
+
+/// 컨트롤 안의 문단 위치
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NestedParagraph {
+    /// 문단의 `controls` 안에서의 번호
+    pub control: usize,
+    /// [`Control::paragraph_lists`] 안에서의 번호
+    pub list: usize,
+    /// 문단 리스트의 `paragraphs` 안에서의 번호
+    pub paragraph: usize,
+}
+
+/// 본문에서 찾은 개인 정보
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PersonalInfo {
+    pub kind: PersonalInfoKind,
+    /// 구역 스트림 경로
+    pub path: String,
+    /// 문서 모델에서의 문단 위치
+    pub location: ParagraphLocation,
+    /// 위치. 그대로 [`super::redact::redact_spans`]에 넘겨 가릴 수 있다
+    pub span: RedactSpan,
+    /// 찾은 문자열
+    pub text: String,
+}
+
+/// 문서의 모든 문단에서 개인 정보를 찾는다
+///
+/// 구역의 문단과 표의 셀, 글상자, 캡션, 머리말/꼬리말, 각주/미주, 숨은 설명 등
+/// 컨트롤 안의 문단을 문서 모델을 따라 살펴본다. 배포용 문서라면 `ViewText`의 본문을 살펴본다.
+///
+/// 문서를 읽을 때 `options`의 제한을 적용한다.
+pub fn scan(bytes: &[u8], options: &ParseOptions) -> Result<Vec<PersonalInfo>, Error> {
+    let hwp = HWP::from_bytes_with_options(bytes, options)?;
+    let (storage_name, sections) = match &hwp.view_texts {
+        Some(view_texts) => ("ViewText", &view_texts.sections),
+        None => ("BodyText", &hwp.body_texts.sections),
+    };
+
+    let mut scanner = Scanner {
+        path: String::new(),
+        location: ParagraphLocation::default(),
+        record_index: 0,
+        found: vec![],
+    };
+    for (section, content) in sections.iter().enumerate() {
+        scanner.path = format!("/{}/Section{}", storage_name, section);
+        scanner.location = ParagraphLocation {
+            section,
+            ..Default::default()
+        };
+        scanner.record_index = 0;
+        scanner.scan_paragraphs(&content.paragraphs);
+    }
+
+    Ok(scanner.found)
+}
+
+struct Scanner {
+    path: String,
+    location: ParagraphLocation,
+    /// 구역 스트림의 문단 머리 레코드를 센 번호 ([`RedactSpan::paragraph`])
+    record_index: usize,
+    found: Vec<PersonalInfo>,
+}
+
+impl Scanner {
+    fn scan_paragraphs(&mut self, paragraphs: &[Paragraph]) {
+        for (index, paragraph) in paragraphs.iter().enumerate() {
+            match self.location.nested.last_mut() {
+                Some(nested) => nested.paragraph = index,
+                None => self.location.paragraph = index,
+            }
+
+            self.scan_paragraph(paragraph);
+            self.record_index += 1;
+
+            // NOTE: 컨트롤 안의 문단은 문서에 기록된 순서대로 살펴봐야 레코드 번호가 맞는다
+            for (control_index, control) in paragraph.controls.iter().enumerate() {
+                if let Control::Unknown(control) = control {
+                    self.record_index += count_paragraphs(&control.children);
+                    continue;
+                }
+
+                for (list, paragraph_list) in control.paragraph_lists().into_iter().enumerate() {
+                    self.location.nested.push(NestedParagraph {
+                        control: control_index,
+                        list,
+                        paragraph: 0,
+                    });
+                    self.scan_paragraphs(&paragraph_list.paragraphs);
+                    self.location.nested.pop();
+                }
+            }
+            self.record_index += count_paragraphs(&paragraph.unknown);
+        }
+    }
+
+    fn scan_paragraph(&mut self, paragraph: &Paragraph) {
+        let text = Text::from_paragraph(&paragraph.char_list.units());
+
+        for (kind, range) in find_personal_info(&text.value) {
+            let position = text.to_positions(std::slice::from_ref(&range)).remove(0);
+            self.found.push(PersonalInfo {
+                kind,
+                path: self.path.clone(),
+                location: self.location.clone(),
+                span: RedactSpan {
+                    section: self.location.section,
+                    paragraph: self.record_index,
+                    start_position: position.start as u32,
+                    end_position: position.end as u32,
+                },
+                text: text.value[range].to_string(),
+            });
+        }
+    }
+}
+
+/// 해석하지 않은 레코드 안의 문단 수
+fn count_paragraphs(records: &[Record]) -> usize {
+    records
+        .iter()
+        .filter(|record| record.tag_id == BodyTextRecord::HWPTAG_PARA_HEADER as u32)
+        .count()
+}
+
+/// 문자열에서 개인 정보를 찾아 종류와 영역(바이트 위치)을 반환한다
+///
+/// [`super::redact::redact`]의 `matcher`로 쓸 수 있다.
+///
+/// NOTE: 형식만 확인하며 실제로 발급된 번호인지는 알 수 없다.
+/// 계좌번호는 은행마다 형식이 달라 `-`로 나눈 세 묶음 이상의 10~16자리 숫자 중 전화번호가 아닌 것으로 본다.
+pub fn find_personal_info(text: &str) -> Vec<(PersonalInfoKind, Range<usize>)> {
+    let bytes = text.as_bytes();
+
+    let mut found = find_numbers(bytes);
+    found.extend(find_passport_numbers(bytes));
+    found.extend(find_emails(bytes));
+    found.sort_by_key(|(_, range)| range.start);
+
+    found
+}
+
+/// 숫자 묶음으로 이루어진 주민등록번호, 전화번호, 계좌번호를 찾는다
+fn find_numbers(bytes: &[u8]) -> Vec<(PersonalInfoKind, Range<usize>)> {
+    let mut groups: Vec<Range<usize>> = vec![];
+    let mut index = 0;
+    while index < bytes.len() {
+        if bytes[index].is_ascii_digit() {
+            let start = index;
+            while index < bytes.len() && bytes[index].is_ascii_digit() {
+                index += 1;
+            }
+            groups.push(start..index);
+        } else {
+            index += 1;
+        }
+    }
+
+    // 묶음 사이의 구분자
+    let separator = |index: usize| -> Option<u8> {
+        let next = groups.get(index + 1)?;
+        let end = groups[index].end;
+        if next.start == end + 1 && matches!(bytes[end], b'-' | b'.' | b' ') {
+            Some(bytes[end])
+        } else {
+            None
+        }
+    };
+
+    let mut found = vec![];
+    let mut index = 0;
+    while index < groups.len() {
+        if !is_boundary(bytes, groups[index].start.checked_sub(1)) {
+            index += 1;
+            continue;
+        }
+
+        let separator_kind = separator(index);
+        let mut last = index;
+        while last - index < 3 && separator_kind.is_some() && separator(last) == separator_kind {
+            last += 1;
+        }
+
+        let matched = (index..=last).rev().find_map(|last| {
+            let end = groups[last].end;
+            let continued = separator_kind.is_some()
+                && bytes.get(end) == separator_kind.as_ref()
+                && bytes.get(end + 1).is_some_and(|c| c.is_ascii_digit());
+            if continued || !is_boundary(bytes, Some(end)) {
+                return None;
+            }
+
+            let digits: Vec<&str> = groups[index..=last]
+                .iter()
+                .map(|group| std::str::from_utf8(&bytes[group.clone()]).unwrap())
+                .collect();
+            classify_number(&digits, separator_kind).map(|kind| (kind, last))
+        });
+
+        match matched {
+            Some((kind, last)) => {
+                found.push((kind, groups[index].start..groups[last].end));
+                index = last + 1;
+            }
+            None => index += 1,
+        }
+    }
+
+    found
+}
+
+fn classify_number(digits: &[&str], separator: Option<u8>) -> Option<PersonalInfoKind> {
+    let lens: Vec<usize> = digits.iter().map(|digits| digits.len()).collect();
+
+    let resident_registration_number = match lens.as_slice() {
+        [6, 7] if matches!(separator, Some(b'-') | Some(b' ')) => Some(digits.concat()),
+        [13] => Some(digits[0].to_string()),
+        _ => None,
+    };
+    if let Some(number) = resident_registration_number {
+        if is_resident_registration_number(&number) {
+            return Some(PersonalInfoKind::ResidentRegistrationNumber);
+        }
+    }
+
+    let phone_number = match lens.as_slice() {
+        [_, 3..=4, 4] => is_area_code(digits[0]),
+        // 대표번호
+        [4, 4] => ["15", "16", "18"].iter().any(|p| digits[0].starts_with(p)),
+        [10..=11] => is_mobile_code(&digits[0][..3]),
+        _ => false,
+    };
+    if phone_number {
+        return Some(PersonalInfoKind::PhoneNumber);
+    }
+
+    let total: usize = lens.iter().sum();
+    if separator == Some(b'-')
+        && lens.len() >= 3
+        && lens.iter().all(|len| *len >= 2)
+        && (10..=16).contains(&total)
+    {
+        return Some(PersonalInfoKind::BankAccount);
+    }
+
+    None
+}
+
+/// 생년월일 6자리와 성별 1자리를 확인한다
+fn is_resident_registration_number(number: &str) -> bool {
+    let month: u32 = number[2..4].parse().unwrap();
+    let day: u32 = number[4..6].parse().unwrap();
+    let gender = number.as_bytes()[6];
+
+    (1..=12).contains(&month) && (1..=31).contains(&day) && (b'1'..=b'8').contains(&gender)
+}
+
+fn is_mobile_code(code: &str) -> bool {
+    matches!(code, "010" | "011" | "016" | "017" | "018" | "019")
+}
+
+fn is_area_code(code: &str) -> bool {
+    if is_mobile_code(code) {
+        return true;
+    }
+
+    match code {
+        "02" | "070" => true,
+        "0502" | "0503" | "0504" | "0505" | "0506" | "0507" | "0508" => true,
+        _ => {
+            let code = code.as_bytes();
+            code.len() == 3
+                && code[0] == b'0'
+                && (b'3'..=b'6').contains(&code[1])
+                && (b'1'..=b'5').contains(&code[2])
+        }
+    }
+}
+
+/// 여권번호 (영문 1자리 + 숫자 8자리, 또는 영문 1자리 + 숫자 3자리 + 영문 1자리 + 숫자 4자리)
+fn find_passport_numbers(bytes: &[u8]) -> Vec<(PersonalInfoKind, Range<usize>)> {
+    let mut found = vec![];
+
+    for start in 0..bytes.len() {
+        if !matches!(bytes[start], b'M' | b'S' | b'R' | b'O' | b'D' | b'G')
+            || !is_boundary(bytes, start.checked_sub(1))
+        {
+            continue;
+        }
+
+        let rest = &bytes[start + 1..];
+        let matched = rest.len() >= 8
+            && (rest[..8].iter().all(u8::is_ascii_digit)
+                || (rest[..3].iter().all(u8::is_ascii_digit)
+                    && rest[3].is_ascii_uppercase()
+                    && rest[4..8].iter().all(u8::is_ascii_digit)));
+
+        if matched && is_boundary(bytes, Some(start + 9)) {
+            found.push((PersonalInfoKind::PassportNumber, start..start + 9));
+        }
+    }
+
+    found
+}
+
+fn find_emails(bytes: &[u8]) -> Vec<(PersonalInfoKind, Range<usize>)> {
+    let is_local =
+        |c: u8| c.is_ascii_alphanumeric() || matches!(c, b'.' | b'_' | b'%' | b'+' | b'-');
+    let is_domain = |c: u8| c.is_ascii_alphanumeric() || matches!(c, b'.' | b'-');
+
+    let mut found: Vec<(PersonalInfoKind, Range<usize>)> = vec![];
+    for (at, _) in bytes.iter().enumerate().filter(|(_, c)| **c == b'@') {
+        let mut start = at;
+        while start > 0 && is_local(bytes[start - 1]) {
+            start -= 1;
+        }
+        while start < at && bytes[start] == b'.' {
+            start += 1;
+        }
+
+        let mut end = at + 1;
+        while end < bytes.len() && is_domain(bytes[end]) {
+            end += 1;
+        }
+        while end > at + 1 && matches!(bytes[end - 1], b'.' | b'-') {
+            end -= 1;
+        }
+
+        let domain = &bytes[at + 1..end];
+        let top_level = domain.rsplit(|c| *c == b'.').next().unwrap_or_default();
+        if start == at
+            || !domain.contains(&b'.')
+            || top_level.len() < 2
+            || !top_level.iter().all(u8::is_ascii_alphabetic)
+        {
+            continue;
+        }
+
+        found.push((PersonalInfoKind::Email, start..end));
+    }
+
+    found
+}
+
+/// 위치의 글자가 숫자나 영문이 아닌지 (없다면 경계로 본다)
+fn is_boundary(bytes: &[u8], index: Option<usize>) -> bool {
+    match index.and_then(|index| bytes.get(index)) {
+        Some(c) => !c.is_ascii_alphanumeric(),
+        None => true,
+    }
+}
@@ -2,18 +2,21 @@ use std::io::{Cursor, Read};
 
 use byteorder::LittleEndian;
 
-use self::reader::RecordReader;
+use self::{reader::RecordReader, writer::RecordWriter};
 
 use super::version::Version;
 
 pub mod reader;
 pub mod tags;
+pub mod writer;
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Record {
     pub tag_id: u32,
     pub level: u32,
     pub size: u32,
+    #[cfg_attr(feature = "serde", serde(with = "crate::hwp::utils::base64"))]
     pub data: Vec<u8>,
 }
 
@@ -38,19 +41,11 @@ pub struct RecordCursor {
 
 impl RecordCursor {
     pub fn new<T: Read>(reader: &mut T) -> Self {
-        let mut data = Vec::new();
-        reader.read_to_end(&mut data).unwrap();
-
-        let mut reader = Cursor::new(&data);
-        let mut records = vec![];
-
-        while reader.position() < (data.len() as u64) {
-            let (tag_id, level, size, mut data) = reader.read_record::<LittleEndian>().unwrap();
-            let mut buf = Vec::new();
-            data.read_to_end(&mut buf).unwrap();
+        Self::from_records(read_records(reader))
+    }
 
-            records.insert(0, Record::new(tag_id, level, size, buf));
-        }
+    pub fn from_records(mut records: Vec<Record>) -> Self {
+        records.reverse();
 
         Self { records }
     }
@@ -84,6 +79,36 @@ impl RecordCursor {
     }
 }
 
+/// 레코드를 순서대로 읽는다
+pub fn read_records<T: Read>(reader: &mut T) -> Vec<Record> {
+    let mut data = Vec::new();
+    reader.read_to_end(&mut data).unwrap();
+
+    let mut reader = Cursor::new(&data);
+    let mut records = vec![];
+
+    while reader.position() < (data.len() as u64) {
+        let (tag_id, level, size, mut data) = reader.read_record::<LittleEndian>().unwrap();
+        let mut buf = Vec::new();
+        data.read_to_end(&mut buf).unwrap();
+
+        records.push(Record::new(tag_id, level, size, buf));
+    }
+
+    records
+}
+
+/// 레코드를 순서대로 쓴다
+pub fn write_records(records: &[Record]) -> Vec<u8> {
+    let mut data = vec![];
+    for record in records {
+        data.write_record::<LittleEndian>(record.tag_id, record.level, &record.data)
+            .unwrap();
+    }
+
+    data
+}
+
 pub trait FromRecordCursor {
     fn from_record_cursor(cursor: &mut RecordCursor, version: &Version) -> Self;
 }
@@ -93,7 +118,8 @@ pub fn read_items<T: FromRecordCursor>(
     version: &Version,
     size: usize,
 ) -> Vec<T> {
-    let mut read_items: Vec<T> = Vec::with_capacity(size);
+    // NOTE: 개수는 문서에 기록된 값이므로 남은 레코드 수 이상 미리 할당하지 않는다
+    let mut read_items: Vec<T> = Vec::with_capacity(size.min(cursor.records.len()));
     for _ in 0..size {
         read_items.push(T::from_record_cursor(cursor, version));
     }
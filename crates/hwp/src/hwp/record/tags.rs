@@ -117,3 +117,25 @@ pub enum BodyTextRecord {
     /// Unknown
     HWPTAG_SHAPE_COMPONENT_UNKNOWN = HWPTAG_BEGIN + 99,
 }
+
+#[repr(u32)]
+#[derive(Debug, PartialEq, Eq)]
+#[allow(non_camel_case_types)]
+pub enum DocHistoryRecord {
+    /// 히스토리 아이템 정보 시작
+    HISTORY_RECORD_TYPE_STAG = 0x10,
+    /// 히스토리 아이템 정보 끝
+    HISTORY_RECORD_TYPE_ETAG = 0x11,
+    /// 히스토리 아이템 버전
+    HISTORY_RECORD_TYPE_VERSION = 0x20,
+    /// 히스토리 날짜
+    HISTORY_RECORD_TYPE_DATE = 0x21,
+    /// 히스토리 작성자
+    HISTORY_RECORD_TYPE_WRITER = 0x22,
+    /// 히스토리 설명
+    HISTORY_RECORD_TYPE_DESCRIPTION = 0x23,
+    /// 비교 정보 (DiffML)
+    HISTORY_RECORD_TYPE_DIFFDATA = 0x30,
+    /// 가장 마지막 최근 문서 (HWPML)
+    HISTORY_RECORD_TYPE_LASTDOCDATA = 0x31,
+}
@@ -0,0 +1,35 @@
+use std::io::{Result, Write};
+
+use byteorder::{ByteOrder, WriteBytesExt};
+
+pub trait RecordWriter: Write + WriteBytesExt {
+    #[inline]
+    fn write_record<T: ByteOrder>(&mut self, tag_id: u32, level: u32, data: &[u8]) -> Result<()> {
+        let size = data.len() as u32;
+        let header = (tag_id & 0x3FF) | ((level & 0x3FF) << 10);
+
+        // 4095 바이트 이상이면 크기를 별도로 기록한다
+        if size >= 0xFFF {
+            self.write_u32::<T>(header | (0xFFF << 20))?;
+            self.write_u32::<T>(size)?;
+        } else {
+            self.write_u32::<T>(header | (size << 20))?;
+        }
+
+        self.write_all(data)
+    }
+
+    /// 길이(WORD)가 앞에 붙은 UTF-16 문자열을 쓴다
+    #[inline]
+    fn write_string<T: ByteOrder>(&mut self, value: &str) -> Result<()> {
+        let chars: Vec<u16> = value.encode_utf16().collect();
+        self.write_u16::<T>(chars.len() as u16)?;
+        for c in chars {
+            self.write_u16::<T>(c)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<W: Write + ?Sized> RecordWriter for W {}
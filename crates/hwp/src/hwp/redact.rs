@@ -0,0 +1,469 @@
+use std::ops::Range;
+
+use super::{
+    error::Error,
+    header::update_flags,
+    options::ParseOptions,
+    paragraph::char::match_char_control,
+    record::{tags::BodyTextRecord, Record},
+    storage::{read_header, update_records, Storage},
+    summary_info::{PropertyValue, SummaryInfo},
+};
+
+/// 가리기 설정
+#[derive(Debug, Clone)]
+pub struct RedactOptions {
+    /// 가린 글자 대신 쓸 문자. UTF-16 한 단위로 표현되는 문자여야 한다
+    pub mask: char,
+}
+
+impl Default for RedactOptions {
+    fn default() -> Self {
+        Self { mask: '*' }
+    }
+}
+
+/// 가릴 영역
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RedactSpan {
+    /// 구역 번호
+    pub section: usize,
+    /// 구역 스트림의 문단 머리(`HWPTAG_PARA_HEADER`) 레코드를 처음부터 센 번호
+    ///
+    /// 표의 셀, 글상자, 머리말/꼬리말, 각주/미주, 캡션, 메모 안의 문단도 나오는 순서대로 센다.
+    /// 따라서 컨트롤 안에 문단이 있다면 [`crate::hwp::section::Section`]의 `paragraphs` 순서와 다르다.
+    /// [`crate::hwp::privacy::scan`]이 찾은 위치나 [`RedactAction::Paragraph`]의 번호를 그대로 쓸 수 있다.
+    pub paragraph: usize,
+    /// 시작 위치 (글자 모양, 영역 태그와 같은 WCHAR 단위)
+    pub start_position: u32,
+    /// 끝 위치 (포함하지 않는다)
+    pub end_position: u32,
+}
+
+/// 문서에서 가리거나 제거한 항목
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RedactAction {
+    /// 본문 문단의 글자
+    Paragraph {
+        /// 구역 스트림 경로
+        path: String,
+        /// 문단 번호 ([`RedactSpan::paragraph`]와 같이 센다)
+        paragraph: usize,
+        start_position: u32,
+        end_position: u32,
+    },
+    /// 미리보기 텍스트 (`PrvText`)
+    PreviewText,
+    /// 미리보기 이미지 (`PrvImage`)
+    PreviewImage,
+    /// 요약 정보 (스트림 경로)
+    SummaryInfo(String),
+    /// 문서 이력 (`DocHistory`)
+    DocHistory,
+}
+
+/// 가리기 결과
+#[derive(Debug, Clone, Default)]
+pub struct RedactReport {
+    pub actions: Vec<RedactAction>,
+}
+
+impl RedactReport {
+    /// 가린 항목이 없는지 여부
+    pub fn is_clean(&self) -> bool {
+        self.actions.is_empty()
+    }
+}
+
+/// 찾은 글자를 가린 새 문서를 만든다
+///
+/// `matcher`는 문단, 미리보기 텍스트, 요약 정보의 문자열을 받아 가릴 영역(바이트 위치)을 반환한다.
+/// 정규식이라면 `|text| re.find_iter(text).map(|m| m.range()).collect()`와 같이 쓸 수 있다.
+///
+/// 문서를 읽을 때 `parse_options`의 제한을 적용한다.
+pub fn redact<F>(
+    bytes: &[u8],
+    options: &RedactOptions,
+    parse_options: &ParseOptions,
+    mut matcher: F,
+) -> Result<(Vec<u8>, RedactReport), Error>
+where
+    F: FnMut(&str) -> Vec<Range<usize>>,
+{
+    redact_with(
+        bytes,
+        options,
+        parse_options,
+        &mut Matcher::Text(&mut matcher),
+    )
+}
+
+/// 지정한 영역을 가린 새 문서를 만든다
+pub fn redact_spans(
+    bytes: &[u8],
+    options: &RedactOptions,
+    parse_options: &ParseOptions,
+    spans: &[RedactSpan],
+) -> Result<(Vec<u8>, RedactReport), Error> {
+    redact_with(bytes, options, parse_options, &mut Matcher::Spans(spans))
+}
+
+enum Matcher<'a> {
+    Text(&'a mut dyn FnMut(&str) -> Vec<Range<usize>>),
+    Spans(&'a [RedactSpan]),
+}
+
+impl Matcher<'_> {
+    /// 문단에서 가릴 영역 (WCHAR 위치)
+    fn find_paragraph(
+        &mut self,
+        section: usize,
+        paragraph: usize,
+        text: &Text,
+    ) -> Vec<Range<usize>> {
+        match self {
+            Matcher::Text(matcher) => text.to_positions(&matcher(&text.value)),
+            Matcher::Spans(spans) => spans
+                .iter()
+                .filter(|span| span.section == section && span.paragraph == paragraph)
+                .map(|span| span.start_position as usize..span.end_position as usize)
+                .collect(),
+        }
+    }
+
+    /// 본문 밖의 문자열에서 가릴 영역 (WCHAR 위치)
+    fn find_text(&mut self, text: &Text) -> Vec<Range<usize>> {
+        match self {
+            Matcher::Text(matcher) => text.to_positions(&matcher(&text.value)),
+            Matcher::Spans(_) => vec![],
+        }
+    }
+}
+
+/// 본문을 가린 뒤 가린 문자열이 남아있을 수 있는 곳을 함께 정리한다
+///
+/// 가린 글자는 같은 수의 마스크 문자로 바꾸므로 문단의 글자 수와 글자 모양, 영역 태그, 줄 정보의 위치는 바뀌지 않는다.
+/// 본문에서 가린 문자열은 미리보기 텍스트와 요약 정보에서도 모두 가리며,
+/// 가린 내용이 그대로 남아있는 미리보기 이미지와 문서 이력은 제거한다.
+///
+/// NOTE: 필드 명령, 수식 스크립트 등 문단 밖의 문자열과 코드 페이지로 저장된 요약 정보는 가리지 않는다
+fn redact_with(
+    bytes: &[u8],
+    options: &RedactOptions,
+    parse_options: &ParseOptions,
+    matcher: &mut Matcher,
+) -> Result<(Vec<u8>, RedactReport), Error> {
+    assert_eq!(
+        options.mask.len_utf16(),
+        1,
+        "올바르지 않은 마스크 문자 입니다"
+    );
+    let mask = options.mask as u16;
+
+    let mut storage = Storage::from_bytes(bytes, parse_options)?;
+    let mut report = RedactReport::default();
+
+    let header = read_header(&storage)?;
+    let compressed = header.flags.compressed;
+
+    let mut fragments = vec![];
+    for (storage_name, distributed) in [("BodyText", false), ("ViewText", true)] {
+        let mut section = 0;
+        loop {
+            let path = format!("/{}/Section{}", storage_name, section);
+            let updated =
+                update_records(&mut storage, &path, compressed, distributed, |records| {
+                    for (current, index) in paragraph_texts(records) {
+                        let record = &mut records[index];
+                        let mut units = to_units(&record.data);
+                        let text = Text::from_paragraph(&units);
+                        for range in matcher.find_paragraph(section, current, &text) {
+                            if let Some(fragment) = text.mask(&mut units, range.clone(), mask) {
+                                fragments.push(fragment);
+                                report.actions.push(RedactAction::Paragraph {
+                                    path: path.clone(),
+                                    paragraph: current,
+                                    start_position: range.start as u32,
+                                    end_position: range.end as u32,
+                                });
+                            }
+                        }
+                        set_units(record, &units);
+                    }
+                })?;
+
+            if !updated {
+                break;
+            }
+            section += 1;
+        }
+    }
+
+    if let Some(data) = storage.get("/PrvText") {
+        let mut units = to_units(data);
+        let end = units.iter().position(|c| *c == 0).unwrap_or(units.len());
+        let text = Text::from_string(&units[..end]);
+
+        if mask_text(&text, &mut units, matcher, &fragments, mask) {
+            storage.set("/PrvText", from_units(&units));
+            report.actions.push(RedactAction::PreviewText);
+        }
+    }
+
+    for path in ["/\u{5}HwpSummaryInformation", "/\u{5}SummaryInformation"] {
+        let mut summary_info = match storage.get(path).and_then(SummaryInfo::from_bytes) {
+            Some(summary_info) => summary_info,
+            None => continue,
+        };
+
+        let mut changed = false;
+        for property in summary_info.properties.iter_mut() {
+            if let PropertyValue::String(value) = &property.value {
+                let mut units: Vec<u16> = value.encode_utf16().collect();
+                let text = Text::from_string(&units);
+
+                if mask_text(&text, &mut units, matcher, &fragments, mask) {
+                    property.value = PropertyValue::String(String::from_utf16_lossy(&units));
+                    changed = true;
+                }
+            }
+        }
+
+        if changed {
+            storage.set(path, summary_info.to_bytes());
+            report
+                .actions
+                .push(RedactAction::SummaryInfo(path.to_string()));
+        }
+    }
+
+    if !report.is_clean() {
+        if storage.remove("/PrvImage").is_some() {
+            report.actions.push(RedactAction::PreviewImage);
+        }
+
+        if storage
+            .paths()
+            .iter()
+            .any(|path| path.starts_with("/DocHistory/"))
+        {
+            storage.remove_storage("/DocHistory");
+            update_flags(&mut storage, |flags| flags.vcs = false);
+            report.actions.push(RedactAction::DocHistory);
+        }
+    }
+
+    Ok((storage.to_bytes(), report))
+}
+
+/// 문단 글자 레코드의 위치를 (문단 번호, 레코드 번호)로 반환한다
+///
+/// 문단 번호는 구역 안의 모든 문단 머리(`HWPTAG_PARA_HEADER`)를 레코드 순서로 센 값이다.
+fn paragraph_texts(records: &[Record]) -> Vec<(usize, usize)> {
+    records
+        .iter()
+        .enumerate()
+        .filter(|(_, record)| record.tag_id == BodyTextRecord::HWPTAG_PARA_HEADER as u32)
+        .enumerate()
+        .filter_map(|(paragraph, (index, header))| {
+            let record = records.get(index + 1)?;
+            if record.tag_id == BodyTextRecord::HWPTAG_PARA_TEXT as u32
+                && record.level == header.level + 1
+            {
+                Some((paragraph, index + 1))
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// 본문 밖의 문자열에서 찾은 영역과 본문에서 가린 문자열을 가린다
+fn mask_text(
+    text: &Text,
+    units: &mut [u16],
+    matcher: &mut Matcher,
+    fragments: &[String],
+    mask: u16,
+) -> bool {
+    let mut ranges = matcher.find_text(text);
+    for fragment in fragments {
+        let found: Vec<Range<usize>> = text
+            .value
+            .match_indices(fragment.as_str())
+            .map(|(start, found)| start..start + found.len())
+            .collect();
+        ranges.extend(text.to_positions(&found));
+    }
+
+    let mut changed = false;
+    for range in ranges {
+        changed |= text.mask(units, range, mask).is_some();
+    }
+
+    changed
+}
+
+/// 문자열과 각 글자의 위치
+pub(crate) struct Text {
+    pub(crate) value: String,
+    chars: Vec<TextChar>,
+}
+
+struct TextChar {
+    /// 문자열에서의 바이트 위치
+    offset: usize,
+    /// WCHAR 위치
+    position: usize,
+    /// WCHAR 수
+    len: usize,
+    /// 가릴 수 있는 글자인지 여부 (제어 문자가 아닌지)
+    maskable: bool,
+}
+
+impl Text {
+    /// 문단 글자(`HWPTAG_PARA_TEXT`)를 읽는다. 줄 바꿈과 탭을 제외한 제어 문자는 문자열에 넣지 않는다
+    pub(crate) fn from_paragraph(units: &[u16]) -> Self {
+        let mut text = Self {
+            value: String::new(),
+            chars: vec![],
+        };
+
+        let mut position = 0;
+        while position < units.len() {
+            let code = units[position];
+            if code > 31 {
+                let len = text.push_code(units, position);
+                position += len;
+                continue;
+            }
+
+            if match_char_control(code).is_some() {
+                if code == 10 {
+                    text.push_char('\n', position, 1);
+                }
+                position += 1;
+                continue;
+            }
+
+            if code == 9 {
+                text.push_char('\t', position, 8);
+            }
+            position += 8;
+        }
+
+        text
+    }
+
+    /// 제어 문자 없는 UTF-16 문자열을 읽는다
+    fn from_string(units: &[u16]) -> Self {
+        let mut text = Self {
+            value: String::new(),
+            chars: vec![],
+        };
+
+        let mut position = 0;
+        while position < units.len() {
+            position += text.push_code(units, position);
+        }
+
+        text
+    }
+
+    fn push_code(&mut self, units: &[u16], position: usize) -> usize {
+        let (c, len) = match char::decode_utf16(units[position..].iter().copied()).next() {
+            Some(Ok(c)) => (c, c.len_utf16()),
+            _ => (char::REPLACEMENT_CHARACTER, 1),
+        };
+
+        self.chars.push(TextChar {
+            offset: self.value.len(),
+            position,
+            len,
+            maskable: true,
+        });
+        self.value.push(c);
+
+        len
+    }
+
+    fn push_char(&mut self, c: char, position: usize, len: usize) {
+        self.chars.push(TextChar {
+            offset: self.value.len(),
+            position,
+            len,
+            maskable: false,
+        });
+        self.value.push(c);
+    }
+
+    /// 문자열의 바이트 영역을 WCHAR 영역으로 바꾼다
+    pub(crate) fn to_positions(&self, ranges: &[Range<usize>]) -> Vec<Range<usize>> {
+        ranges
+            .iter()
+            .filter_map(|range| {
+                let mut chars = self
+                    .chars
+                    .iter()
+                    .filter(|c| c.offset >= range.start && c.offset < range.end);
+                let first = chars.next()?;
+                let last = chars.next_back().unwrap_or(first);
+
+                Some(first.position..last.position + last.len)
+            })
+            .collect()
+    }
+
+    /// 영역 안의 글자를 가리고 가린 문자열을 반환한다. 가린 글자가 없다면 None을 반환한다
+    fn mask(&self, units: &mut [u16], range: Range<usize>, mask: u16) -> Option<String> {
+        let mut fragment = String::new();
+        for (index, c) in self.chars.iter().enumerate() {
+            if !c.maskable || c.position < range.start || c.position + c.len > range.end {
+                continue;
+            }
+
+            let end = self
+                .chars
+                .get(index + 1)
+                .map_or(self.value.len(), |next| next.offset);
+            let original = &self.value[c.offset..end];
+            if units[c.position..c.position + c.len]
+                .iter()
+                .all(|u| *u == mask)
+            {
+                continue;
+            }
+
+            fragment.push_str(original);
+            for unit in &mut units[c.position..c.position + c.len] {
+                *unit = mask;
+            }
+        }
+
+        if fragment.is_empty() {
+            None
+        } else {
+            Some(fragment)
+        }
+    }
+}
+
+fn to_units(data: &[u8]) -> Vec<u16> {
+    data.chunks_exact(2)
+        .map(|c| u16::from_le_bytes([c[0], c[1]]))
+        .collect()
+}
+
+fn from_units(units: &[u16]) -> Vec<u8> {
+    units.iter().flat_map(|unit| unit.to_le_bytes()).collect()
+}
+
+fn set_units(record: &mut Record, units: &[u16]) {
+    let mut data = from_units(units);
+    // 홀수 길이라면 남은 바이트를 보존한다
+    if record.data.len() % 2 == 1 {
+        data.push(*record.data.last().unwrap());
+    }
+
+    record.data = data;
+}
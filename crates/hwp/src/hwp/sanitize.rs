@@ -0,0 +1,288 @@
+use std::io::Read;
+
+use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
+use flate2::{write::DeflateEncoder, Compression};
+use hwp_macro::make_4chid;
+
+use super::{
+    doc_info::bin_data::BinDataKind,
+    error::Error,
+    header::update_flags,
+    options::ParseOptions,
+    record::{
+        reader::RecordReader,
+        tags::{BodyTextRecord, DocInfoRecord},
+        Record,
+    },
+    storage::{update_records, Storage},
+    HWP,
+};
+
+/// 문서에서 제거한 항목
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SanitizeAction {
+    /// 스크립트 (`Scripts`)
+    RemoveScripts,
+    /// OLE 개체 (BinData 스트림 경로)
+    RemoveOle(String),
+    /// EPS/PS 이미지 (BinData 스트림 경로)
+    RemovePostScript(String),
+    /// 외부 파일 연결 (절대 경로)
+    RemoveLink(String),
+    /// 실행 파일을 가리키는 하이퍼링크 (필드 명령)
+    RemoveHyperlink(String),
+}
+
+/// 정리 결과
+#[derive(Debug, Clone, Default)]
+pub struct SanitizeReport {
+    pub actions: Vec<SanitizeAction>,
+}
+
+impl SanitizeReport {
+    /// 제거한 항목이 없는지 여부
+    pub fn is_clean(&self) -> bool {
+        self.actions.is_empty()
+    }
+}
+
+/// 문서에서 위험할 수 있는 내용을 제거한 새 문서를 만든다
+///
+/// 스크립트, OLE 개체, EPS/PS 이미지, 외부 파일 연결, 실행 파일을 가리키는 하이퍼링크를 제거한다.
+/// OLE 개체와 EPS/PS 이미지는 문서가 그대로 열리도록 스트림을 비워서 남긴다.
+///
+/// 문서를 읽을 때 `options`의 제한을 적용한다.
+pub fn sanitize(bytes: &[u8], options: &ParseOptions) -> Result<(Vec<u8>, SanitizeReport), Error> {
+    let hwp = HWP::from_bytes_with_options(bytes, options)?;
+    let compressed = hwp.header.flags.compressed;
+
+    let mut storage = Storage::from_bytes(bytes, options)?;
+    let mut report = SanitizeReport::default();
+
+    if storage
+        .paths()
+        .iter()
+        .any(|path| path.starts_with("/Scripts/"))
+    {
+        storage.remove_storage("/Scripts");
+        report.actions.push(SanitizeAction::RemoveScripts);
+    }
+
+    let mut links = vec![];
+    for (index, item) in hwp.doc_info.id_mappings.binary_data.iter().enumerate() {
+        match item.properties.kind {
+            BinDataKind::Link => {
+                let absolute_path = item.absolute_path.clone().unwrap_or_default();
+                let relative_path = item.relative_path.clone().unwrap_or_default();
+                if absolute_path.is_empty() && relative_path.is_empty() {
+                    continue;
+                }
+
+                links.push(index);
+                report
+                    .actions
+                    .push(SanitizeAction::RemoveLink(absolute_path));
+            }
+            BinDataKind::Embedding | BinDataKind::Storage => {
+                let id = match item.id {
+                    Some(id) => id,
+                    None => continue,
+                };
+
+                let is_ole = item.properties.kind == BinDataKind::Storage
+                    || item
+                        .extension
+                        .as_deref()
+                        .is_some_and(|extension| extension.eq_ignore_ascii_case("ole"));
+                // NOTE: 확장자는 바꿀 수 있으므로 EPS/PS는 내용으로 확인한다
+                let is_post_script = item
+                    .cfb_file_name()
+                    .and_then(|name| hwp.bin_data.iter().find(|file| file.name == name))
+                    .is_some_and(|file| is_post_script(&file.data));
+                if !is_ole && !is_post_script {
+                    continue;
+                }
+
+                // NOTE: DocInfo와 컨트롤이 스트림을 가리키므로 지우지 않고 빈 스트림으로 바꾼다
+                let placeholder = placeholder_bin_data(item.compressed(&hwp.header));
+
+                // NOTE: OLE 개체는 확장자가 기록되지 않으므로 ID로 찾는다
+                let prefix = format!("/bindata/bin{:0>4x}.", id);
+                let paths: Vec<String> = storage
+                    .paths()
+                    .into_iter()
+                    .filter(|path| path.to_ascii_lowercase().starts_with(&prefix))
+                    .filter(|path| storage.get(path) != Some(placeholder.as_slice()))
+                    .map(|path| path.to_string())
+                    .collect();
+
+                for path in paths {
+                    storage.set(&path, placeholder.clone());
+                    report.actions.push(if is_ole {
+                        SanitizeAction::RemoveOle(path)
+                    } else {
+                        SanitizeAction::RemovePostScript(path)
+                    });
+                }
+            }
+        }
+    }
+
+    if !links.is_empty() {
+        update_records(&mut storage, "/DocInfo", compressed, false, |records| {
+            let bin_data = records
+                .iter_mut()
+                .filter(|record| record.tag_id == DocInfoRecord::HWPTAG_BIN_DATA as u32);
+
+            for (index, record) in bin_data.enumerate() {
+                if links.contains(&index) {
+                    remove_link_paths(record);
+                }
+            }
+        })?;
+    }
+
+    for (storage_name, distributed) in [("BodyText", false), ("ViewText", true)] {
+        let mut index = 0;
+        loop {
+            let path = format!("/{}/Section{}", storage_name, index);
+            let updated =
+                update_records(&mut storage, &path, compressed, distributed, |records| {
+                    for record in records.iter_mut() {
+                        if let Some(command) = remove_executable_hyperlink(record) {
+                            report
+                                .actions
+                                .push(SanitizeAction::RemoveHyperlink(command));
+                        }
+                    }
+                })?;
+
+            if !updated {
+                break;
+            }
+            index += 1;
+        }
+    }
+
+    if report.actions.contains(&SanitizeAction::RemoveScripts) {
+        update_flags(&mut storage, |flags| flags.has_script = false);
+    }
+
+    Ok((storage.to_bytes(), report))
+}
+
+/// EPS/PS 파일인지 내용으로 확인한다 (`%!PS` 또는 DOS EPS 바이너리 헤더)
+fn is_post_script(data: &[u8]) -> bool {
+    data.starts_with(b"%!PS") || data.starts_with(&[0xC5, 0xD0, 0xD3, 0xC6])
+}
+
+/// 제거한 바이너리 데이터 대신 남길 빈 스트림
+fn placeholder_bin_data(compressed: bool) -> Vec<u8> {
+    if !compressed {
+        return vec![];
+    }
+
+    let encoder = DeflateEncoder::new(vec![], Compression::default());
+    encoder.finish().unwrap()
+}
+
+/// 외부 파일 연결의 경로를 비운다
+fn remove_link_paths(record: &mut Record) {
+    let properties = match record.get_data_reader().read_u16::<LittleEndian>() {
+        Ok(properties) => properties,
+        Err(_) => return,
+    };
+
+    let mut data = vec![];
+    data.write_u16::<LittleEndian>(properties).unwrap();
+    // 절대 경로, 상대 경로
+    data.write_u16::<LittleEndian>(0).unwrap();
+    data.write_u16::<LittleEndian>(0).unwrap();
+
+    record.size = data.len() as u32;
+    record.data = data;
+}
+
+/// 실행 파일을 가리키는 하이퍼링크라면 명령을 비우고 원래 명령을 반환한다
+fn remove_executable_hyperlink(record: &mut Record) -> Option<String> {
+    if record.tag_id != BodyTextRecord::HWPTAG_CTRL_HEADER as u32 {
+        return None;
+    }
+
+    let mut reader = record.get_data_reader();
+    let ctrl_id = reader.read_u32::<LittleEndian>().ok()?;
+    if ctrl_id != make_4chid!('%', 'h', 'l', 'k') {
+        return None;
+    }
+
+    let properties = reader.read_u32::<LittleEndian>().ok()?;
+    let extra_properties = reader.read_u8().ok()?;
+    let command = reader.read_string::<LittleEndian>().ok()?;
+
+    if !is_executable_target(&command) {
+        return None;
+    }
+
+    let mut rest = vec![];
+    reader.read_to_end(&mut rest).ok()?;
+
+    let mut data = vec![];
+    data.write_u32::<LittleEndian>(ctrl_id).unwrap();
+    data.write_u32::<LittleEndian>(properties).unwrap();
+    data.write_u8(extra_properties).unwrap();
+    data.write_u16::<LittleEndian>(0).unwrap();
+    data.extend(rest);
+
+    record.size = data.len() as u32;
+    record.data = data;
+
+    Some(command)
+}
+
+const EXECUTABLE_EXTENSIONS: [&str; 18] = [
+    "exe", "com", "bat", "cmd", "scr", "pif", "msi", "js", "jse", "vbs", "vbe", "wsf", "wsh",
+    "ps1", "hta", "jar", "lnk", "cpl",
+];
+
+/// 하이퍼링크 명령의 대상이 실행 파일인지 확인한다
+///
+/// 명령은 `http\://example.com;1;0;0;`과 같이 `;`로 구분되며 `:`는 `\`로 이스케이프 된다
+pub(crate) fn is_executable_target(command: &str) -> bool {
+    let target = get_hyperlink_target(command).to_ascii_lowercase();
+
+    // 주소라면 호스트를 제외한 경로만 본다 (example.com의 com을 확장자로 보지 않도록)
+    let path = match target.find("://") {
+        Some(index) => match target[index + 3..].find('/') {
+            Some(slash) => &target[index + 3 + slash..],
+            None => return false,
+        },
+        None => &target,
+    };
+
+    let path = path.split(['?', '#']).next().unwrap_or_default();
+    let extension = path.rsplit_once('.').map(|(_, extension)| extension);
+
+    match extension {
+        Some(extension) => EXECUTABLE_EXTENSIONS.contains(&extension),
+        None => false,
+    }
+}
+
+/// 하이퍼링크 명령에서 대상 주소를 꺼낸다
+pub(crate) fn get_hyperlink_target(command: &str) -> String {
+    let mut target = String::new();
+    let mut chars = command.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                if let Some(next) = chars.next() {
+                    target.push(next);
+                }
+            }
+            ';' => break,
+            _ => target.push(c),
+        }
+    }
+
+    target
+}
@@ -0,0 +1,114 @@
+use std::io::{Cursor, Read, Seek};
+
+use byteorder::{LittleEndian, ReadBytesExt};
+use cfb::CompoundFile;
+
+use super::{error::Error, header::Header, options::Limiter};
+
+/// 스크립트 (`Scripts`)
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Scripts {
+    /// 스크립트 버전
+    pub version: ScriptVersion,
+    /// 스크립트 헤더
+    pub header: String,
+    /// 스크립트 소스
+    pub source: String,
+    /// 스크립트 Pre 소스
+    pub pre_source: String,
+    /// 스크립트 Post 소스
+    pub post_source: String,
+}
+
+impl Scripts {
+    /// 스크립트를 읽는다. 스트림이 없거나 형식이 올바르지 않다면 None을 반환한다
+    pub fn from_cfb<T: Read + Seek>(cfb: &mut CompoundFile<T>, header: &Header) -> Option<Self> {
+        Self::read(cfb, header, &Limiter::unlimited()).unwrap_or_default()
+    }
+
+    /// 제한을 확인하며 스크립트를 읽는다
+    pub(crate) fn read<T: Read + Seek>(
+        cfb: &mut CompoundFile<T>,
+        header: &Header,
+        limiter: &Limiter,
+    ) -> Result<Option<Self>, Error> {
+        let version = read_stream(cfb, "/Scripts/JScriptVersion", header, limiter)?;
+        let script = read_stream(cfb, "/Scripts/DefaultJScript", header, limiter)?;
+
+        match (version, script) {
+            (Some(version), Some(script)) => Ok(Self::from_data(&version, script)),
+            _ => Ok(None),
+        }
+    }
+
+    fn from_data(version: &[u8], script: Vec<u8>) -> Option<Self> {
+        let version = ScriptVersion::from_data(version)?;
+
+        let mut reader = Cursor::new(script);
+        let header = read_script_string(&mut reader)?;
+        let source = read_script_string(&mut reader)?;
+        let pre_source = read_script_string(&mut reader)?;
+        let post_source = read_script_string(&mut reader)?;
+
+        Some(Self {
+            version,
+            header,
+            source,
+            pre_source,
+            post_source,
+        })
+    }
+}
+
+/// 스크립트 버전
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScriptVersion {
+    pub high: u32,
+    pub low: u32,
+}
+
+impl ScriptVersion {
+    fn from_data(data: &[u8]) -> Option<Self> {
+        let mut reader = Cursor::new(data);
+
+        let high = reader.read_u32::<LittleEndian>().ok()?;
+        let low = reader.read_u32::<LittleEndian>().ok()?;
+
+        Some(Self { high, low })
+    }
+}
+
+/// 스크립트 스트림을 읽는다. 압축이나 암호화를 풀 수 없다면 None을 반환한다
+fn read_stream<T: Read + Seek>(
+    cfb: &mut CompoundFile<T>,
+    path: &str,
+    header: &Header,
+    limiter: &Limiter,
+) -> Result<Option<Vec<u8>>, Error> {
+    // NOTE: 배포용 문서는 스크립트도 본문과 같은 방식으로 암호화 되어있다
+    match limiter.read_stream(cfb, header, path, header.flags.distributed) {
+        Err(Error::InvalidStream(_)) => Ok(None),
+        result => result,
+    }
+}
+
+/// 길이(DWORD) + UTF-16LE 문자열
+fn read_script_string<T: Read>(reader: &mut T) -> Option<String> {
+    let len = reader.read_u32::<LittleEndian>().ok()? as u64;
+
+    // NOTE: 길이는 문서에 기록된 값이므로 실제로 읽은 만큼만 할당한다
+    let mut data = vec![];
+    reader.take(len * 2).read_to_end(&mut data).ok()?;
+    if data.len() as u64 != len * 2 {
+        return None;
+    }
+
+    let buf: Vec<u16> = data
+        .chunks_exact(2)
+        .map(|c| u16::from_le_bytes([c[0], c[1]]))
+        .collect();
+
+    String::from_utf16(&buf).ok()
+}
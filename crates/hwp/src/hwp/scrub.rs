@@ -0,0 +1,398 @@
+use std::io::{Cursor, Read};
+
+use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
+use hwp_macro::make_4chid;
+
+use super::{
+    error::Error,
+    header::update_flags,
+    options::ParseOptions,
+    record::{
+        reader::RecordReader,
+        tags::{BodyTextRecord, DocInfoRecord},
+        writer::RecordWriter,
+        Record,
+    },
+    storage::{read_header, update_records, Storage},
+    summary_info::{PropertyId, PropertyValue, SummaryInfo},
+};
+
+/// 개인 정보 정리 설정
+#[derive(Debug, Clone, Default)]
+pub struct ScrubOptions {
+    /// 작성자 대신 기록할 이름. 없다면 작성자를 지운다
+    pub pseudonym: Option<String>,
+}
+
+/// 문서에서 지우거나 바꾼 항목
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScrubAction {
+    /// 요약 정보의 지은이 (스트림 경로, 원래 값)
+    ///
+    /// 해석하지 못한 값이라면 원래 값은 None이다
+    Author(String, Option<String>),
+    /// 요약 정보의 마지막으로 저장한 사람 (스트림 경로, 원래 값)
+    LastSavedBy(String, Option<String>),
+    /// 메모 필드 (스트림 경로)
+    Memo(String),
+    /// 개인 정보 보호 필드 (스트림 경로)
+    PersonalInfo(String),
+    /// 변경 추적 작성자 (원래 이름)
+    TrackChangeAuthor(String),
+    /// 외부 파일 연결의 절대 경로 (원래 경로)
+    BinDataPath(String),
+    /// 문서 이력 (`DocHistory`)
+    DocHistory,
+}
+
+/// 형식을 확인할 수 없어 정리하지 못한 항목
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScrubSkip {
+    /// 해석하지 못한 변경 추적 작성자 (`DocInfo`의 레코드 순서)
+    TrackChangeAuthor(usize),
+    /// 해석하지 못한 메모나 개인 정보 보호 필드 (스트림 경로)
+    Field(String),
+}
+
+/// 정리 결과
+#[derive(Debug, Clone, Default)]
+pub struct ScrubReport {
+    pub actions: Vec<ScrubAction>,
+    /// 작성자 정보가 남아 있을 수 있는 항목
+    pub skipped: Vec<ScrubSkip>,
+}
+
+impl ScrubReport {
+    /// 정리한 항목이 없는지 여부
+    pub fn is_clean(&self) -> bool {
+        self.actions.is_empty()
+    }
+}
+
+const SUMMARY_STREAMS: [&str; 2] = ["/\u{5}HwpSummaryInformation", "/\u{5}SummaryInformation"];
+
+/// 문서에서 작성자를 알 수 있는 정보를 지우거나 바꾼 새 문서를 만든다
+///
+/// 요약 정보의 지은이와 마지막으로 저장한 사람, 변경 추적 작성자, 메모와 개인 정보 보호 필드,
+/// 외부 파일 연결의 절대 경로, 문서 이력을 정리한다.
+/// 해석하지 못한 항목은 건드리지 않고 [`ScrubReport::skipped`]에 남긴다.
+///
+/// 문서를 읽을 때 `parse_options`의 제한을 적용한다.
+pub fn scrub(
+    bytes: &[u8],
+    options: &ScrubOptions,
+    parse_options: &ParseOptions,
+) -> Result<(Vec<u8>, ScrubReport), Error> {
+    let mut storage = Storage::from_bytes(bytes, parse_options)?;
+    let mut report = ScrubReport::default();
+
+    let header = read_header(&storage)?;
+    let compressed = header.flags.compressed;
+
+    for path in SUMMARY_STREAMS {
+        let mut summary_info = match storage.get(path).and_then(SummaryInfo::from_bytes) {
+            Some(summary_info) => summary_info,
+            None => continue,
+        };
+
+        let mut changed = false;
+        for id in [PropertyId::Author, PropertyId::LastSavedBy] {
+            // NOTE: 코드 페이지 문자열(VT_LPSTR) 등 해석하지 못한 값도 지운다
+            let value = match summary_info.get(id) {
+                Some(PropertyValue::String(value)) if !value.is_empty() => Some(value.clone()),
+                Some(PropertyValue::Unknown(_)) => None,
+                _ => continue,
+            };
+
+            match &options.pseudonym {
+                Some(pseudonym) => summary_info.set(id, PropertyValue::String(pseudonym.clone())),
+                None => {
+                    summary_info.remove(id);
+                }
+            }
+            changed = true;
+
+            report.actions.push(match id {
+                PropertyId::Author => ScrubAction::Author(path.to_string(), value),
+                _ => ScrubAction::LastSavedBy(path.to_string(), value),
+            });
+        }
+
+        if changed {
+            storage.set(path, summary_info.to_bytes());
+        }
+    }
+
+    update_records(&mut storage, "/DocInfo", compressed, false, |records| {
+        for (index, record) in records.iter_mut().enumerate() {
+            match record.tag_id {
+                tag if tag == DocInfoRecord::HWPTAG_TRACK_CHANGE_AUTHOR as u32 => {
+                    let pseudonym = options.pseudonym.as_deref().unwrap_or_default();
+                    match replace_author(record, pseudonym) {
+                        Some(Some(author)) => {
+                            report.actions.push(ScrubAction::TrackChangeAuthor(author))
+                        }
+                        Some(None) => {}
+                        None => report.skipped.push(ScrubSkip::TrackChangeAuthor(index)),
+                    }
+                }
+                tag if tag == DocInfoRecord::HWPTAG_BIN_DATA as u32 => {
+                    if let Some(path) = remove_absolute_path(record) {
+                        report.actions.push(ScrubAction::BinDataPath(path));
+                    }
+                }
+                _ => {}
+            }
+        }
+    })?;
+
+    for (storage_name, distributed) in [("BodyText", false), ("ViewText", true)] {
+        let mut index = 0;
+        loop {
+            let path = format!("/{}/Section{}", storage_name, index);
+            let updated =
+                update_records(&mut storage, &path, compressed, distributed, |records| {
+                    for (field, cleared) in clear_private_fields(records) {
+                        if cleared {
+                            report.actions.push(field.into_action(&path));
+                        } else {
+                            report.skipped.push(ScrubSkip::Field(path.clone()));
+                        }
+                    }
+                })?;
+
+            if !updated {
+                break;
+            }
+            index += 1;
+        }
+    }
+
+    if storage
+        .paths()
+        .iter()
+        .any(|path| path.starts_with("/DocHistory/"))
+    {
+        storage.remove_storage("/DocHistory");
+        update_flags(&mut storage, |flags| flags.vcs = false);
+        report.actions.push(ScrubAction::DocHistory);
+    }
+
+    Ok((storage.to_bytes(), report))
+}
+
+/// 변경 추적 작성자의 이름을 바꾸고 원래 이름을 반환한다
+///
+/// 이름이 비어 있거나 이미 바꿀 이름과 같다면 Some(None)을, 해석하지 못하면 None을 반환한다.
+///
+/// NOTE: 공개된 명세가 없지만 레코드는 작성자 이름(길이가 앞에 붙은 WCHAR 문자열)으로 시작한다.
+/// 뒤의 데이터는 그대로 둔다
+fn replace_author(record: &mut Record, pseudonym: &str) -> Option<Option<String>> {
+    let mut reader = record.get_data_reader();
+    // NOTE: 조작된 문서의 잘못된 UTF-16 문자열에 멈추지 않도록 직접 읽는다
+    let len = reader.read_u16::<LittleEndian>().ok()?;
+    let author = (0..len)
+        .map(|_| reader.read_u16::<LittleEndian>())
+        .collect::<Result<Vec<u16>, _>>()
+        .ok()?;
+    let author = String::from_utf16(&author).ok()?;
+    if author.is_empty() || author == pseudonym {
+        return Some(None);
+    }
+
+    let mut rest = vec![];
+    reader.read_to_end(&mut rest).ok()?;
+
+    let mut data = vec![];
+    data.write_string::<LittleEndian>(pseudonym).unwrap();
+    data.extend(rest);
+
+    record.size = data.len() as u32;
+    record.data = data;
+
+    Some(Some(author))
+}
+
+/// 외부 파일 연결의 절대 경로를 비우고 원래 경로를 반환한다. 상대 경로는 남긴다
+fn remove_absolute_path(record: &mut Record) -> Option<String> {
+    let mut reader = record.get_data_reader();
+    let properties = reader.read_u16::<LittleEndian>().ok()?;
+    // 그림 외부 파일 참조
+    if properties & 0xF != 0 {
+        return None;
+    }
+
+    let absolute_path = reader.read_string::<LittleEndian>().ok()?;
+    if absolute_path.is_empty() {
+        return None;
+    }
+
+    let mut rest = vec![];
+    reader.read_to_end(&mut rest).ok()?;
+
+    let mut data = vec![];
+    data.write_u16::<LittleEndian>(properties).unwrap();
+    data.write_u16::<LittleEndian>(0).unwrap();
+    data.extend(rest);
+
+    record.size = data.len() as u32;
+    record.data = data;
+
+    Some(absolute_path)
+}
+
+enum PrivateField {
+    Memo,
+    PersonalInfo,
+}
+
+impl PrivateField {
+    fn into_action(self, path: &str) -> ScrubAction {
+        match self {
+            PrivateField::Memo => ScrubAction::Memo(path.to_string()),
+            PrivateField::PersonalInfo => ScrubAction::PersonalInfo(path.to_string()),
+        }
+    }
+}
+
+/// 메모와 개인 정보 보호 필드의 명령과 컨트롤 임의 데이터의 문자열을 비운다
+///
+/// 고친 필드와 모두 비웠는지 여부를 반환하며, 이미 비어 있는 필드는 반환하지 않는다.
+/// 레코드 구조는 바꾸지 않는다.
+fn clear_private_fields(records: &mut [Record]) -> Vec<(PrivateField, bool)> {
+    let mut fields = vec![];
+
+    for index in 0..records.len() {
+        let record = &records[index];
+        if record.tag_id != BodyTextRecord::HWPTAG_CTRL_HEADER as u32 {
+            continue;
+        }
+
+        let field = match record.get_data_reader().read_u32::<LittleEndian>() {
+            Ok(make_4chid!('%', '%', 'm', 'e')) => PrivateField::Memo,
+            Ok(make_4chid!('%', 'c', 'p', 'r')) => PrivateField::PersonalInfo,
+            _ => continue,
+        };
+
+        let level = record.level;
+        let (mut changed, mut cleared) = match clear_field_command(&mut records[index]) {
+            Some(changed) => (changed, true),
+            None => (false, false),
+        };
+
+        // NOTE: 컨트롤 임의 데이터는 필드 바로 아래의 파라미터 셋이다. 해석하지 못하면 그대로 둔다
+        for child in records[index + 1..]
+            .iter_mut()
+            .take_while(|child| child.level > level)
+            .filter(|child| {
+                child.level == level + 1 && child.tag_id == BodyTextRecord::HWPTAG_CTRL_DATA as u32
+            })
+        {
+            match clear_parameter_set(&child.data) {
+                Some(data) => {
+                    changed |= data != child.data;
+                    child.size = data.len() as u32;
+                    child.data = data;
+                }
+                None => cleared = false,
+            }
+        }
+
+        if changed || !cleared {
+            fields.push((field, cleared));
+        }
+    }
+
+    fields
+}
+
+/// 필드 컨트롤의 명령을 비우고 바뀌었는지 여부를 반환한다
+fn clear_field_command(record: &mut Record) -> Option<bool> {
+    let mut reader = record.get_data_reader();
+    let ctrl_id = reader.read_u32::<LittleEndian>().ok()?;
+    let properties = reader.read_u32::<LittleEndian>().ok()?;
+    let extra_properties = reader.read_u8().ok()?;
+    reader.read_string::<LittleEndian>().ok()?;
+
+    let mut rest = vec![];
+    reader.read_to_end(&mut rest).ok()?;
+
+    let mut data = vec![];
+    data.write_u32::<LittleEndian>(ctrl_id).unwrap();
+    data.write_u32::<LittleEndian>(properties).unwrap();
+    data.write_u8(extra_properties).unwrap();
+    data.write_u16::<LittleEndian>(0).unwrap();
+    data.extend(rest);
+
+    let changed = data != record.data;
+    record.size = data.len() as u32;
+    record.data = data;
+
+    Some(changed)
+}
+
+const MAX_PARAMETER_SET_DEPTH: u32 = 16;
+
+/// 파라미터 셋의 문자열 아이템을 모두 비운 데이터를 만든다. 끝까지 해석하지 못하면 None을 반환한다
+fn clear_parameter_set(data: &[u8]) -> Option<Vec<u8>> {
+    let mut reader = Cursor::new(data);
+    let mut cleared = vec![];
+    copy_parameter_set(&mut reader, &mut cleared, 0)?;
+
+    if reader.position() != data.len() as u64 {
+        return None;
+    }
+
+    Some(cleared)
+}
+
+fn copy_parameter_set(reader: &mut Cursor<&[u8]>, writer: &mut Vec<u8>, depth: u32) -> Option<()> {
+    // NOTE: 조작된 문서가 스택을 넘치게 하지 않도록 중첩 깊이를 제한한다
+    if depth > MAX_PARAMETER_SET_DEPTH {
+        return None;
+    }
+
+    let id = reader.read_u16::<LittleEndian>().ok()?;
+    // NOTE: 명세에는 INT16이지만 책갈피와 같이 실제 문서에서는 4바이트로 기록된다
+    let count = reader.read_u32::<LittleEndian>().ok()?;
+    writer.write_u16::<LittleEndian>(id).unwrap();
+    writer.write_u32::<LittleEndian>(count).unwrap();
+
+    for _ in 0..count {
+        let item_id = reader.read_u16::<LittleEndian>().ok()?;
+        let kind = reader.read_u16::<LittleEndian>().ok()?;
+        writer.write_u16::<LittleEndian>(item_id).unwrap();
+        writer.write_u16::<LittleEndian>(kind).unwrap();
+
+        match kind {
+            // PIT_BSTR
+            1 => {
+                reader.read_string::<LittleEndian>().ok()?;
+                writer.write_string::<LittleEndian>("").unwrap();
+            }
+            // PIT_NULL, PIT_I1 ~ PIT_UI
+            0 | 2..=9 => {
+                let value = reader.read_u32::<LittleEndian>().ok()?;
+                writer.write_u32::<LittleEndian>(value).unwrap();
+            }
+            // PIT_SET
+            0x8000 => copy_parameter_set(reader, writer, depth + 1)?,
+            // PIT_ARRAY
+            0x8001 => {
+                let len = reader.read_i16::<LittleEndian>().ok()?;
+                writer.write_i16::<LittleEndian>(len).unwrap();
+                for _ in 0..len {
+                    copy_parameter_set(reader, writer, depth + 1)?;
+                }
+            }
+            // PIT_BINDATA
+            0x8002 => {
+                let value = reader.read_u16::<LittleEndian>().ok()?;
+                writer.write_u16::<LittleEndian>(value).unwrap();
+            }
+            _ => return None,
+        }
+    }
+
+    Some(())
+}
@@ -1,31 +1,28 @@
-use crate::hwp::utils::random::SRand;
-
 use super::{
-    header::Header,
-    paragraph::Paragraph,
-    record::{reader::RecordReader, tags::DocInfoRecord, RecordCursor},
-    utils::crypto::decrypt_aes_128_ecb,
-    version::Version,
+    header::Header, paragraph::Paragraph, record::RecordCursor,
+    utils::distribute::decrypt_distributed, version::Version,
 };
 
 use std::io::{Cursor, Read};
 
-use byteorder::{LittleEndian, ReadBytesExt};
 use flate2::read::DeflateDecoder;
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Section {
     pub paragraphs: Vec<Paragraph>,
 }
 
 impl Section {
     pub fn from_reader<T: Read>(reader: &mut T, version: &Version) -> Self {
-        let mut cursor = RecordCursor::new(reader);
+        Self::from_record_cursor(&mut RecordCursor::new(reader), version)
+    }
 
+    pub(crate) fn from_record_cursor(cursor: &mut RecordCursor, version: &Version) -> Self {
         let mut paragraphs = vec![];
 
         while cursor.has_next() {
-            paragraphs.push(Paragraph::from_record_cursor(&mut cursor, version))
+            paragraphs.push(Paragraph::from_record_cursor(cursor, version))
         }
 
         Self { paragraphs }
@@ -41,51 +38,7 @@ impl Section {
     }
 
     pub fn from_distributed<T: Read>(stream: &mut T, header: &Header) -> Self {
-        let (tag_id, _, size, mut reader) = stream.read_record::<LittleEndian>().unwrap();
-
-        assert_eq!(tag_id, DocInfoRecord::HWPTAG_DISTRIBUTE_DOC_DATA as u32);
-        assert_eq!(size, 256);
-
-        let mut data = [0u8; 256];
-        reader.read_exact(&mut data).unwrap();
-
-        let mut seed_cursor = Cursor::new(&data[0..4]);
-        let seed = seed_cursor.read_u32::<LittleEndian>().unwrap();
-        let mut rand = SRand::new(seed);
-
-        let mut random_numbers = [0u8; 256];
-        let mut i = 0;
-        loop {
-            if i == 256 {
-                break;
-            }
-            let fill = rand.rand() & 0xFF;
-            let times = (rand.rand() & 0x0F) + 1;
-
-            for _ in 0..times {
-                if i == 256 {
-                    break;
-                }
-
-                random_numbers[i] = fill as u8;
-                i += 1;
-            }
-        }
-
-        let offset = ((seed & 0xF) + 4) as usize;
-
-        let mut out = [0u8; 256];
-        for i in 0..256 {
-            out[i] = data[i] ^ random_numbers[i];
-        }
-
-        let hash_code = &out[offset..offset + 80];
-        let decryption_key = &hash_code[0..16];
-
-        let mut encrypted: Vec<u8> = Vec::new();
-        stream.read_to_end(&mut encrypted).unwrap();
-
-        let decrypted = decrypt_aes_128_ecb(&decryption_key, &encrypted);
+        let decrypted = decrypt_distributed(stream);
 
         let mut cursor = Cursor::new(decrypted);
 
@@ -0,0 +1,228 @@
+use std::{
+    io::{Cursor, Write},
+    path::{Component, Path},
+};
+
+use cfb::CompoundFile;
+use flate2::{write::DeflateEncoder, Compression};
+use uuid::Uuid;
+
+use super::{
+    error::Error,
+    header::Header,
+    options::{invalid_stream, Limiter, ParseOptions},
+    record::{write_records, Record},
+    utils::distribute::{decryption_key, encrypt_distributed, read_distribute_doc_data},
+};
+
+/// 복합 파일(CFB)의 스트림을 경로 별로 담는 저장소
+///
+/// 문서를 해석하지 않고 스트림 단위로 고쳐서 다시 쓸 때 사용한다.
+/// 경로는 `/BodyText/Section0`과 같이 `/`로 시작한다.
+#[derive(Debug, Clone)]
+pub struct Storage {
+    storages: Vec<String>,
+    streams: Vec<(String, Vec<u8>)>,
+    /// 항목의 CLSID와 상태 비트. 둘 다 기본 값인 항목은 담지 않는다
+    attributes: Vec<(String, Uuid, u32)>,
+    /// 스트림의 압축을 풀거나 레코드를 읽을 때 적용할 제한
+    options: ParseOptions,
+}
+
+impl Storage {
+    /// 모든 스트림을 읽는다
+    ///
+    /// 복합 파일이 아니라면 `Error::InvalidFile`, 제한보다 큰 스트림이 있다면 `Error::LimitExceeded`를 반환한다.
+    pub fn from_bytes(bytes: &[u8], options: &ParseOptions) -> Result<Self, Error> {
+        let cursor = Cursor::new(bytes);
+        let mut cfb = CompoundFile::open(cursor).map_err(|_| Error::InvalidFile)?;
+
+        let mut storages = vec![];
+        let mut paths = vec![];
+        let mut attributes = vec![];
+        for entry in cfb.walk() {
+            let path = to_path_string(entry.path());
+            if !entry.clsid().is_nil() || entry.state_bits() != 0 {
+                attributes.push((path.clone(), *entry.clsid(), entry.state_bits()));
+            }
+
+            if entry.is_root() {
+                continue;
+            }
+
+            if entry.is_storage() {
+                storages.push(path);
+            } else {
+                paths.push(path);
+            }
+        }
+
+        let limiter = Limiter::new(options);
+        let mut streams = vec![];
+        for path in paths {
+            let data = limiter
+                .read_raw_stream(&mut cfb, &path)?
+                .ok_or_else(|| invalid_stream(&path))?;
+            streams.push((path, data));
+        }
+
+        Ok(Self {
+            storages,
+            streams,
+            attributes,
+            options: options.clone(),
+        })
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let cursor = Cursor::new(vec![]);
+        let mut cfb = CompoundFile::create(cursor).unwrap();
+
+        for storage in &self.storages {
+            cfb.create_storage_all(storage).unwrap();
+        }
+
+        for (path, data) in &self.streams {
+            if let Some(parent) = Path::new(path).parent() {
+                cfb.create_storage_all(parent).unwrap();
+            }
+
+            let mut stream = cfb.create_stream(path).unwrap();
+            stream.write_all(data).unwrap();
+        }
+
+        for (path, clsid, state_bits) in &self.attributes {
+            // NOTE: 루트는 빈 문자열로 저장된다
+            let path = if path.is_empty() { "/" } else { path.as_str() };
+            if !cfb.exists(path) {
+                continue;
+            }
+
+            if cfb.is_storage(path) {
+                cfb.set_storage_clsid(path, *clsid).unwrap();
+            }
+            cfb.set_state_bits(path, *state_bits).unwrap();
+        }
+
+        cfb.flush().unwrap();
+        cfb.into_inner().into_inner()
+    }
+
+    /// 스트림 경로 목록
+    pub fn paths(&self) -> Vec<&str> {
+        self.streams.iter().map(|(path, _)| path.as_str()).collect()
+    }
+
+    pub fn get(&self, path: &str) -> Option<&[u8]> {
+        self.streams
+            .iter()
+            .find(|(name, _)| name == path)
+            .map(|(_, data)| data.as_slice())
+    }
+
+    /// 스트림을 바꾼다. 없는 스트림이라면 추가한다
+    pub fn set(&mut self, path: &str, data: Vec<u8>) {
+        match self.streams.iter_mut().find(|(name, _)| name == path) {
+            Some((_, stream)) => *stream = data,
+            None => self.streams.push((path.to_string(), data)),
+        }
+    }
+
+    pub fn remove(&mut self, path: &str) -> Option<Vec<u8>> {
+        let index = self.streams.iter().position(|(name, _)| name == path)?;
+
+        Some(self.streams.remove(index).1)
+    }
+
+    /// 스토리지와 하위의 모든 스트림을 지운다
+    pub fn remove_storage(&mut self, path: &str) {
+        let prefix = format!("{}/", path);
+
+        self.storages
+            .retain(|name| name != path && !name.starts_with(&prefix));
+        self.streams.retain(|(name, _)| !name.starts_with(&prefix));
+    }
+}
+
+/// 저장소의 파일 헤더를 읽는다
+///
+/// 한글 문서가 아니라면 `Error::InvalidFile`, 암호가 걸린 문서라면 `Error::PasswordRequired`를 반환한다.
+pub(crate) fn read_header(storage: &Storage) -> Result<Header, Error> {
+    let header = storage
+        .get("/FileHeader")
+        .and_then(Header::from_bytes)
+        .ok_or(Error::InvalidFile)?;
+
+    if header.flags.encrypted {
+        return Err(Error::PasswordRequired);
+    }
+
+    Ok(header)
+}
+
+/// 레코드로 이루어진 스트림을 고쳐서 다시 쓴다
+///
+/// 배포용 문서의 암호화와 압축을 풀어서 레코드를 넘기고, 고친 뒤 같은 방식으로 다시 감싼다.
+/// 스트림이 없다면 `false`를 반환한다.
+pub(crate) fn update_records<F: FnOnce(&mut Vec<Record>)>(
+    storage: &mut Storage,
+    path: &str,
+    compressed: bool,
+    distributed: bool,
+    f: F,
+) -> Result<bool, Error> {
+    let mut records = match decode_records(storage, path, compressed, distributed)? {
+        Some(records) => records,
+        None => return Ok(false),
+    };
+
+    f(&mut records);
+    let mut data = write_records(&records);
+
+    if compressed {
+        let mut encoder = DeflateEncoder::new(vec![], Compression::default());
+        encoder.write_all(&data).unwrap();
+        data = encoder.finish().unwrap();
+    }
+
+    if distributed {
+        let original = storage.get(path).ok_or_else(|| invalid_stream(path))?;
+        let distribute_doc_data = read_distribute_doc_data(&mut Cursor::new(original));
+        let key = decryption_key(&distribute_doc_data);
+        data = encrypt_distributed(&distribute_doc_data, &key, &data);
+    }
+
+    storage.set(path, data);
+
+    Ok(true)
+}
+
+/// 레코드로 이루어진 스트림의 암호화와 압축을 풀어서 읽는다. 스트림이 없다면 None을 반환한다
+///
+/// 저장소를 읽을 때 받은 제한을 넘는 스트림이나 레코드는 `Error::LimitExceeded`를 반환한다.
+pub(crate) fn decode_records(
+    storage: &Storage,
+    path: &str,
+    compressed: bool,
+    distributed: bool,
+) -> Result<Option<Vec<Record>>, Error> {
+    let data = match storage.get(path) {
+        Some(data) => data.to_vec(),
+        None => return Ok(None),
+    };
+
+    let limiter = Limiter::new(&storage.options);
+    let data = limiter.decode_stream(data, path, compressed, distributed)?;
+
+    limiter.read_records(&data, path).map(Some)
+}
+
+/// NOTE: 운영체제에 따라 경로 구분자가 달라지지 않도록 `/`로 합친다
+pub(crate) fn to_path_string(path: &Path) -> String {
+    path.components()
+        .filter_map(|component| match component {
+            Component::Normal(name) => Some(format!("/{}", name.to_string_lossy())),
+            _ => None,
+        })
+        .collect()
+}
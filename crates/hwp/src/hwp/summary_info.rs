@@ -0,0 +1,446 @@
+use std::io::{Cursor, Read, Seek};
+
+use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
+use cfb::CompoundFile;
+
+use super::{error::Error, options::Limiter};
+
+/// 문서 요약 정보 (`\005HwpSummaryInformation`)
+///
+/// MS OLE Property Set 형식으로 저장된다. 속성의 순서를 그대로 보존하므로
+/// `from_bytes`로 읽은 내용을 `to_bytes`로 다시 쓸 수 있다.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SummaryInfo {
+    /// 프로퍼티 셋을 만든 시스템 정보
+    pub system_identifier: u32,
+    /// 프로퍼티 셋 CLSID
+    pub clsid: [u8; 16],
+    /// 섹션의 FMTID
+    pub format_id: [u8; 16],
+    /// 속성 목록
+    pub properties: Vec<Property>,
+    /// 첫 섹션 이후의 섹션 (해석하지 않고 그대로 다시 쓴다)
+    pub other_sections: Vec<UnknownSection>,
+}
+
+/// 해석하지 않은 프로퍼티 셋 섹션
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UnknownSection {
+    /// 섹션의 FMTID
+    pub format_id: [u8; 16],
+    /// 섹션 크기부터 시작하는 원본
+    #[cfg_attr(feature = "serde", serde(with = "crate::hwp::utils::base64"))]
+    pub data: Vec<u8>,
+}
+
+/// 섹션의 속성 목록을 읽는다
+fn read_properties(section: &[u8]) -> Option<Vec<Property>> {
+    let mut reader = Cursor::new(section);
+
+    let section_size = reader.read_u32::<LittleEndian>().ok()? as usize;
+    let section_size = section_size.min(section.len());
+    let count = reader.read_u32::<LittleEndian>().ok()?;
+
+    let mut entries = vec![];
+    for _ in 0..count {
+        let id = reader.read_u32::<LittleEndian>().ok()?;
+        let offset = reader.read_u32::<LittleEndian>().ok()? as usize;
+        entries.push((id, offset));
+    }
+
+    let mut offsets: Vec<usize> = entries.iter().map(|(_, offset)| *offset).collect();
+    offsets.sort();
+
+    entries
+        .into_iter()
+        .map(|(id, offset)| {
+            // 다음 속성 전까지를 속성 값의 영역으로 본다
+            let end = offsets
+                .iter()
+                .find(|o| **o > offset)
+                .copied()
+                .unwrap_or(section_size);
+
+            Some(Property::from_bytes(id, section.get(offset..end)?))
+        })
+        .collect()
+}
+
+const STREAM_NAME: &str = "/\u{5}HwpSummaryInformation";
+
+const BYTE_ORDER: u16 = 0xFFFE;
+
+/// 섹션 목록을 제외한 헤더의 크기
+const HEADER_SIZE: u32 = 28;
+
+impl SummaryInfo {
+    pub fn from_cfb<T: Read + Seek>(cfb: &mut CompoundFile<T>) -> Option<Self> {
+        Self::read(cfb, &Limiter::unlimited()).ok()?
+    }
+
+    /// 제한을 확인하며 요약 정보를 읽는다
+    pub(crate) fn read<T: Read + Seek>(
+        cfb: &mut CompoundFile<T>,
+        limiter: &Limiter,
+    ) -> Result<Option<Self>, Error> {
+        let data = limiter.read_raw_stream(cfb, STREAM_NAME)?;
+
+        Ok(data.and_then(|data| Self::from_bytes(&data)))
+    }
+
+    /// 요약 정보를 읽는다. 형식이 올바르지 않다면 None을 반환한다
+    pub fn from_bytes(data: &[u8]) -> Option<Self> {
+        let mut reader = Cursor::new(data);
+
+        let byte_order = reader.read_u16::<LittleEndian>().ok()?;
+        if byte_order != BYTE_ORDER {
+            return None;
+        }
+
+        // 포맷 버전
+        reader.read_u16::<LittleEndian>().ok()?;
+        let system_identifier = reader.read_u32::<LittleEndian>().ok()?;
+
+        let mut clsid = [0; 16];
+        reader.read_exact(&mut clsid).ok()?;
+
+        let section_count = reader.read_u32::<LittleEndian>().ok()?;
+        if section_count == 0 {
+            return None;
+        }
+
+        let mut sections = vec![];
+        for _ in 0..section_count {
+            let mut format_id = [0; 16];
+            reader.read_exact(&mut format_id).ok()?;
+            let offset = reader.read_u32::<LittleEndian>().ok()? as usize;
+            sections.push((format_id, offset));
+        }
+
+        let (format_id, offset) = sections[0];
+        let properties = read_properties(data.get(offset..)?)?;
+
+        let mut other_sections = vec![];
+        for (format_id, offset) in sections.into_iter().skip(1) {
+            let section = data.get(offset..)?;
+            let size = Cursor::new(section).read_u32::<LittleEndian>().ok()? as usize;
+
+            other_sections.push(UnknownSection {
+                format_id,
+                data: section.get(..size)?.to_vec(),
+            });
+        }
+
+        Some(Self {
+            system_identifier,
+            clsid,
+            format_id,
+            properties,
+            other_sections,
+        })
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut values = vec![];
+        let mut entries = vec![];
+
+        let table_size = 8 + 8 * self.properties.len() as u32;
+        for property in &self.properties {
+            entries.push((property.id, table_size + values.len() as u32));
+            values.extend(property.value.to_bytes());
+        }
+
+        let section_count = 1 + self.other_sections.len() as u32;
+        let header_size = HEADER_SIZE + 20 * section_count;
+
+        let mut data = vec![];
+        data.write_u16::<LittleEndian>(BYTE_ORDER).unwrap();
+        data.write_u16::<LittleEndian>(0).unwrap();
+        data.write_u32::<LittleEndian>(self.system_identifier)
+            .unwrap();
+        data.extend(self.clsid);
+        data.write_u32::<LittleEndian>(section_count).unwrap();
+
+        let mut offset = header_size + table_size + values.len() as u32;
+        data.extend(self.format_id);
+        data.write_u32::<LittleEndian>(header_size).unwrap();
+        for section in &self.other_sections {
+            data.extend(section.format_id);
+            data.write_u32::<LittleEndian>(offset).unwrap();
+            offset += section.data.len() as u32;
+        }
+
+        data.write_u32::<LittleEndian>(table_size + values.len() as u32)
+            .unwrap();
+        data.write_u32::<LittleEndian>(self.properties.len() as u32)
+            .unwrap();
+        for (id, offset) in entries {
+            data.write_u32::<LittleEndian>(id).unwrap();
+            data.write_u32::<LittleEndian>(offset).unwrap();
+        }
+        data.extend(values);
+
+        for section in &self.other_sections {
+            data.extend(&section.data);
+        }
+
+        data
+    }
+
+    pub fn get(&self, id: PropertyId) -> Option<&PropertyValue> {
+        self.properties
+            .iter()
+            .find(|property| property.id == id as u32)
+            .map(|property| &property.value)
+    }
+
+    /// 속성 값을 바꾼다. 없는 속성이라면 끝에 추가한다
+    pub fn set(&mut self, id: PropertyId, value: PropertyValue) {
+        match self
+            .properties
+            .iter_mut()
+            .find(|property| property.id == id as u32)
+        {
+            Some(property) => property.value = value,
+            None => self.properties.push(Property {
+                id: id as u32,
+                value,
+            }),
+        }
+    }
+
+    /// 속성을 지우고 지운 값을 반환한다
+    pub fn remove(&mut self, id: PropertyId) -> Option<PropertyValue> {
+        let index = self
+            .properties
+            .iter()
+            .position(|property| property.id == id as u32)?;
+
+        Some(self.properties.remove(index).value)
+    }
+
+    fn get_string(&self, id: PropertyId) -> Option<&str> {
+        match self.get(id) {
+            Some(PropertyValue::String(value)) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn get_file_time(&self, id: PropertyId) -> Option<FileTime> {
+        match self.get(id) {
+            Some(PropertyValue::FileTime(value)) => Some(value.clone()),
+            _ => None,
+        }
+    }
+
+    /// 제목
+    pub fn title(&self) -> Option<&str> {
+        self.get_string(PropertyId::Title)
+    }
+
+    /// 주제
+    pub fn subject(&self) -> Option<&str> {
+        self.get_string(PropertyId::Subject)
+    }
+
+    /// 지은이
+    pub fn author(&self) -> Option<&str> {
+        self.get_string(PropertyId::Author)
+    }
+
+    /// 키워드
+    pub fn keywords(&self) -> Option<&str> {
+        self.get_string(PropertyId::Keywords)
+    }
+
+    /// 설명
+    pub fn comments(&self) -> Option<&str> {
+        self.get_string(PropertyId::Comments)
+    }
+
+    /// 마지막으로 저장한 사람
+    pub fn last_saved_by(&self) -> Option<&str> {
+        self.get_string(PropertyId::LastSavedBy)
+    }
+
+    /// 개정 번호
+    ///
+    /// NOTE: 한글은 이 곳에 저장한 프로그램의 버전을 기록한다
+    pub fn revision(&self) -> Option<&str> {
+        self.get_string(PropertyId::Revision)
+    }
+
+    /// 날짜 (한글이 기록한 문자열)
+    pub fn date(&self) -> Option<&str> {
+        self.get_string(PropertyId::Date)
+    }
+
+    /// 마지막으로 인쇄한 시각
+    pub fn last_printed(&self) -> Option<FileTime> {
+        self.get_file_time(PropertyId::LastPrinted)
+    }
+
+    /// 만든 시각
+    pub fn created_at(&self) -> Option<FileTime> {
+        self.get_file_time(PropertyId::CreatedAt)
+    }
+
+    /// 마지막으로 저장한 시각
+    pub fn last_saved_at(&self) -> Option<FileTime> {
+        self.get_file_time(PropertyId::LastSavedAt)
+    }
+
+    /// 쪽 수
+    pub fn page_count(&self) -> Option<i32> {
+        match self.get(PropertyId::PageCount) {
+            Some(PropertyValue::I4(value)) => Some(*value),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Property {
+    /// 속성 ID
+    pub id: u32,
+    /// 값
+    pub value: PropertyValue,
+}
+
+impl Property {
+    fn from_bytes(id: u32, data: &[u8]) -> Self {
+        // NOTE: 0번 속성은 사전(dictionary)으로 타입 없이 저장된다
+        if id == PropertyId::Dictionary as u32 || data.len() < 4 {
+            return Self {
+                id,
+                value: PropertyValue::Unknown(data.to_vec()),
+            };
+        }
+
+        let value = read_value(data).unwrap_or_else(|| PropertyValue::Unknown(data.to_vec()));
+
+        Self { id, value }
+    }
+}
+
+/// 타입 정보로 시작하는 속성 값을 읽는다. 해석할 수 없다면 None을 반환한다
+fn read_value(data: &[u8]) -> Option<PropertyValue> {
+    let mut reader = Cursor::new(data);
+    let kind = reader.read_u32::<LittleEndian>().ok()?;
+
+    let value = match kind {
+        VT_I4 => PropertyValue::I4(reader.read_i32::<LittleEndian>().ok()?),
+        VT_LPWSTR => {
+            let length = reader.read_u32::<LittleEndian>().ok()? as usize;
+            if length > data.len() / 2 {
+                return None;
+            }
+            let mut chars = vec![0; length];
+            reader.read_u16_into::<LittleEndian>(&mut chars).ok()?;
+            if chars.last() == Some(&0) {
+                chars.pop();
+            }
+
+            PropertyValue::String(String::from_utf16(&chars).ok()?)
+        }
+        VT_FILETIME => PropertyValue::FileTime(FileTime(reader.read_u64::<LittleEndian>().ok()?)),
+        _ => return None,
+    };
+
+    Some(value)
+}
+
+const VT_I4: u32 = 0x0003;
+const VT_LPWSTR: u32 = 0x001F;
+const VT_FILETIME: u32 = 0x0040;
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PropertyValue {
+    I4(i32),
+    String(String),
+    FileTime(FileTime),
+    /// 해석하지 않은 값 (타입 정보를 포함한 원본)
+    Unknown(#[cfg_attr(feature = "serde", serde(with = "crate::hwp::utils::base64"))] Vec<u8>),
+}
+
+impl PropertyValue {
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut data = vec![];
+
+        match self {
+            PropertyValue::I4(value) => {
+                data.write_u32::<LittleEndian>(VT_I4).unwrap();
+                data.write_i32::<LittleEndian>(*value).unwrap();
+            }
+            PropertyValue::String(value) => {
+                let mut chars: Vec<u16> = value.encode_utf16().collect();
+                chars.push(0);
+
+                data.write_u32::<LittleEndian>(VT_LPWSTR).unwrap();
+                data.write_u32::<LittleEndian>(chars.len() as u32).unwrap();
+                for c in chars {
+                    data.write_u16::<LittleEndian>(c).unwrap();
+                }
+                // 4바이트 단위로 정렬한다
+                while data.len() % 4 != 0 {
+                    data.push(0);
+                }
+            }
+            PropertyValue::FileTime(value) => {
+                data.write_u32::<LittleEndian>(VT_FILETIME).unwrap();
+                data.write_u64::<LittleEndian>(value.0).unwrap();
+            }
+            PropertyValue::Unknown(value) => data.extend(value),
+        }
+
+        data
+    }
+}
+
+/// 1601년 1월 1일(UTC)부터의 100 나노초 단위 시각
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FileTime(pub u64);
+
+/// 1601년 1월 1일부터 1970년 1월 1일 까지의 초
+const UNIX_EPOCH_SECONDS: i64 = 11_644_473_600;
+
+impl FileTime {
+    /// 유닉스 타임스탬프(초). 값이 없다면 (0) None을 반환한다
+    pub fn to_unix_timestamp(&self) -> Option<i64> {
+        if self.0 == 0 {
+            return None;
+        }
+
+        Some((self.0 / 10_000_000) as i64 - UNIX_EPOCH_SECONDS)
+    }
+
+    pub fn from_unix_timestamp(timestamp: i64) -> Self {
+        Self(((timestamp + UNIX_EPOCH_SECONDS) * 10_000_000) as u64)
+    }
+}
+
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PropertyId {
+    Dictionary = 0x00,
+    Title = 0x02,
+    Subject = 0x03,
+    Author = 0x04,
+    Keywords = 0x05,
+    Comments = 0x06,
+    Template = 0x07,
+    LastSavedBy = 0x08,
+    Revision = 0x09,
+    LastPrinted = 0x0B,
+    CreatedAt = 0x0C,
+    LastSavedAt = 0x0D,
+    PageCount = 0x0E,
+    /// 한글 전용: 날짜 문자열
+    Date = 0x14,
+}
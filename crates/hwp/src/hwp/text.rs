@@ -0,0 +1,154 @@
+use super::{
+    paragraph::{
+        control::{
+            common_properties::{Caption, CaptionAlign},
+            table::TableControl,
+            Control,
+        },
+        text_options::TextOptions,
+        Paragraph,
+    },
+    section::Section,
+};
+
+/// 구역들을 읽는 순서대로 문자열로 바꾼다
+///
+/// 표는 행 순서대로 셀의 문단을 한 줄씩 넣고, 글상자와 캡션도 개체가 있는 위치에 넣는다.
+/// 각주는 본문에 `[번호]`, 미주는 `[미주 번호]`로 표시하고 내용은 문서 끝에 각주, 미주 순서로 모은다.
+/// 각주와 미주는 따로 번호를 매긴다.
+pub(crate) fn sections_to_text(sections: &[Section], options: &TextOptions) -> String {
+    let mut writer = TextWriter {
+        options,
+        footnotes: vec![],
+        endnotes: vec![],
+    };
+
+    let mut out = String::new();
+    for section in sections {
+        writer.write_paragraphs(&mut out, &section.paragraphs);
+    }
+
+    write_notes(&mut out, &writer.footnotes, "");
+    write_notes(&mut out, &writer.endnotes, "미주 ");
+
+    out
+}
+
+fn write_notes(out: &mut String, notes: &[String], prefix: &str) {
+    if notes.is_empty() {
+        return;
+    }
+
+    out.push('\n');
+    for (index, note) in notes.iter().enumerate() {
+        out.push_str(&format!("[{}{}] {}\n", prefix, index + 1, note));
+    }
+}
+
+struct TextWriter<'a> {
+    options: &'a TextOptions,
+    /// 문서 끝에 모을 각주
+    footnotes: Vec<String>,
+    /// 문서 끝에 모을 미주
+    endnotes: Vec<String>,
+}
+
+impl TextWriter<'_> {
+    fn notes(&mut self, endnote: bool) -> &mut Vec<String> {
+        if endnote {
+            &mut self.endnotes
+        } else {
+            &mut self.footnotes
+        }
+    }
+
+    fn write_paragraphs(&mut self, out: &mut String, paragraphs: &[Paragraph]) {
+        for paragraph in paragraphs {
+            let start = out.len();
+
+            let options = self.options;
+            paragraph.write_text(out, options, |out, control| {
+                self.write_control(out, control)
+            });
+
+            // 표 등으로 끝난 문단은 이미 줄이 바뀌어 있다
+            if out.len() == start || !out.ends_with('\n') {
+                out.push('\n');
+            }
+        }
+    }
+
+    fn write_control(&mut self, out: &mut String, control: &Control) {
+        match control {
+            Control::AutoNumber(auto_number) if self.options.controls => {
+                out.push_str(&auto_number.to_string());
+            }
+            Control::Footnote(note) | Control::Endnote(note) if self.options.notes => {
+                let endnote = matches!(control, Control::Endnote(_));
+
+                // NOTE: 주석 안의 주석보다 먼저 번호를 매긴다
+                let index = self.notes(endnote).len();
+                self.notes(endnote).push(String::new());
+
+                let mut text = String::new();
+                self.write_paragraphs(&mut text, &note.paragraph_list.paragraphs);
+                self.notes(endnote)[index] = text.trim_end().to_string();
+
+                let prefix = if endnote { "미주 " } else { "" };
+                out.push_str(&format!("[{}{}]", prefix, index + 1));
+            }
+            Control::Header(header_footer) | Control::Footer(header_footer)
+                if self.options.headers_footers =>
+            {
+                start_line(out);
+                self.write_paragraphs(out, &header_footer.paragraph_list.paragraphs);
+            }
+            _ => {
+                let caption = control
+                    .common_properties()
+                    .and_then(|properties| properties.caption.as_ref());
+                let draw_texts = control.draw_texts();
+                if caption.is_none()
+                    && draw_texts.is_empty()
+                    && !matches!(control, Control::Table(_))
+                {
+                    return;
+                }
+
+                start_line(out);
+                self.write_caption(out, caption, true);
+                if let Control::Table(table) = control {
+                    self.write_table(out, table);
+                }
+                for draw_text in draw_texts {
+                    self.write_paragraphs(out, &draw_text.paragraph_list.paragraphs);
+                }
+                self.write_caption(out, caption, false);
+            }
+        }
+    }
+
+    /// 위쪽 캡션은 개체 앞에, 나머지 캡션은 개체 뒤에 넣는다
+    fn write_caption(&mut self, out: &mut String, caption: Option<&Caption>, before: bool) {
+        if let Some(caption) = caption {
+            if matches!(caption.align, CaptionAlign::Top) == before {
+                self.write_paragraphs(out, &caption.paragraph_list.paragraphs);
+            }
+        }
+    }
+
+    fn write_table(&mut self, out: &mut String, table: &TableControl) {
+        let mut cells: Vec<_> = table.cells.iter().collect();
+        cells.sort_by_key(|cell| (cell.row, cell.column));
+
+        for cell in cells {
+            self.write_paragraphs(out, &cell.paragraph_list.paragraphs);
+        }
+    }
+}
+
+fn start_line(out: &mut String) {
+    if !out.is_empty() && !out.ends_with('\n') {
+        out.push('\n');
+    }
+}
@@ -0,0 +1,839 @@
+use std::io::{Cursor, Read, Seek};
+
+use cfb::CompoundFile;
+use hwp_macro::make_4chid;
+
+use super::{
+    error::Error,
+    header::Flags,
+    options::{Limiter, ParseOptions},
+    paragraph::control::is_known_ctrl_id,
+    record::tags::{BodyTextRecord, DocInfoRecord},
+    sanitize::{get_hyperlink_target, is_executable_target},
+    storage::to_path_string,
+};
+
+/// 위험도
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Info,
+    Low,
+    Medium,
+    High,
+}
+
+impl Severity {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Severity::Info => "info",
+            Severity::Low => "low",
+            Severity::Medium => "medium",
+            Severity::High => "high",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FindingKind {
+    /// 복합 파일(CFB)로 열 수 없다
+    InvalidFile,
+    /// 암호가 걸려 내용을 분석할 수 없다
+    Encrypted,
+    /// 파서가 사용하지 않는 스트림
+    UnknownStream,
+    /// 압축이나 암호화를 풀 수 없는 스트림
+    UnreadableStream,
+    /// `ParseOptions`의 제한보다 큰 스트림 (압축 폭탄)
+    LimitExceeded,
+    /// 스크립트 (매크로)
+    Script,
+    /// 외부 파일 연결
+    ExternalLink,
+    /// 실행 파일을 가리키는 하이퍼링크
+    ExecutableHyperlink,
+    /// OLE 개체
+    Ole,
+    /// PostScript/EPS
+    PostScript,
+    /// 실행 파일
+    Executable,
+    /// 확장자와 실제 내용이 다른 바이너리 데이터
+    ExtensionMismatch,
+    /// 바이너리 데이터 정보에 해당하는 스트림이 없다
+    MissingBinData,
+    /// `ParseOptions::max_record_size`보다 큰 레코드
+    OversizedRecord,
+    /// 선언된 크기보다 데이터가 모자란 레코드
+    TruncatedRecord,
+    /// 알 수 없는 컨트롤 ID
+    UnknownControl,
+}
+
+impl FindingKind {
+    pub fn as_str(&self) -> &str {
+        match self {
+            FindingKind::InvalidFile => "invalid_file",
+            FindingKind::Encrypted => "encrypted",
+            FindingKind::UnknownStream => "unknown_stream",
+            FindingKind::UnreadableStream => "unreadable_stream",
+            FindingKind::LimitExceeded => "limit_exceeded",
+            FindingKind::Script => "script",
+            FindingKind::ExternalLink => "external_link",
+            FindingKind::ExecutableHyperlink => "executable_hyperlink",
+            FindingKind::Ole => "ole",
+            FindingKind::PostScript => "post_script",
+            FindingKind::Executable => "executable",
+            FindingKind::ExtensionMismatch => "extension_mismatch",
+            FindingKind::MissingBinData => "missing_bin_data",
+            FindingKind::OversizedRecord => "oversized_record",
+            FindingKind::TruncatedRecord => "truncated_record",
+            FindingKind::UnknownControl => "unknown_control",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Finding {
+    pub severity: Severity,
+    pub kind: FindingKind,
+    /// 발견된 스트림 경로
+    pub path: String,
+    pub message: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct StreamInfo {
+    /// `/BodyText/Section0`과 같이 `/`로 시작하는 경로
+    pub path: String,
+    pub size: u64,
+    /// 파서가 해석하는 스트림인지 여부
+    pub known: bool,
+}
+
+/// 정적 분석 결과
+#[derive(Debug, Clone, Default)]
+pub struct ThreatReport {
+    /// 복합 파일의 모든 스트림
+    pub streams: Vec<StreamInfo>,
+    pub findings: Vec<Finding>,
+}
+
+impl ThreatReport {
+    /// 가장 높은 위험도. 발견된 항목이 없다면 `None`
+    pub fn risk(&self) -> Option<Severity> {
+        self.findings.iter().map(|finding| finding.severity).max()
+    }
+
+    pub fn has(&self, kind: FindingKind) -> bool {
+        self.findings.iter().any(|finding| finding.kind == kind)
+    }
+
+    pub fn to_json(&self) -> String {
+        let risk = match self.risk() {
+            Some(severity) => quote(severity.as_str()),
+            None => "null".to_string(),
+        };
+
+        let streams: Vec<String> = self
+            .streams
+            .iter()
+            .map(|stream| {
+                format!(
+                    "{{\"path\":{},\"size\":{},\"known\":{}}}",
+                    quote(&stream.path),
+                    stream.size,
+                    stream.known
+                )
+            })
+            .collect();
+
+        let findings: Vec<String> = self
+            .findings
+            .iter()
+            .map(|finding| {
+                format!(
+                    "{{\"severity\":{},\"kind\":{},\"path\":{},\"message\":{}}}",
+                    quote(finding.severity.as_str()),
+                    quote(finding.kind.as_str()),
+                    quote(&finding.path),
+                    quote(&finding.message)
+                )
+            })
+            .collect();
+
+        format!(
+            "{{\"risk\":{},\"streams\":[{}],\"findings\":[{}]}}",
+            risk,
+            streams.join(","),
+            findings.join(",")
+        )
+    }
+
+    fn push(&mut self, severity: Severity, kind: FindingKind, path: &str, message: String) {
+        self.findings.push(Finding {
+            severity,
+            kind,
+            path: path.to_string(),
+            message,
+        });
+    }
+}
+
+/// 문서를 해석하거나 실행하지 않고 위험 요소를 찾는다
+///
+/// `HWP::from_bytes`와 달리 손상되거나 조작된 문서에서도 패닉하지 않는다.
+/// 스트림은 `options`의 제한까지만 읽고 압축을 풀며, 제한을 넘는 스트림은 발견 항목으로 남긴다.
+pub fn analyze(bytes: &[u8], options: &ParseOptions) -> ThreatReport {
+    let mut report = ThreatReport::default();
+    let limiter = Limiter::new(options);
+
+    let mut cfb = match CompoundFile::open(Cursor::new(bytes)) {
+        Ok(cfb) => cfb,
+        Err(error) => {
+            report.push(
+                Severity::High,
+                FindingKind::InvalidFile,
+                "/",
+                format!("복합 파일로 열 수 없습니다: {}", error),
+            );
+            return report;
+        }
+    };
+
+    for entry in cfb.walk() {
+        if entry.is_stream() {
+            report.streams.push(StreamInfo {
+                path: to_path_string(entry.path()),
+                size: entry.len(),
+                known: false,
+            });
+        }
+    }
+
+    let flags = read_stream(&mut report, &limiter, &mut cfb, "/FileHeader")
+        .and_then(|data| Flags::from_file_header(&data))
+        .unwrap_or_default();
+    let compressed = flags.compressed;
+    let encrypted = flags.encrypted;
+    let distributed = flags.distributed;
+
+    let mut known = vec![];
+    for path in report.streams.iter().map(|stream| stream.path.clone()) {
+        if is_known_stream(&path) {
+            known.push(path);
+        }
+    }
+
+    if encrypted {
+        report.push(
+            Severity::Medium,
+            FindingKind::Encrypted,
+            "/FileHeader",
+            "암호가 걸린 문서라 본문을 분석할 수 없습니다".to_string(),
+        );
+    }
+
+    if !encrypted {
+        known.extend(analyze_doc_info(
+            &mut cfb,
+            &mut report,
+            &limiter,
+            compressed,
+        ));
+        analyze_scripts(&mut cfb, &mut report, &limiter, compressed, distributed);
+
+        for (storage, distributed) in [("BodyText", false), ("ViewText", distributed)] {
+            let mut index = 0;
+            loop {
+                let path = format!("/{}/Section{}", storage, index);
+                if !cfb.is_stream(&path) {
+                    break;
+                }
+
+                let data = read_stream(&mut report, &limiter, &mut cfb, &path).and_then(|data| {
+                    decode_stream(&mut report, &limiter, &path, data, compressed, distributed)
+                });
+                if let Some(data) = data {
+                    analyze_section(&mut report, options, &path, &data);
+                }
+                index += 1;
+            }
+        }
+    }
+
+    for stream in report.streams.iter_mut() {
+        stream.known = known.contains(&stream.path);
+    }
+
+    for stream in report.streams.clone() {
+        if !stream.known {
+            report.push(
+                Severity::Info,
+                FindingKind::UnknownStream,
+                &stream.path,
+                "해석하지 않는 스트림 입니다".to_string(),
+            );
+        }
+    }
+
+    report
+}
+
+/// NOTE: `BinData` 스트림은 `DocInfo`에서 참조하는 경우에만 알려진 스트림으로 본다
+fn is_known_stream(path: &str) -> bool {
+    const STREAMS: [&str; 7] = [
+        "/FileHeader",
+        "/DocInfo",
+        "/\u{5}HwpSummaryInformation",
+        "/PrvText",
+        "/PrvImage",
+        "/Scripts/DefaultJScript",
+        "/Scripts/JScriptVersion",
+    ];
+
+    let is_numbered = |prefix: &str| match path.strip_prefix(prefix) {
+        Some(index) => !index.is_empty() && index.chars().all(|c| c.is_ascii_digit()),
+        None => false,
+    };
+
+    STREAMS.contains(&path)
+        || is_numbered("/BodyText/Section")
+        || is_numbered("/ViewText/Section")
+        || is_numbered("/DocHistory/VersionLog")
+}
+
+/// 스크립트에 실제 소스가 있는지 확인한다
+///
+/// NOTE: 한/글은 스크립트가 없어도 빈 `DefaultJScript`를 저장하므로 스트림의 존재만으로 판단하지 않는다
+fn analyze_scripts<T: Read + Seek>(
+    cfb: &mut CompoundFile<T>,
+    report: &mut ThreatReport,
+    limiter: &Limiter,
+    compressed: bool,
+    distributed: bool,
+) {
+    let path = "/Scripts/DefaultJScript";
+    let data = match read_stream(report, limiter, cfb, path) {
+        Some(data) => data,
+        None => return,
+    };
+    let data = match decode_stream(report, limiter, path, data, compressed, distributed) {
+        Some(data) => data,
+        None => return,
+    };
+
+    // 헤더, 소스, Pre 소스, Post 소스
+    let mut reader = ByteReader::new(&data);
+    let sources: Option<Vec<String>> = (0..4).map(|_| reader.script_string()).collect();
+
+    match sources {
+        Some(sources) => {
+            if sources[1..].iter().any(|source| !is_empty_script(source)) {
+                report.push(
+                    Severity::High,
+                    FindingKind::Script,
+                    path,
+                    "스크립트가 포함되어 있습니다".to_string(),
+                );
+            }
+        }
+        None => report.push(
+            Severity::High,
+            FindingKind::Script,
+            path,
+            "형식이 올바르지 않은 스크립트가 포함되어 있습니다".to_string(),
+        ),
+    }
+}
+
+/// 주석을 제외하면 비어있는 함수만 있는 스크립트인지 확인한다
+///
+/// NOTE: 한/글은 `function OnDocument_New() { //todo : }`와 같은 기본 스크립트를 저장한다
+fn is_empty_script(source: &str) -> bool {
+    let code: String = source
+        .lines()
+        .map(|line| line.split("//").next().unwrap_or_default())
+        .flat_map(|line| line.chars())
+        .filter(|c| !c.is_whitespace())
+        .collect();
+
+    let mut rest = code.as_str();
+    while !rest.is_empty() {
+        let function = match rest.strip_prefix("function") {
+            Some(function) => function,
+            None => return false,
+        };
+        rest = match function.find("){}") {
+            Some(index) if !function[..index].contains(['{', '}']) => &function[index + 3..],
+            _ => return false,
+        };
+    }
+
+    true
+}
+
+/// 바이너리 데이터 정보를 확인하고 참조하는 스트림 경로를 반환한다
+fn analyze_doc_info<T: Read + Seek>(
+    cfb: &mut CompoundFile<T>,
+    report: &mut ThreatReport,
+    limiter: &Limiter,
+    compressed: bool,
+) -> Vec<String> {
+    let mut referenced = vec![];
+
+    let data = match read_stream(report, limiter, cfb, "/DocInfo") {
+        Some(data) => data,
+        None => return referenced,
+    };
+    let data = match decode_stream(report, limiter, "/DocInfo", data, compressed, false) {
+        Some(data) => data,
+        None => return referenced,
+    };
+
+    for record in scan_records(report, limiter.options(), "/DocInfo", &data) {
+        if record.tag_id != DocInfoRecord::HWPTAG_BIN_DATA as u32 {
+            continue;
+        }
+
+        let mut reader = ByteReader::new(record.data);
+        let properties = match reader.u16() {
+            Some(properties) => properties,
+            None => continue,
+        };
+
+        match properties & 0xF {
+            // 외부 파일 연결
+            0 => {
+                let absolute_path = reader.string().unwrap_or_default();
+                let relative_path = reader.string().unwrap_or_default();
+                report.push(
+                    Severity::Medium,
+                    FindingKind::ExternalLink,
+                    "/DocInfo",
+                    format!("외부 파일 연결: {} ({})", absolute_path, relative_path),
+                );
+            }
+            // 파일 포함, OLE 포함
+            kind @ (1 | 2) => {
+                let id = match reader.u16() {
+                    Some(id) => id,
+                    None => continue,
+                };
+
+                let extension = if kind == 1 {
+                    reader.string().unwrap_or_default()
+                } else {
+                    "OLE".to_string()
+                };
+
+                let expected = format!("/BinData/BIN{:0>4X}.{}", id, extension);
+                let path = report
+                    .streams
+                    .iter()
+                    .map(|stream| stream.path.clone())
+                    .find(|path| path.eq_ignore_ascii_case(&expected));
+
+                let path = match path {
+                    Some(path) => path,
+                    None => {
+                        report.push(
+                            Severity::Low,
+                            FindingKind::MissingBinData,
+                            "/DocInfo",
+                            format!("{} 스트림이 없습니다", expected),
+                        );
+                        continue;
+                    }
+                };
+                referenced.push(path.clone());
+
+                let compressed = match (properties >> 4) & 0x3 {
+                    0 => compressed,
+                    1 => true,
+                    _ => false,
+                };
+
+                let data = match read_stream(report, limiter, cfb, &path) {
+                    Some(data) => data,
+                    None => continue,
+                };
+                if let Some(data) = decode_stream(report, limiter, &path, data, compressed, false) {
+                    analyze_bin_data(report, &path, &extension, kind == 2, &data);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    referenced
+}
+
+fn analyze_bin_data(
+    report: &mut ThreatReport,
+    path: &str,
+    extension: &str,
+    is_storage: bool,
+    data: &[u8],
+) {
+    let extension = extension.to_ascii_lowercase();
+    let format = sniff_format(data);
+
+    if format == Some("exe") {
+        report.push(
+            Severity::High,
+            FindingKind::Executable,
+            path,
+            "실행 파일이 포함되어 있습니다".to_string(),
+        );
+    }
+
+    if format == Some("eps") || extension == "eps" || extension == "ps" {
+        report.push(
+            Severity::High,
+            FindingKind::PostScript,
+            path,
+            "PostScript/EPS가 포함되어 있습니다".to_string(),
+        );
+    }
+
+    if is_storage || format == Some("ole") {
+        report.push(
+            Severity::Medium,
+            FindingKind::Ole,
+            path,
+            "OLE 개체가 포함되어 있습니다".to_string(),
+        );
+    }
+
+    // NOTE: 내용으로 형식을 알 수 있는 확장자만 비교한다
+    let declared = match extension.as_str() {
+        "jpeg" => "jpg",
+        "tiff" => "tif",
+        "ps" => "eps",
+        "ole" => "ole",
+        extension => extension,
+    };
+    if !MAGIC_NUMBERS.iter().any(|(name, _)| *name == declared) {
+        return;
+    }
+
+    if format != Some(declared) {
+        report.push(
+            Severity::High,
+            FindingKind::ExtensionMismatch,
+            path,
+            format!(
+                "확장자는 {} 이지만 내용은 {} 입니다",
+                extension,
+                format.unwrap_or("알 수 없는 형식")
+            ),
+        );
+    }
+}
+
+/// 형식 별 시그니처
+const MAGIC_NUMBERS: [(&str, &[u8]); 12] = [
+    ("png", &[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A]),
+    ("jpg", &[0xFF, 0xD8, 0xFF]),
+    ("gif", b"GIF8"),
+    ("bmp", b"BM"),
+    ("tif", b"II*\0"),
+    ("tif", b"MM\0*"),
+    ("wmf", &[0xD7, 0xCD, 0xC6, 0x9A]),
+    ("emf", &[0x01, 0x00, 0x00, 0x00]),
+    ("eps", b"%!PS"),
+    // DOS EPS 바이너리 헤더
+    ("eps", &[0xC5, 0xD0, 0xD3, 0xC6]),
+    ("ole", &[0xD0, 0xCF, 0x11, 0xE0, 0xA1, 0xB1, 0x1A, 0xE1]),
+    ("exe", b"MZ"),
+];
+
+/// 내용으로 형식을 추측한다
+fn sniff_format(data: &[u8]) -> Option<&'static str> {
+    // NOTE: 위치 지정 없는 WMF는 메타 헤더(type 1 또는 2, 헤더 크기 9)로 시작한다
+    if data.len() >= 4 && matches!(data[0..4], [1 | 2, 0, 9, 0]) {
+        return Some("wmf");
+    }
+
+    // NOTE: EMF는 44번째 바이트에 " EMF" 시그니처가 있다
+    if data.starts_with(&[0x01, 0x00, 0x00, 0x00]) && data.get(40..44) != Some(b" EMF") {
+        return None;
+    }
+
+    MAGIC_NUMBERS
+        .iter()
+        .find(|(_, magic)| data.starts_with(magic))
+        .map(|(name, _)| *name)
+}
+
+fn analyze_section(report: &mut ThreatReport, options: &ParseOptions, path: &str, data: &[u8]) {
+    for record in scan_records(report, options, path, data) {
+        if record.tag_id != BodyTextRecord::HWPTAG_CTRL_HEADER as u32 {
+            continue;
+        }
+
+        let mut reader = ByteReader::new(record.data);
+        let ctrl_id = match reader.u32() {
+            Some(ctrl_id) => ctrl_id,
+            None => continue,
+        };
+
+        if !is_known_ctrl_id(ctrl_id) {
+            let name: String = ctrl_id
+                .to_be_bytes()
+                .iter()
+                .map(|c| match c {
+                    0x20..=0x7E => *c as char,
+                    _ => '?',
+                })
+                .collect();
+            report.push(
+                Severity::Low,
+                FindingKind::UnknownControl,
+                path,
+                format!("알 수 없는 컨트롤 ID: {} (0x{:08X})", name, ctrl_id),
+            );
+            continue;
+        }
+
+        if ctrl_id == make_4chid!('%', 'h', 'l', 'k') {
+            // 속성 (UINT32), 기타 속성 (UINT8)
+            if reader.skip(5).is_none() {
+                continue;
+            }
+            let command = match reader.string() {
+                Some(command) => command,
+                None => continue,
+            };
+
+            if is_executable_target(&command) {
+                report.push(
+                    Severity::High,
+                    FindingKind::ExecutableHyperlink,
+                    path,
+                    format!(
+                        "실행 파일을 가리키는 하이퍼링크: {}",
+                        get_hyperlink_target(&command)
+                    ),
+                );
+            }
+        }
+    }
+}
+
+/// 배포용 문서의 암호화와 압축을 푼다. 풀 수 없다면 발견 항목을 남기고 `None`을 반환한다
+fn decode_stream(
+    report: &mut ThreatReport,
+    limiter: &Limiter,
+    path: &str,
+    data: Vec<u8>,
+    compressed: bool,
+    distributed: bool,
+) -> Option<Vec<u8>> {
+    match limiter.decode_stream(data, path, compressed, distributed) {
+        Ok(data) => Some(data),
+        Err(error) => {
+            push_read_error(report, path, error);
+            None
+        }
+    }
+}
+
+/// 스트림을 제한까지만 읽는다. 읽을 수 없다면 발견 항목을 남기고 `None`을 반환한다
+fn read_stream<T: Read + Seek>(
+    report: &mut ThreatReport,
+    limiter: &Limiter,
+    cfb: &mut CompoundFile<T>,
+    path: &str,
+) -> Option<Vec<u8>> {
+    match limiter.read_raw_stream(cfb, path) {
+        Ok(data) => data,
+        Err(error) => {
+            push_read_error(report, path, error);
+            None
+        }
+    }
+}
+
+fn push_read_error(report: &mut ThreatReport, path: &str, error: Error) {
+    let (severity, kind) = match error {
+        Error::LimitExceeded { .. } => (Severity::High, FindingKind::LimitExceeded),
+        _ => (Severity::Medium, FindingKind::UnreadableStream),
+    };
+
+    report.push(severity, kind, path, error.to_string());
+}
+
+struct RawRecord<'a> {
+    tag_id: u32,
+    data: &'a [u8],
+}
+
+/// 레코드 헤더만 읽으며 크기를 검사한다
+///
+/// 선언된 크기보다 데이터가 모자란 레코드를 만나면 나머지는 읽지 않는다
+fn scan_records<'a>(
+    report: &mut ThreatReport,
+    options: &ParseOptions,
+    path: &str,
+    data: &'a [u8],
+) -> Vec<RawRecord<'a>> {
+    let mut records = vec![];
+    let mut reader = ByteReader::new(data);
+
+    while !reader.is_empty() {
+        let offset = reader.position;
+        let header = match reader.u32() {
+            Some(header) => header,
+            None => {
+                report.push(
+                    Severity::Medium,
+                    FindingKind::TruncatedRecord,
+                    path,
+                    format!("{} 위치의 레코드 헤더가 잘렸습니다", offset),
+                );
+                break;
+            }
+        };
+
+        let tag_id = header & 0x3FF;
+        let mut size = (header >> 20) & 0xFFF;
+        if size == 0xFFF {
+            size = match reader.u32() {
+                Some(size) => size,
+                None => {
+                    report.push(
+                        Severity::Medium,
+                        FindingKind::TruncatedRecord,
+                        path,
+                        format!("{} 위치의 레코드 헤더가 잘렸습니다", offset),
+                    );
+                    break;
+                }
+            };
+        }
+
+        if size > options.max_record_size {
+            report.push(
+                Severity::Medium,
+                FindingKind::OversizedRecord,
+                path,
+                format!(
+                    "{} 위치의 레코드(태그 {})의 크기가 {} 바이트 입니다",
+                    offset, tag_id, size
+                ),
+            );
+        }
+
+        let data = match reader.bytes(size as usize) {
+            Some(data) => data,
+            None => {
+                report.push(
+                    Severity::High,
+                    FindingKind::TruncatedRecord,
+                    path,
+                    format!(
+                        "{} 위치의 레코드(태그 {})가 선언한 크기 {} 바이트 중 {} 바이트만 남아있습니다",
+                        offset,
+                        tag_id,
+                        size,
+                        reader.remaining()
+                    ),
+                );
+                break;
+            }
+        };
+
+        records.push(RawRecord { tag_id, data });
+    }
+
+    records
+}
+
+/// 범위를 벗어나도 패닉하지 않는 읽기 도구
+struct ByteReader<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> ByteReader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data, position: 0 }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.remaining() == 0
+    }
+
+    fn remaining(&self) -> usize {
+        self.data.len() - self.position
+    }
+
+    fn bytes(&mut self, len: usize) -> Option<&'a [u8]> {
+        if self.remaining() < len {
+            return None;
+        }
+
+        let bytes = &self.data[self.position..self.position + len];
+        self.position += len;
+
+        Some(bytes)
+    }
+
+    fn skip(&mut self, len: usize) -> Option<()> {
+        self.bytes(len).map(|_| ())
+    }
+
+    fn u16(&mut self) -> Option<u16> {
+        self.bytes(2).map(|c| u16::from_le_bytes([c[0], c[1]]))
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        self.bytes(4)
+            .map(|c| u32::from_le_bytes([c[0], c[1], c[2], c[3]]))
+    }
+
+    /// 길이(WORD)가 앞에 붙은 UTF-16 문자열
+    fn string(&mut self) -> Option<String> {
+        let len = self.u16()? as usize;
+        let chars: Vec<u16> = self
+            .bytes(len * 2)?
+            .chunks_exact(2)
+            .map(|c| u16::from_le_bytes([c[0], c[1]]))
+            .collect();
+
+        Some(String::from_utf16_lossy(&chars))
+    }
+
+    /// 길이(DWORD)가 앞에 붙은 UTF-16 문자열
+    fn script_string(&mut self) -> Option<String> {
+        let len = self.u32()? as usize;
+        let chars: Vec<u16> = self
+            .bytes(len.checked_mul(2)?)?
+            .chunks_exact(2)
+            .map(|c| u16::from_le_bytes([c[0], c[1]]))
+            .collect();
+
+        Some(String::from_utf16_lossy(&chars))
+    }
+}
+
+/// JSON 문자열로 감싼다
+fn quote(value: &str) -> String {
+    let mut result = String::with_capacity(value.len() + 2);
+    result.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            c if (c as u32) < 0x20 => result.push_str(&format!("\\u{:04x}", c as u32)),
+            c => result.push(c),
+        }
+    }
+    result.push('"');
+
+    result
+}
@@ -3,10 +3,12 @@ use std::io::Read;
 use crate::hwp::record::{Record, RecordCursor};
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UnknownRecord {
     /// 태그 ID
     pub tag_id: u32,
     /// 데이터
+    #[cfg_attr(feature = "serde", serde(with = "crate::hwp::utils::base64"))]
     pub data: Vec<u8>,
     /// 레코드
     pub children: Vec<Record>,
@@ -0,0 +1,71 @@
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// 표준 Base64 (RFC 4648, 패딩 포함)로 인코딩한다
+pub fn encode(data: &[u8]) -> String {
+    let mut out = String::with_capacity(data.len().div_ceil(3) * 4);
+
+    for chunk in data.chunks(3) {
+        let bytes = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let value = u32::from_be_bytes([0, bytes[0], bytes[1], bytes[2]]);
+
+        for index in 0..4 {
+            if index <= chunk.len() {
+                let sextet = (value >> (18 - index * 6)) & 0x3F;
+                out.push(ALPHABET[sextet as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+
+    out
+}
+
+/// 표준 Base64 (RFC 4648)를 디코딩한다. 패딩은 생략할 수 있다
+#[cfg(feature = "serde")]
+pub fn decode(text: &str) -> Option<Vec<u8>> {
+    let text = text.trim_end_matches('=').as_bytes();
+    let mut out = Vec::with_capacity(text.len() * 3 / 4);
+
+    for chunk in text.chunks(4) {
+        if chunk.len() == 1 {
+            return None;
+        }
+
+        let mut value = 0;
+        for (index, c) in chunk.iter().enumerate() {
+            let sextet = ALPHABET.iter().position(|a| a == c)? as u32;
+            value |= sextet << (18 - index * 6);
+        }
+
+        let bytes = value.to_be_bytes();
+        out.extend_from_slice(&bytes[1..chunk.len()]);
+    }
+
+    Some(out)
+}
+
+/// 바이트를 Base64 문자열로 직렬화한다 (`#[serde(with = "...::base64")]`)
+#[cfg(feature = "serde")]
+pub fn serialize<T: AsRef<[u8]>, S: serde::Serializer>(
+    data: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&encode(data.as_ref()))
+}
+
+/// Base64 문자열을 바이트로 역직렬화한다
+#[cfg(feature = "serde")]
+pub fn deserialize<'de, T: TryFrom<Vec<u8>>, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<T, D::Error> {
+    use serde::{de::Error, Deserialize};
+
+    let text = String::deserialize(deserializer)?;
+    let data = decode(&text).ok_or_else(|| D::Error::custom("잘못된 Base64 문자열"))?;
+    T::try_from(data).map_err(|_| D::Error::custom("바이트 길이가 맞지 않음"))
+}
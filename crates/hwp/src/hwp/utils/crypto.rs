@@ -1,5 +1,5 @@
 use aes::cipher::KeyInit;
-use aes::cipher::{generic_array::GenericArray, BlockDecrypt};
+use aes::cipher::{generic_array::GenericArray, BlockDecrypt, BlockEncrypt};
 use aes::Aes128;
 
 pub fn decrypt_aes_128_ecb(key: &[u8], data: &[u8]) -> Vec<u8> {
@@ -13,3 +13,18 @@ pub fn decrypt_aes_128_ecb(key: &[u8], data: &[u8]) -> Vec<u8> {
 
     blocks.into_iter().flatten().map(|x| x as u8).collect()
 }
+
+/// 16 바이트 단위가 아닌 경우 0으로 채워서 암호화 한다
+pub fn encrypt_aes_128_ecb(key: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut blocks = Vec::new();
+    data.chunks(16).for_each(|chunk| {
+        let mut block = [0u8; 16];
+        block[..chunk.len()].copy_from_slice(chunk);
+        blocks.push(GenericArray::from(block));
+    });
+
+    let cipher = Aes128::new_from_slice(key).unwrap();
+    cipher.encrypt_blocks(&mut blocks);
+
+    blocks.into_iter().flatten().collect()
+}
@@ -0,0 +1,158 @@
+use std::io::{Cursor, Read};
+
+use byteorder::{LittleEndian, ReadBytesExt};
+
+use crate::hwp::record::{reader::RecordReader, tags::DocInfoRecord, writer::RecordWriter};
+
+use super::{
+    crypto::{decrypt_aes_128_ecb, encrypt_aes_128_ecb},
+    random::SRand,
+};
+
+/// 배포용 문서 데이터의 크기
+pub const DISTRIBUTE_DOC_DATA_SIZE: usize = 256;
+
+/// 배포용 문서로 암호화된 스트림을 복호화 한다
+///
+/// 스트림은 `HWPTAG_DISTRIBUTE_DOC_DATA` 레코드로 시작하며 나머지는 AES-128 ECB로 암호화 되어있다.
+/// 압축 해제는 하지 않는다.
+pub fn decrypt_distributed<T: Read>(stream: &mut T) -> Vec<u8> {
+    let data = read_distribute_doc_data(stream);
+    let decryption_key = decryption_key(&data);
+
+    let mut encrypted: Vec<u8> = Vec::new();
+    stream.read_to_end(&mut encrypted).unwrap();
+
+    decrypt_aes_128_ecb(&decryption_key, &encrypted)
+}
+
+/// `decrypt_distributed`와 같지만 형식이 올바르지 않다면 None을 반환한다
+///
+/// 문서를 여는 데 꼭 필요하지 않은 스트림(스크립트 등)을 읽을 때 사용한다.
+pub fn try_decrypt_distributed(stream: &[u8]) -> Option<Vec<u8>> {
+    let header = u32::from_le_bytes(stream.get(0..4)?.try_into().ok()?);
+    let tag_id = header & 0x3FF;
+    let size = (header >> 20) as usize;
+    if tag_id != DocInfoRecord::HWPTAG_DISTRIBUTE_DOC_DATA as u32
+        || size != DISTRIBUTE_DOC_DATA_SIZE
+    {
+        return None;
+    }
+
+    let encrypted = stream.get(4 + DISTRIBUTE_DOC_DATA_SIZE..)?;
+    if encrypted.len() % 16 != 0 {
+        return None;
+    }
+
+    let mut data = [0u8; DISTRIBUTE_DOC_DATA_SIZE];
+    data.copy_from_slice(&stream[4..4 + DISTRIBUTE_DOC_DATA_SIZE]);
+
+    Some(decrypt_aes_128_ecb(&decryption_key(&data), encrypted))
+}
+
+/// 마스킹된 배포용 문서 데이터에서 AES 키(해시 코드의 앞 16바이트)를 구한다
+pub fn decryption_key(data: &[u8; DISTRIBUTE_DOC_DATA_SIZE]) -> [u8; 16] {
+    let decoded = decode_distribute_doc_data(data);
+    let offset = hash_code_offset(data);
+
+    let mut key = [0u8; 16];
+    key.copy_from_slice(&decoded[offset..offset + 16]);
+
+    key
+}
+
+/// 스트림 앞의 `HWPTAG_DISTRIBUTE_DOC_DATA` 레코드를 읽는다 (마스킹된 상태)
+pub fn read_distribute_doc_data<T: Read>(stream: &mut T) -> [u8; DISTRIBUTE_DOC_DATA_SIZE] {
+    let (tag_id, _, size, mut reader) = stream.read_record::<LittleEndian>().unwrap();
+
+    assert_eq!(tag_id, DocInfoRecord::HWPTAG_DISTRIBUTE_DOC_DATA as u32);
+    assert_eq!(size as usize, DISTRIBUTE_DOC_DATA_SIZE);
+
+    let mut data = [0u8; DISTRIBUTE_DOC_DATA_SIZE];
+    reader.read_exact(&mut data).unwrap();
+
+    data
+}
+
+/// 배포용 문서 데이터의 난수 마스킹을 푼다
+pub fn decode_distribute_doc_data(
+    data: &[u8; DISTRIBUTE_DOC_DATA_SIZE],
+) -> [u8; DISTRIBUTE_DOC_DATA_SIZE] {
+    let random_numbers = random_numbers(read_seed(data));
+
+    let mut out = [0u8; DISTRIBUTE_DOC_DATA_SIZE];
+    for (i, value) in out.iter_mut().enumerate() {
+        *value = data[i] ^ random_numbers[i];
+    }
+
+    out
+}
+
+/// 배포용 문서 데이터에 난수 마스킹을 한다
+///
+/// 앞의 4바이트는 마스킹 하지 않고 seed를 기록한다
+pub fn encode_distribute_doc_data(
+    seed: u32,
+    data: &[u8; DISTRIBUTE_DOC_DATA_SIZE],
+) -> [u8; DISTRIBUTE_DOC_DATA_SIZE] {
+    let random_numbers = random_numbers(seed);
+
+    let mut out = [0u8; DISTRIBUTE_DOC_DATA_SIZE];
+    for (i, value) in out.iter_mut().enumerate() {
+        *value = data[i] ^ random_numbers[i];
+    }
+    out[0..4].copy_from_slice(&seed.to_le_bytes());
+
+    out
+}
+
+/// 배포용 문서로 암호화된 스트림을 만든다
+///
+/// `data`는 마스킹된 배포용 문서 데이터, `key`는 해시 코드의 앞 16바이트
+pub fn encrypt_distributed(
+    data: &[u8; DISTRIBUTE_DOC_DATA_SIZE],
+    key: &[u8],
+    stream: &[u8],
+) -> Vec<u8> {
+    let mut out = vec![];
+    out.write_record::<LittleEndian>(DocInfoRecord::HWPTAG_DISTRIBUTE_DOC_DATA as u32, 0, data)
+        .unwrap();
+    out.extend(encrypt_aes_128_ecb(key, stream));
+
+    out
+}
+
+fn random_numbers(seed: u32) -> [u8; DISTRIBUTE_DOC_DATA_SIZE] {
+    let mut rand = SRand::new(seed);
+
+    let mut random_numbers = [0u8; DISTRIBUTE_DOC_DATA_SIZE];
+    let mut i = 0;
+    loop {
+        if i == DISTRIBUTE_DOC_DATA_SIZE {
+            break;
+        }
+        let fill = rand.rand() & 0xFF;
+        let times = (rand.rand() & 0x0F) + 1;
+
+        for _ in 0..times {
+            if i == DISTRIBUTE_DOC_DATA_SIZE {
+                break;
+            }
+
+            random_numbers[i] = fill as u8;
+            i += 1;
+        }
+    }
+
+    random_numbers
+}
+
+/// 배포용 문서 데이터에서 해시 코드(80 바이트)가 시작하는 위치
+pub fn hash_code_offset(data: &[u8; DISTRIBUTE_DOC_DATA_SIZE]) -> usize {
+    ((read_seed(data) & 0xF) + 4) as usize
+}
+
+fn read_seed(data: &[u8; DISTRIBUTE_DOC_DATA_SIZE]) -> u32 {
+    let mut seed_cursor = Cursor::new(&data[0..4]);
+    seed_cursor.read_u32::<LittleEndian>().unwrap()
+}
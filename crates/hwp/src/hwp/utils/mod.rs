@@ -1,3 +1,7 @@
+pub mod base64;
 pub mod bits;
 pub mod crypto;
+pub mod distribute;
 pub mod random;
+pub mod xml;
+pub mod zip;
@@ -0,0 +1,97 @@
+use crate::hwp::doc_info::border_fill::BorderKind;
+
+/// HTML, DOCX, ODT로 바꿀 때 쓰는 선 모양
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineStyle {
+    None,
+    Solid,
+    Dash,
+    Dot,
+    DashDot,
+    DashDotDot,
+    Double,
+    /// 가는선 + 굵은선
+    ThinThick,
+    /// 굵은선 + 가는선
+    ThickThin,
+    /// 가는선 + 굵은선 + 가는선
+    ThinThickThin,
+    Wave,
+    DoubleWave,
+    /// 3D (튀어나온 선)
+    Emboss,
+    /// 3D (들어간 선)
+    Engrave,
+}
+
+impl LineStyle {
+    /// 테두리 선 종류
+    ///
+    /// NOTE: 문서에 기록된 값은 `BorderKind`보다 하나씩 밀려 있어 `Solid`(0)가 선 없음이다
+    pub fn from_border(kind: &BorderKind) -> Self {
+        match kind.clone() as u8 {
+            0 => LineStyle::None,
+            1 => LineStyle::Solid,
+            2 | 6 => LineStyle::Dash,
+            3 | 7 => LineStyle::Dot,
+            4 => LineStyle::DashDot,
+            5 => LineStyle::DashDotDot,
+            8 => LineStyle::Double,
+            9 => LineStyle::ThinThick,
+            10 => LineStyle::ThickThin,
+            11 => LineStyle::ThinThickThin,
+            12 => LineStyle::Wave,
+            13 => LineStyle::DoubleWave,
+            14 | 16 => LineStyle::Emboss,
+            _ => LineStyle::Engrave,
+        }
+    }
+
+    /// CSS와 ODF의 선 종류
+    pub fn to_css(self) -> &'static str {
+        match self {
+            LineStyle::None => "none",
+            LineStyle::Solid | LineStyle::Wave | LineStyle::DoubleWave => "solid",
+            LineStyle::Dash | LineStyle::DashDot | LineStyle::DashDotDot => "dashed",
+            LineStyle::Dot => "dotted",
+            LineStyle::Double
+            | LineStyle::ThinThick
+            | LineStyle::ThickThin
+            | LineStyle::ThinThickThin => "double",
+            LineStyle::Emboss => "ridge",
+            LineStyle::Engrave => "groove",
+        }
+    }
+}
+
+/// 선 굵기(mm)
+pub fn border_width(width: u8) -> f32 {
+    const WIDTHS: [f32; 16] = [
+        0.1, 0.12, 0.15, 0.2, 0.25, 0.3, 0.4, 0.5, 0.6, 0.7, 1.0, 1.5, 2.0, 3.0, 4.0, 5.0,
+    ];
+
+    WIDTHS[(width as usize).min(WIDTHS.len() - 1)]
+}
+
+/// HWPUNIT(1/7200 인치)을 pt로 바꾼다
+pub fn to_pt(value: i32) -> String {
+    format!("{}pt", value as f32 / 100.0)
+}
+
+/// XML(HTML) 특수 문자를 바꾸고 XML에 쓸 수 없는 제어 문자를 지운다
+pub fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\t' | '\n' | '\r' => out.push(c),
+            c if c < ' ' || c == '\u{FFFE}' || c == '\u{FFFF}' => {}
+            _ => out.push(c),
+        }
+    }
+
+    out
+}
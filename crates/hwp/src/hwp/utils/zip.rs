@@ -0,0 +1,121 @@
+use std::io::Write;
+
+use byteorder::{LittleEndian, WriteBytesExt};
+use flate2::{write::DeflateEncoder, Compression, Crc};
+
+const LOCAL_FILE_HEADER: u32 = 0x04034B50;
+const CENTRAL_DIRECTORY_HEADER: u32 = 0x02014B50;
+const END_OF_CENTRAL_DIRECTORY: u32 = 0x06054B50;
+
+/// 파일 이름이 UTF-8임을 나타내는 플래그
+const UTF8_FLAG: u16 = 1 << 11;
+/// 1980-01-01 00:00 (MS-DOS 날짜)
+const DOS_DATE: u16 = (1 << 5) | 1;
+
+/// DOCX, ODT 등 패키지를 만들기 위한 ZIP 쓰기
+///
+/// ZIP64와 암호화는 지원하지 않는다. 모든 파일의 수정 시각은 1980-01-01로 기록한다.
+#[derive(Default)]
+pub struct ZipWriter {
+    data: Vec<u8>,
+    entries: Vec<Entry>,
+}
+
+struct Entry {
+    name: String,
+    method: u16,
+    crc: u32,
+    compressed_size: u32,
+    size: u32,
+    offset: u32,
+}
+
+impl ZipWriter {
+    /// 파일을 추가한다. `compress`가 거짓이라면 압축하지 않고 저장한다
+    pub fn add_file(&mut self, name: &str, data: &[u8], compress: bool) {
+        let mut crc = Crc::new();
+        crc.update(data);
+
+        let (method, content) = if compress {
+            let mut encoder = DeflateEncoder::new(vec![], Compression::default());
+            encoder.write_all(data).unwrap();
+            (8, encoder.finish().unwrap())
+        } else {
+            (0, data.to_vec())
+        };
+
+        let entry = Entry {
+            name: name.to_string(),
+            method,
+            crc: crc.sum(),
+            compressed_size: content.len() as u32,
+            size: data.len() as u32,
+            offset: self.data.len() as u32,
+        };
+
+        let out = &mut self.data;
+        out.write_u32::<LittleEndian>(LOCAL_FILE_HEADER).unwrap();
+        out.write_u16::<LittleEndian>(20).unwrap();
+        out.write_u16::<LittleEndian>(UTF8_FLAG).unwrap();
+        out.write_u16::<LittleEndian>(entry.method).unwrap();
+        out.write_u16::<LittleEndian>(0).unwrap();
+        out.write_u16::<LittleEndian>(DOS_DATE).unwrap();
+        out.write_u32::<LittleEndian>(entry.crc).unwrap();
+        out.write_u32::<LittleEndian>(entry.compressed_size)
+            .unwrap();
+        out.write_u32::<LittleEndian>(entry.size).unwrap();
+        out.write_u16::<LittleEndian>(name.len() as u16).unwrap();
+        out.write_u16::<LittleEndian>(0).unwrap();
+        out.extend_from_slice(name.as_bytes());
+        out.extend_from_slice(&content);
+
+        self.entries.push(entry);
+    }
+
+    /// 중앙 디렉터리를 쓰고 ZIP 파일을 반환한다
+    pub fn finish(mut self) -> Vec<u8> {
+        let offset = self.data.len() as u32;
+
+        let out = &mut self.data;
+        for entry in &self.entries {
+            out.write_u32::<LittleEndian>(CENTRAL_DIRECTORY_HEADER)
+                .unwrap();
+            out.write_u16::<LittleEndian>(20).unwrap();
+            out.write_u16::<LittleEndian>(20).unwrap();
+            out.write_u16::<LittleEndian>(UTF8_FLAG).unwrap();
+            out.write_u16::<LittleEndian>(entry.method).unwrap();
+            out.write_u16::<LittleEndian>(0).unwrap();
+            out.write_u16::<LittleEndian>(DOS_DATE).unwrap();
+            out.write_u32::<LittleEndian>(entry.crc).unwrap();
+            out.write_u32::<LittleEndian>(entry.compressed_size)
+                .unwrap();
+            out.write_u32::<LittleEndian>(entry.size).unwrap();
+            out.write_u16::<LittleEndian>(entry.name.len() as u16)
+                .unwrap();
+            // 확장 필드, 주석, 디스크 번호, 내부 속성
+            out.write_u16::<LittleEndian>(0).unwrap();
+            out.write_u16::<LittleEndian>(0).unwrap();
+            out.write_u16::<LittleEndian>(0).unwrap();
+            out.write_u16::<LittleEndian>(0).unwrap();
+            // 외부 속성
+            out.write_u32::<LittleEndian>(0).unwrap();
+            out.write_u32::<LittleEndian>(entry.offset).unwrap();
+            out.extend_from_slice(entry.name.as_bytes());
+        }
+        let size = out.len() as u32 - offset;
+
+        out.write_u32::<LittleEndian>(END_OF_CENTRAL_DIRECTORY)
+            .unwrap();
+        out.write_u16::<LittleEndian>(0).unwrap();
+        out.write_u16::<LittleEndian>(0).unwrap();
+        out.write_u16::<LittleEndian>(self.entries.len() as u16)
+            .unwrap();
+        out.write_u16::<LittleEndian>(self.entries.len() as u16)
+            .unwrap();
+        out.write_u32::<LittleEndian>(size).unwrap();
+        out.write_u32::<LittleEndian>(offset).unwrap();
+        out.write_u16::<LittleEndian>(0).unwrap();
+
+        self.data
+    }
+}
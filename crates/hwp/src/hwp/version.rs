@@ -1,6 +1,7 @@
 use std::cmp::Ordering;
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Version {
     pub major: u8,
     pub minor: u8,
@@ -1,4 +1,10 @@
-use hwp::HWP;
+use hwp::{
+    hwp::{
+        distribute::{to_distributed, DistributeOptions, HANCOM_RESTRICTIONS},
+        options::ParseOptions,
+    },
+    HWP,
+};
 use std::fs;
 
 use crate::utils::get_tests_path;
@@ -42,3 +48,67 @@ fn check_hwp_equation_format() {
 
     // TODO: (@hahnlee) 정보 채우기
 }
+
+#[test]
+fn check_distributed_scripts() {
+    let path = get_tests_path("integration/hancom/files/한글문서파일형식_수식_revision1.3.hwp");
+    let file = fs::read(path).unwrap();
+
+    let hwp = HWP::from_bytes(&file);
+
+    // 배포용 문서의 스크립트는 암호화 되어있다
+    let scripts = hwp.scripts().unwrap();
+    assert_eq!(scripts.version.high, 1);
+    assert_eq!(scripts.header.starts_with("var Documents"), true);
+}
+
+#[test]
+fn check_distribution_info() {
+    let path = get_tests_path("integration/hancom/files/한글문서파일형식_5.0_revision1.3.hwp");
+    let file = fs::read(path).unwrap();
+
+    let hwp = HWP::from_bytes(&file);
+
+    let info = hwp.distribution_info.unwrap();
+    assert_eq!(info.hash_code, "7D7559E6C9122EDAED6977FB930F256808E8357A");
+    assert_eq!(info.flags, 0x8003);
+}
+
+#[test]
+fn check_to_distributed_layout() {
+    let path = get_tests_path("integration/hancom/files/한글문서파일형식_5.0_revision1.3.hwp");
+    let hancom = HWP::from_bytes(&fs::read(path).unwrap());
+
+    let path = get_tests_path("integration/project/files/hello_world.hwp");
+    let options = DistributeOptions {
+        password: "password".to_string(),
+        restrictions: HANCOM_RESTRICTIONS,
+        seed: None,
+    };
+    let distributed =
+        to_distributed(&fs::read(path).unwrap(), &options, &ParseOptions::default()).unwrap();
+    let hwp = HWP::from_bytes(&distributed);
+
+    // 한/글이 만든 배포용 문서와 같은 구조로 만든다
+    for hwp in [&hancom, &hwp] {
+        assert_eq!(hwp.header.flags.distributed, true);
+        assert_eq!(hwp.body_texts.sections.len(), 1);
+        assert_eq!(hwp.body_texts.sections[0].paragraphs.len(), 1);
+        assert_eq!(hwp.body_texts.sections[0].paragraphs[0].controls.len(), 2);
+        assert_eq!(hwp.view_texts.is_some(), true);
+
+        let info = hwp.distribution_info.as_ref().unwrap();
+        assert_eq!(info.hash_code.len(), 40);
+        assert_eq!(
+            info.hash_code
+                .chars()
+                .all(|c| c.is_ascii_digit() || c.is_ascii_uppercase()),
+            true
+        );
+    }
+
+    assert_eq!(
+        hwp.distribution_info.unwrap().flags,
+        hancom.distribution_info.unwrap().flags
+    );
+}
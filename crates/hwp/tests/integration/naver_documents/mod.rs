@@ -1,5 +1,9 @@
-use hwp::HWP;
-use std::fs;
+use flate2::{write::DeflateEncoder, Compression};
+use hwp::{
+    hwp::{options::ParseOptions, scripts::Scripts, storage::Storage},
+    HWP,
+};
+use std::{fs, io::Write};
 
 use crate::utils::get_tests_path;
 
@@ -40,3 +44,40 @@ fn check_work_report() {
 
     // TODO: (@hahnlee) 정보 채우기
 }
+
+#[test]
+fn check_scripts() {
+    let path = get_tests_path("integration/naver_documents/files/annual_report.hwp");
+    let file = fs::read(path).unwrap();
+
+    let hwp = HWP::from_bytes(&file);
+
+    let scripts = hwp.scripts().unwrap();
+    assert_eq!(scripts.version.high, 1);
+    assert_eq!(scripts.version.low, 0);
+    assert_eq!(
+        scripts.header,
+        "var Documents = XHwpDocuments;\r\nvar Document = Documents.Active_XHwpDocument;\r\n"
+    );
+    assert_eq!(
+        scripts.source.starts_with("function OnDocument_New()"),
+        true
+    );
+    assert_eq!(scripts.pre_source, "");
+    assert_eq!(scripts.post_source, "");
+
+    // 잘못된 스크립트는 None으로 읽는다
+    let read_scripts = |script: Vec<u8>| -> Option<Scripts> {
+        let mut storage = Storage::from_bytes(&file, &ParseOptions::default()).unwrap();
+        storage.set("/Scripts/DefaultJScript", script);
+        HWP::from_bytes(&storage.to_bytes()).scripts
+    };
+    assert_eq!(read_scripts(vec![0xFF; 16]).is_none(), true);
+
+    // 길이가 모자라거나 짝이 없는 서로게이트
+    for script in [vec![2, 0, 0, 0, 0x41], vec![1, 0, 0, 0, 0x00, 0xD8]] {
+        let mut encoder = DeflateEncoder::new(vec![], Compression::default());
+        encoder.write_all(&script).unwrap();
+        assert_eq!(read_scripts(encoder.finish().unwrap()).is_none(), true);
+    }
+}
@@ -1,8 +1,42 @@
+use flate2::{read::DeflateDecoder, write::DeflateEncoder, Compression};
 use hwp::{
-    hwp::doc_info::border_fill::{FillKind, GradationKind, PatternKind},
+    hwp::{
+        distribute::{to_distributed, DistributeOptions, HANCOM_RESTRICTIONS},
+        doc_info::border_fill::{FillKind, GradationKind, PatternKind},
+        error::Error,
+        html::HtmlOptions,
+        markdown::MarkdownOptions,
+        options::{Limit, ParseOptions},
+        paragraph::{
+            control::{
+                common_properties::{Caption, CaptionAlign},
+                footnote_endnote::FootnoteEndnote,
+                header_footer::HeaderFooter,
+                shape_object::{
+                    content::ShapeObjectContent,
+                    video::{VideoKind, VideoRecord},
+                },
+                Control,
+            },
+            pua_map::PuaMap,
+            run::RunContent,
+            text_options::TextOptions,
+        },
+        preview::{ImageKind, Preview},
+        privacy::{find_personal_info, scan, NestedParagraph, ParagraphLocation, PersonalInfoKind},
+        redact::{redact, redact_spans, RedactAction, RedactOptions, RedactSpan},
+        sanitize::{sanitize, SanitizeAction},
+        scrub::{scrub, ScrubAction, ScrubOptions, ScrubSkip},
+        storage::Storage,
+        summary_info::{PropertyId, PropertyValue, SummaryInfo, UnknownSection},
+        triage::{analyze, FindingKind, Severity},
+    },
     HWP,
 };
-use std::fs;
+use std::{
+    fs,
+    io::{Cursor, Read, Write},
+};
 
 use crate::utils::get_tests_path;
 
@@ -239,4 +273,1753 @@ fn check_group_with_draw_text() {
     assert_eq!(hwp.header.license.replication_restrictions, false);
 
     assert_eq!(hwp.body_texts.sections.len(), 1);
-}
\ No newline at end of file
+}
+
+#[test]
+fn check_video() {
+    let path = get_tests_path("integration/project/files/video.hwp");
+    let file = fs::read(path).unwrap();
+
+    let hwp = HWP::from_bytes(&file);
+
+    assert_eq!(hwp.header.flags.has_video_control, true);
+
+    let videos: Vec<&VideoRecord> = hwp.body_texts.sections[0]
+        .paragraphs
+        .iter()
+        .flat_map(|paragraph| paragraph.controls.iter())
+        .filter_map(|control| match control {
+            Control::GenShapeObject(control) => match &control.content {
+                ShapeObjectContent::Video(video) => Some(video),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect();
+    assert_eq!(videos.len(), 2);
+
+    assert_eq!(videos[0].kind, VideoKind::Local);
+    assert_eq!(videos[0].video_bin_id, Some(1));
+    assert_eq!(videos[0].thumbnail_bin_id, 2);
+    assert_eq!(videos[0].url(), None);
+
+    assert_eq!(videos[1].kind, VideoKind::Web);
+    assert_eq!(videos[1].video_bin_id, None);
+    assert_eq!(videos[1].thumbnail_bin_id, 3);
+    assert_eq!(
+        videos[1].url(),
+        Some("https://www.youtube.com/embed/hwp-rs".to_string())
+    );
+}
+
+#[test]
+fn check_summary_info() {
+    let path = get_tests_path("integration/project/files/hello_world.hwp");
+    let file = fs::read(path).unwrap();
+
+    let hwp = HWP::from_bytes(&file);
+
+    let summary_info = hwp.summary_info.unwrap();
+    assert_eq!(summary_info.title(), Some("Hello World"));
+    assert_eq!(summary_info.subject(), Some(""));
+    assert_eq!(summary_info.author(), Some("hanlee"));
+    assert_eq!(summary_info.last_saved_by(), Some("hanlee"));
+    assert_eq!(
+        summary_info.revision(),
+        Some("10.30.11.3339 MAC64LEDarwin_21.6.0")
+    );
+    assert_eq!(
+        summary_info.last_printed().unwrap().to_unix_timestamp(),
+        None
+    );
+    assert_eq!(
+        summary_info.created_at().unwrap().to_unix_timestamp(),
+        Some(1664280483)
+    );
+
+    let bytes = summary_info.to_bytes();
+    assert_eq!(SummaryInfo::from_bytes(&bytes), Some(summary_info.clone()));
+
+    // 잘린 요약 정보는 무시하고 문서를 연다
+    assert_eq!(SummaryInfo::from_bytes(&bytes[..60]), None);
+    let mut storage = Storage::from_bytes(&file, &ParseOptions::default()).unwrap();
+    storage.set("/\u{5}HwpSummaryInformation", bytes[..60].to_vec());
+    let hwp = HWP::from_bytes(&storage.to_bytes());
+    assert_eq!(hwp.summary_info, None);
+    assert_eq!(hwp.body_texts.sections.len(), 1);
+
+    // 둘째 섹션도 그대로 다시 쓴다
+    let mut summary_info = summary_info;
+    summary_info.other_sections.push(UnknownSection {
+        format_id: [1; 16],
+        data: vec![8, 0, 0, 0, 0, 0, 0, 0],
+    });
+    let bytes = summary_info.to_bytes();
+    assert_eq!(SummaryInfo::from_bytes(&bytes), Some(summary_info));
+}
+
+#[test]
+fn check_preview() {
+    let path = get_tests_path("integration/project/files/hello_world.hwp");
+    let file = fs::read(path).unwrap();
+
+    let hwp = HWP::from_bytes(&file);
+    assert_eq!(hwp.preview_text(), Some("Hello World!\r\n"));
+    assert_eq!(hwp.preview_image().unwrap().kind, ImageKind::Png);
+
+    let preview = Preview::from_bytes(&file, &ParseOptions::default()).unwrap();
+    assert_eq!(preview.text, Some("Hello World!\r\n".to_string()));
+    assert_eq!(
+        preview.image.unwrap().data,
+        hwp.preview_image().unwrap().data
+    );
+
+    // 미리보기 이미지보다 작은 제한
+    let options = ParseOptions {
+        max_stream_size: 64,
+        ..ParseOptions::default()
+    };
+    assert!(matches!(
+        Preview::from_bytes(&file, &options),
+        Err(Error::LimitExceeded {
+            limit: Limit::StreamSize,
+            ..
+        })
+    ));
+    assert!(matches!(
+        Preview::from_bytes(b"PrvText", &ParseOptions::default()),
+        Err(Error::InvalidFile)
+    ));
+}
+
+#[test]
+fn check_doc_history() {
+    let path = get_tests_path("integration/project/files/history.hwp");
+    let file = fs::read(path).unwrap();
+
+    let hwp = HWP::from_bytes(&file);
+
+    assert_eq!(hwp.header.flags.vcs, true);
+
+    let doc_history = hwp.doc_history.as_ref().unwrap();
+    assert_eq!(doc_history.items.len(), 2);
+
+    let first = doc_history.get(1).unwrap();
+    assert_eq!(first.writer.as_deref(), Some("hanlee"));
+    assert_eq!(first.description.as_deref(), Some("초안"));
+    assert_eq!(first.date.as_ref().unwrap().year, 2022);
+    assert_eq!(first.date.as_ref().unwrap().day, 27);
+    assert_eq!(first.diff_data, None);
+    assert_eq!(first.locked, false);
+    assert_eq!(first.auto_saved, false);
+
+    let second = doc_history.get(2).unwrap();
+    assert_eq!(second.description.as_deref(), Some("인사말 완성"));
+    assert_eq!(
+        second.diff_data.as_deref(),
+        Some("<DIFFML><INSERT Pos=\"5\"> World!</INSERT></DIFFML>")
+    );
+    assert_eq!(
+        second.last_document_data.as_deref(),
+        Some("<HWPML Version=\"2.8\"><BODY><P>Hello World!</P></BODY></HWPML>")
+    );
+    assert_eq!(second.locked, true);
+    assert_eq!(second.auto_saved, true);
+
+    // 배포용 문서는 문서 이력도 암호화 되어있다
+    let distributed = to_distributed(
+        &file,
+        &DistributeOptions::default(),
+        &ParseOptions::default(),
+    )
+    .unwrap();
+    let hwp = HWP::from_bytes(&distributed);
+    let doc_history = hwp.doc_history.as_ref().unwrap();
+    assert_eq!(doc_history.items.len(), 2);
+    assert_eq!(
+        doc_history.get(2).unwrap().writer.as_deref(),
+        Some("hanlee")
+    );
+
+    // 올바르지 않은 VersionLog는 건너뛴다
+    let mut storage = Storage::from_bytes(&file, &ParseOptions::default()).unwrap();
+    storage.set("/DocHistory/VersionLog0", vec![0xFF; 64]);
+    let hwp = HWP::from_bytes(&storage.to_bytes());
+    let doc_history = hwp.doc_history.as_ref().unwrap();
+    assert_eq!(doc_history.items.len(), 1);
+    assert_eq!(doc_history.get(1).is_none(), true);
+    assert_eq!(doc_history.get(2).is_some(), true);
+}
+
+#[test]
+fn check_password_required() {
+    let path = get_tests_path("integration/project/files/hello_world.hwp");
+    let mut file = fs::read(path).unwrap();
+
+    let hwp = HWP::from_bytes_with_options(&file, &ParseOptions::default()).unwrap();
+    assert_eq!(hwp.header.flags.encrypted, false);
+
+    // 파일 헤더의 암호 설정 여부 비트를 켠다
+    let offset = file
+        .windows(17)
+        .position(|window| window == b"HWP Document File")
+        .unwrap();
+    file[offset + 36] |= 0b10;
+
+    assert!(matches!(
+        HWP::from_bytes_with_options(&file, &ParseOptions::default()),
+        Err(Error::PasswordRequired)
+    ));
+
+    // 복합 파일이 아니거나 파일 헤더가 잘못된 경우
+    assert!(matches!(
+        HWP::from_bytes_with_options(b"HWP Document File", &ParseOptions::default()),
+        Err(Error::InvalidFile)
+    ));
+    file[offset] = b'X';
+    assert!(matches!(
+        HWP::from_bytes_with_options(&file, &ParseOptions::default()),
+        Err(Error::InvalidFile)
+    ));
+}
+
+#[test]
+fn check_to_distributed() {
+    let path = get_tests_path("integration/project/files/hello_world.hwp");
+    let file = fs::read(path).unwrap();
+
+    let options = DistributeOptions {
+        password: "password".to_string(),
+        restrictions: HANCOM_RESTRICTIONS,
+        seed: None,
+    };
+    let distributed = to_distributed(&file, &options, &ParseOptions::default()).unwrap();
+
+    let hwp = HWP::from_bytes(&distributed);
+    assert_eq!(hwp.header.flags.distributed, true);
+
+    // 본문은 비워지고 내용은 ViewText로 옮겨진다
+    assert_eq!(hwp.body_texts.sections.len(), 1);
+    assert_eq!(hwp.body_texts.sections[0].paragraphs.len(), 1);
+    assert_eq!(hwp.body_texts.sections[0].paragraphs[0].to_string(), "");
+    assert_eq!(hwp.body_texts.sections[0].paragraphs[0].controls.len(), 2);
+
+    let view_texts = hwp.view_texts.unwrap();
+    assert_eq!(view_texts.sections.len(), 1);
+    assert_eq!(
+        view_texts.sections[0].paragraphs[0].to_string(),
+        "Hello World!"
+    );
+
+    assert_eq!(hwp.scripts.is_some(), true);
+
+    // 이미 배포용 문서라면 다시 만들지 않는다
+    assert!(matches!(
+        to_distributed(&distributed, &options, &ParseOptions::default()),
+        Err(Error::AlreadyDistributed)
+    ));
+
+    // 압축되지 않은 문서는 만들 수 없다
+    let mut cfb = cfb::CompoundFile::open(Cursor::new(file.clone())).unwrap();
+    let mut header = vec![];
+    cfb.open_stream("/FileHeader")
+        .unwrap()
+        .read_to_end(&mut header)
+        .unwrap();
+    header[36] &= !1;
+    cfb.create_stream("/FileHeader")
+        .unwrap()
+        .write_all(&header)
+        .unwrap();
+    cfb.flush().unwrap();
+    let uncompressed = cfb.into_inner().into_inner();
+    assert!(matches!(
+        to_distributed(&uncompressed, &options, &ParseOptions::default()),
+        Err(Error::NotCompressed)
+    ));
+
+    // 문서가 아니라면 오류를 반환한다
+    assert!(matches!(
+        to_distributed(b"not a document", &options, &ParseOptions::default()),
+        Err(Error::InvalidFile)
+    ));
+}
+
+#[test]
+fn check_distribution_info() {
+    let path = get_tests_path("integration/project/files/hello_world.hwp");
+    let file = fs::read(path).unwrap();
+
+    // 일반 문서에는 배포용 문서 정보가 없다
+    let hwp = HWP::from_bytes(&file);
+    assert_eq!(hwp.distribution_info.is_none(), true);
+
+    // 제한 속성은 원본 값 그대로 읽는다
+    for (restrictions, flags) in [
+        (0x0000, 0x8000),
+        (0x0001, 0x8001),
+        (0x0002, 0x8002),
+        (HANCOM_RESTRICTIONS, 0x8003),
+    ] {
+        let options = DistributeOptions {
+            password: "password".to_string(),
+            restrictions,
+            seed: None,
+        };
+        let hwp =
+            HWP::from_bytes(&to_distributed(&file, &options, &ParseOptions::default()).unwrap());
+
+        let info = hwp.distribution_info.unwrap();
+        assert_eq!(info.flags, flags);
+    }
+}
+
+#[test]
+fn check_storage_attributes() {
+    let path = get_tests_path("integration/project/files/hello_world.hwp");
+    let file = fs::read(path).unwrap();
+
+    let clsid = uuid::Uuid::from_u128(0x1234_5678_9ABC_DEF0_1234_5678_9ABC_DEF0);
+    let mut cfb = cfb::CompoundFile::open(Cursor::new(file)).unwrap();
+    cfb.set_storage_clsid("/", clsid).unwrap();
+    cfb.set_storage_clsid("/BodyText", clsid).unwrap();
+    cfb.set_state_bits("/FileHeader", 0x10).unwrap();
+    cfb.flush().unwrap();
+    let file = cfb.into_inner().into_inner();
+
+    // 다시 쓴 문서와 배포용으로 바꾼 문서 모두 CLSID와 상태 비트를 유지한다
+    let rewritten = Storage::from_bytes(&file, &ParseOptions::default())
+        .unwrap()
+        .to_bytes();
+    let distributed = to_distributed(
+        &file,
+        &DistributeOptions::default(),
+        &ParseOptions::default(),
+    )
+    .unwrap();
+    for bytes in [rewritten, distributed] {
+        let cfb = cfb::CompoundFile::open(Cursor::new(bytes)).unwrap();
+        assert_eq!(cfb.root_entry().clsid(), &clsid);
+        assert_eq!(cfb.entry("/BodyText").unwrap().clsid(), &clsid);
+        assert_eq!(cfb.entry("/FileHeader").unwrap().state_bits(), 0x10);
+        assert_eq!(cfb.entry("/DocInfo").unwrap().state_bits(), 0);
+    }
+}
+
+#[test]
+fn check_sanitize() {
+    let path = get_tests_path("integration/project/files/hello_world.hwp");
+    let file = fs::read(path).unwrap();
+
+    let (sanitized, report) = sanitize(&file, &ParseOptions::default()).unwrap();
+    assert_eq!(report.actions, vec![SanitizeAction::RemoveScripts]);
+
+    let hwp = HWP::from_bytes(&sanitized);
+    assert_eq!(hwp.header.flags.has_script, false);
+    assert_eq!(hwp.scripts.is_none(), true);
+    assert_eq!(
+        hwp.body_texts.sections[0].paragraphs[0].to_string(),
+        "Hello World!"
+    );
+
+    // 정리된 문서는 다시 정리할 것이 없다
+    let (_, report) = sanitize(&sanitized, &ParseOptions::default()).unwrap();
+    assert_eq!(report.is_clean(), true);
+
+    // 문서가 아니거나 제한을 넘으면 오류를 반환한다
+    assert!(matches!(
+        sanitize(b"not a document", &ParseOptions::default()),
+        Err(Error::InvalidFile)
+    ));
+    let options = ParseOptions {
+        max_stream_size: 16,
+        ..ParseOptions::default()
+    };
+    assert!(matches!(
+        sanitize(&file, &options),
+        Err(Error::LimitExceeded {
+            limit: Limit::StreamSize,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn check_sanitize_embedded_objects() {
+    let path = get_tests_path("integration/project/files/embedded_objects.hwp");
+    let file = fs::read(path).unwrap();
+
+    let (sanitized, report) = sanitize(&file, &ParseOptions::default()).unwrap();
+    assert_eq!(
+        report.actions,
+        vec![
+            SanitizeAction::RemoveScripts,
+            SanitizeAction::RemoveOle("/BinData/BIN0002.OLE".to_string()),
+            SanitizeAction::RemovePostScript("/BinData/BIN0003.dat".to_string()),
+            SanitizeAction::RemoveLink("C:\\Users\\hwp\\secret.png".to_string()),
+        ]
+    );
+
+    // 정리한 문서도 열 수 있어야 한다
+    let hwp = HWP::from_bytes(&sanitized);
+    let bin_data: Vec<(&str, usize)> = hwp
+        .bin_data
+        .iter()
+        .map(|file| (file.name.as_str(), file.data.len()))
+        .collect();
+    assert_eq!(
+        bin_data,
+        vec![
+            ("BIN0001.jpg", 924214),
+            ("BIN0002.ole", 0),
+            ("BIN0003.dat", 0)
+        ]
+    );
+
+    let link = &hwp.doc_info.id_mappings.binary_data[3];
+    assert_eq!(link.absolute_path.as_deref(), Some(""));
+    assert_eq!(link.relative_path.as_deref(), Some(""));
+
+    let (_, report) = sanitize(&sanitized, &ParseOptions::default()).unwrap();
+    assert_eq!(report.is_clean(), true);
+}
+
+#[test]
+fn check_triage() {
+    let path = get_tests_path("integration/project/files/hello_world.hwp");
+    let file = fs::read(path).unwrap();
+
+    // 한/글이 저장한 기본 스크립트는 스크립트로 보지 않는다
+    let report = analyze(&file, &ParseOptions::default());
+    assert_eq!(report.risk(), Some(Severity::Info));
+    assert_eq!(report.has(FindingKind::Script), false);
+    assert_eq!(
+        report
+            .streams
+            .iter()
+            .any(|stream| stream.path == "/BodyText/Section0" && stream.known),
+        true
+    );
+
+    let mut script = vec![];
+    for source in [
+        "",
+        "new ActiveXObject(\"WScript.Shell\").Run(\"calc\");",
+        "",
+        "",
+    ] {
+        let source: Vec<u8> = source
+            .encode_utf16()
+            .flat_map(|c| c.to_le_bytes())
+            .collect();
+        script.extend((source.len() as u32 / 2).to_le_bytes());
+        script.extend(source);
+    }
+    let mut encoder = DeflateEncoder::new(vec![], Compression::default());
+    encoder.write_all(&script).unwrap();
+
+    let mut storage = Storage::from_bytes(&file, &ParseOptions::default()).unwrap();
+    storage.set("/Scripts/DefaultJScript", encoder.finish().unwrap());
+    storage.set("/Payload", vec![0x4D, 0x5A]);
+
+    let report = analyze(&storage.to_bytes(), &ParseOptions::default());
+    assert_eq!(report.risk(), Some(Severity::High));
+    assert_eq!(report.has(FindingKind::Script), true);
+    assert_eq!(
+        report
+            .findings
+            .iter()
+            .any(|finding| finding.kind == FindingKind::UnknownStream
+                && finding.path == "/Payload"),
+        true
+    );
+
+    let json = report.to_json();
+    assert_eq!(json.starts_with("{\"risk\":\"high\""), true);
+    assert_eq!(json.contains("\"kind\":\"script\""), true);
+
+    let report = analyze(b"not a hwp file", &ParseOptions::default());
+    assert_eq!(report.has(FindingKind::InvalidFile), true);
+
+    // 작은 스트림이 제한보다 크게 풀리는 압축 폭탄
+    let mut encoder = DeflateEncoder::new(vec![], Compression::default());
+    encoder.write_all(&vec![0; 4 * 1024 * 1024]).unwrap();
+    let mut storage = Storage::from_bytes(&file, &ParseOptions::default()).unwrap();
+    storage.set("/BodyText/Section1", encoder.finish().unwrap());
+    let bytes = storage.to_bytes();
+
+    let options = ParseOptions {
+        max_stream_size: 1024 * 1024,
+        ..ParseOptions::default()
+    };
+    let report = analyze(&bytes, &options);
+    assert_eq!(
+        report
+            .findings
+            .iter()
+            .any(|finding| finding.kind == FindingKind::LimitExceeded
+                && finding.path == "/BodyText/Section1"),
+        true
+    );
+
+    // 저장소는 압축을 풀지 않은 스트림의 크기를 확인한다
+    let options = ParseOptions {
+        max_stream_size: 16,
+        ..ParseOptions::default()
+    };
+    assert!(matches!(
+        Storage::from_bytes(&bytes, &options),
+        Err(Error::LimitExceeded {
+            limit: Limit::StreamSize,
+            ..
+        })
+    ));
+    assert!(matches!(
+        Storage::from_bytes(b"not a hwp file", &options),
+        Err(Error::InvalidFile)
+    ));
+}
+
+#[test]
+fn check_parse_options() {
+    let path = get_tests_path("integration/project/files/hello_world.hwp");
+    let file = fs::read(path).unwrap();
+
+    let hwp = HWP::from_bytes_with_options(&file, &ParseOptions::default()).unwrap();
+    assert_eq!(hwp.body_texts.sections.len(), 1);
+
+    let check = |options: ParseOptions| match HWP::from_bytes_with_options(&file, &options) {
+        Err(Error::LimitExceeded { limit, .. }) => limit,
+        _ => panic!("제한을 넘어야 합니다"),
+    };
+
+    let limit = check(ParseOptions {
+        max_stream_size: 64,
+        ..Default::default()
+    });
+    assert_eq!(limit, Limit::StreamSize);
+
+    let limit = check(ParseOptions {
+        max_record_size: 8,
+        ..Default::default()
+    });
+    assert_eq!(limit, Limit::RecordSize);
+
+    let limit = check(ParseOptions {
+        max_level: 1,
+        ..Default::default()
+    });
+    assert_eq!(limit, Limit::Level);
+
+    let limit = check(ParseOptions {
+        max_paragraphs: 0,
+        ..Default::default()
+    });
+    assert_eq!(limit, Limit::Paragraphs);
+
+    let limit = check(ParseOptions {
+        max_controls: 1,
+        ..Default::default()
+    });
+    assert_eq!(limit, Limit::Controls);
+
+    let path = get_tests_path("integration/project/files/image_fill.hwp");
+    let file = fs::read(path).unwrap();
+    let result = HWP::from_bytes_with_options(
+        &file,
+        &ParseOptions {
+            max_bin_data_size: 1024,
+            ..Default::default()
+        },
+    );
+    assert!(matches!(
+        result,
+        Err(Error::LimitExceeded {
+            limit: Limit::BinDataSize,
+            ..
+        })
+    ));
+
+    // 압축을 풀 수 없는 본문은 오류를 반환한다
+    let mut storage = Storage::from_bytes(&file, &ParseOptions::default()).unwrap();
+    storage.set("/BodyText/Section0", vec![0xFF; 64]);
+    let result = HWP::from_bytes_with_options(&storage.to_bytes(), &ParseOptions::default());
+    match result {
+        Err(Error::InvalidStream(path)) => assert_eq!(path, "/BodyText/Section0"),
+        _ => panic!("올바르지 않은 스트림 이어야 합니다"),
+    }
+
+    // 요약 정보와 미리보기도 같은 제한을 적용한다
+    for path in ["/\u{5}HwpSummaryInformation", "/PrvImage"] {
+        let mut storage = Storage::from_bytes(&file, &ParseOptions::default()).unwrap();
+        storage.set(path, vec![0; 2 * 1024 * 1024]);
+        let options = ParseOptions {
+            max_stream_size: 1024 * 1024,
+            ..Default::default()
+        };
+        match HWP::from_bytes_with_options(&storage.to_bytes(), &options) {
+            Err(Error::LimitExceeded {
+                limit: Limit::StreamSize,
+                path: limited,
+            }) => assert_eq!(limited, path),
+            _ => panic!("제한을 넘어야 합니다"),
+        }
+    }
+}
+
+#[test]
+fn check_scrub() {
+    let path = get_tests_path("integration/project/files/hello_world.hwp");
+    let file = fs::read(path).unwrap();
+
+    let mut storage = Storage::from_bytes(&file, &ParseOptions::default()).unwrap();
+    storage.set("/DocHistory/VersionLog0", vec![0; 16]);
+    let mut header = storage.get("/FileHeader").unwrap().to_vec();
+    header[36] |= 1 << 6;
+    storage.set("/FileHeader", header);
+    let file = storage.to_bytes();
+
+    let (scrubbed, report) =
+        scrub(&file, &ScrubOptions::default(), &ParseOptions::default()).unwrap();
+    assert_eq!(
+        report.actions,
+        vec![
+            ScrubAction::Author(
+                "/\u{5}HwpSummaryInformation".to_string(),
+                Some("hanlee".to_string())
+            ),
+            ScrubAction::LastSavedBy(
+                "/\u{5}HwpSummaryInformation".to_string(),
+                Some("hanlee".to_string())
+            ),
+            ScrubAction::DocHistory,
+        ]
+    );
+
+    let hwp = HWP::from_bytes(&scrubbed);
+    assert_eq!(hwp.header.flags.vcs, false);
+    assert_eq!(hwp.doc_history.is_none(), true);
+    let summary_info = hwp.summary_info.unwrap();
+    assert_eq!(summary_info.author(), None);
+    assert_eq!(summary_info.last_saved_by(), None);
+    assert_eq!(summary_info.title(), Some("Hello World"));
+    assert_eq!(
+        hwp.body_texts.sections[0].paragraphs[0].to_string(),
+        "Hello World!"
+    );
+
+    // 정리된 문서는 다시 정리할 것이 없다
+    let (_, report) = scrub(
+        &scrubbed,
+        &ScrubOptions::default(),
+        &ParseOptions::default(),
+    )
+    .unwrap();
+    assert_eq!(report.is_clean(), true);
+
+    // 문서가 아니라면 오류를 반환한다
+    assert!(matches!(
+        scrub(
+            b"not a document",
+            &ScrubOptions::default(),
+            &ParseOptions::default()
+        ),
+        Err(Error::InvalidFile)
+    ));
+
+    let options = ScrubOptions {
+        pseudonym: Some("익명".to_string()),
+    };
+    let (scrubbed, _) = scrub(&file, &options, &ParseOptions::default()).unwrap();
+    let summary_info = HWP::from_bytes(&scrubbed).summary_info.unwrap();
+    assert_eq!(summary_info.author(), Some("익명"));
+    assert_eq!(summary_info.last_saved_by(), Some("익명"));
+
+    // 해석하지 못한 지은이(VT_LPSTR)도 지우지만 원래 값은 알 수 없다
+    let mut storage = Storage::from_bytes(&file, &ParseOptions::default()).unwrap();
+    let mut summary_info =
+        SummaryInfo::from_bytes(storage.get("/\u{5}HwpSummaryInformation").unwrap()).unwrap();
+    summary_info.set(
+        PropertyId::Author,
+        PropertyValue::Unknown(vec![0x1E, 0, 0, 0, 4, 0, 0, 0, b'k', b'i', b'm', 0]),
+    );
+    summary_info.remove(PropertyId::LastSavedBy);
+    storage.set("/\u{5}SummaryInformation", summary_info.to_bytes());
+
+    let (scrubbed, report) = scrub(
+        &storage.to_bytes(),
+        &ScrubOptions::default(),
+        &ParseOptions::default(),
+    )
+    .unwrap();
+    assert_eq!(
+        report.actions[2],
+        ScrubAction::Author("/\u{5}SummaryInformation".to_string(), None)
+    );
+    let storage = Storage::from_bytes(&scrubbed, &ParseOptions::default()).unwrap();
+    let summary_info =
+        SummaryInfo::from_bytes(storage.get("/\u{5}SummaryInformation").unwrap()).unwrap();
+    assert_eq!(summary_info.get(PropertyId::Author), None);
+}
+
+const HWPTAG_CTRL_HEADER: u32 = 0x47;
+const HWPTAG_CTRL_DATA: u32 = 0x57;
+const HWPTAG_TRACK_CHANGE_AUTHOR: u32 = 0x61;
+
+/// 압축된 스트림을 (태그, 깊이, 데이터) 레코드로 읽는다
+fn read_stream_records(storage: &Storage, path: &str) -> Vec<(u32, u32, Vec<u8>)> {
+    let mut data = vec![];
+    DeflateDecoder::new(storage.get(path).unwrap())
+        .read_to_end(&mut data)
+        .unwrap();
+
+    let mut records = vec![];
+    let mut offset = 0;
+    while offset < data.len() {
+        let header = u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap());
+        offset += 4;
+        let mut size = (header >> 20) as usize;
+        if size == 0xFFF {
+            size = u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
+            offset += 4;
+        }
+        records.push((
+            header & 0x3FF,
+            (header >> 10) & 0x3FF,
+            data[offset..offset + size].to_vec(),
+        ));
+        offset += size;
+    }
+
+    records
+}
+
+/// 압축된 스트림의 레코드를 고친다
+fn update_stream_records<F: FnOnce(&mut Vec<(u32, u32, Vec<u8>)>)>(
+    storage: &mut Storage,
+    path: &str,
+    f: F,
+) {
+    let mut records = read_stream_records(storage, path);
+    f(&mut records);
+
+    let mut encoder = DeflateEncoder::new(vec![], Compression::default());
+    for (tag_id, level, data) in records {
+        assert!(data.len() < 0xFFF);
+        let header = tag_id | (level << 10) | ((data.len() as u32) << 20);
+        encoder.write_all(&header.to_le_bytes()).unwrap();
+        encoder.write_all(&data).unwrap();
+    }
+    storage.set(path, encoder.finish().unwrap());
+}
+
+/// 필드 컨트롤 헤더의 데이터를 만든다
+fn field_record(ctrl_id: &[u8; 4], command: &str) -> Vec<u8> {
+    let mut data = u32::from_be_bytes(*ctrl_id).to_le_bytes().to_vec();
+    data.extend([0; 5]);
+    data.extend((command.encode_utf16().count() as u16).to_le_bytes());
+    data.extend(command.encode_utf16().flat_map(|c| c.to_le_bytes()));
+    data.extend(7u32.to_le_bytes());
+    data
+}
+
+fn string_parameter_set(value: &str) -> Vec<u8> {
+    let mut data = vec![0x1B, 0x02, 1, 0, 0, 0, 0x00, 0x40, 1, 0];
+    data.extend((value.encode_utf16().count() as u16).to_le_bytes());
+    data.extend(value.encode_utf16().flat_map(|c| c.to_le_bytes()));
+    data
+}
+
+#[test]
+fn check_scrub_fields() {
+    let path = get_tests_path("integration/project/files/hello_world.hwp");
+    let file = fs::read(path).unwrap();
+
+    let mut storage = Storage::from_bytes(&file, &ParseOptions::default()).unwrap();
+    let mut author_index = 0;
+    update_stream_records(&mut storage, "/DocInfo", |records| {
+        author_index = records.len();
+        // 이름 (홍길동), 뒤의 데이터
+        let data = vec![3, 0, 0x4D, 0xD6, 0x38, 0xAE, 0xD9, 0xB3, 1, 0];
+        records.push((HWPTAG_TRACK_CHANGE_AUTHOR, 0, data));
+        // 이름의 길이보다 짧은 레코드
+        records.push((HWPTAG_TRACK_CHANGE_AUTHOR, 0, vec![9, 0, 0x4D]));
+    });
+    update_stream_records(&mut storage, "/BodyText/Section0", |records| {
+        let memo = field_record(b"%%me", "작성자 홍길동");
+        let memo_data = string_parameter_set("홍길동");
+        let personal_info = field_record(b"%cpr", "");
+        // 알 수 없는 파라미터 아이템 종류
+        let personal_info_data = vec![0x1B, 0x02, 1, 0, 0, 0, 0x00, 0x40, 0x77, 0x77];
+
+        records.push((HWPTAG_CTRL_HEADER, 1, memo));
+        records.push((HWPTAG_CTRL_DATA, 2, memo_data));
+        records.push((HWPTAG_CTRL_HEADER, 1, personal_info));
+        records.push((HWPTAG_CTRL_DATA, 2, personal_info_data));
+    });
+    let file = storage.to_bytes();
+
+    let (scrubbed, report) =
+        scrub(&file, &ScrubOptions::default(), &ParseOptions::default()).unwrap();
+    assert_eq!(
+        report.actions[2..],
+        [
+            ScrubAction::TrackChangeAuthor("홍길동".to_string()),
+            ScrubAction::Memo("/BodyText/Section0".to_string()),
+        ]
+    );
+    assert_eq!(
+        report.skipped,
+        vec![
+            ScrubSkip::TrackChangeAuthor(author_index + 1),
+            ScrubSkip::Field("/BodyText/Section0".to_string()),
+        ]
+    );
+
+    let original = Storage::from_bytes(&file, &ParseOptions::default()).unwrap();
+    let storage = Storage::from_bytes(&scrubbed, &ParseOptions::default()).unwrap();
+
+    // 변경 추적 작성자는 이름만 지우고, 해석하지 못한 레코드는 그대로 둔다
+    let records = read_stream_records(&storage, "/DocInfo");
+    assert_eq!(
+        records[author_index],
+        (HWPTAG_TRACK_CHANGE_AUTHOR, 0, vec![0, 0, 1, 0])
+    );
+    assert_eq!(
+        records[author_index + 1],
+        read_stream_records(&original, "/DocInfo")[author_index + 1]
+    );
+    assert_eq!(
+        records
+            .iter()
+            .any(|(_, _, data)| data.windows(2).any(|c| c == [0x4D, 0xD6])),
+        false
+    );
+
+    let options = ScrubOptions {
+        pseudonym: Some("익명".to_string()),
+    };
+    let (pseudonymized, _) = scrub(&file, &options, &ParseOptions::default()).unwrap();
+    let storage = Storage::from_bytes(&pseudonymized, &ParseOptions::default()).unwrap();
+    let mut data = vec![2, 0];
+    data.extend("익명".encode_utf16().flat_map(|c| c.to_le_bytes()));
+    data.extend([1, 0]);
+    assert_eq!(
+        read_stream_records(&storage, "/DocInfo")[author_index],
+        (HWPTAG_TRACK_CHANGE_AUTHOR, 0, data)
+    );
+
+    let storage = Storage::from_bytes(&scrubbed, &ParseOptions::default()).unwrap();
+
+    // 레코드 구조는 그대로 두고 명령과 문자열만 비운다
+    let original = read_stream_records(&original, "/BodyText/Section0");
+    let records = read_stream_records(&storage, "/BodyText/Section0");
+    assert_eq!(records.len(), original.len());
+
+    let fields = &records[records.len() - 4..];
+    assert_eq!(
+        fields[0],
+        (HWPTAG_CTRL_HEADER, 1, field_record(b"%%me", ""))
+    );
+    assert_eq!(fields[1], (HWPTAG_CTRL_DATA, 2, string_parameter_set("")));
+    assert_eq!(
+        fields[2],
+        (HWPTAG_CTRL_HEADER, 1, field_record(b"%cpr", ""))
+    );
+    assert_eq!(fields[3], original[original.len() - 1]);
+
+    // 비운 필드는 다시 정리하지 않는다
+    let (_, report) = scrub(
+        &scrubbed,
+        &ScrubOptions::default(),
+        &ParseOptions::default(),
+    )
+    .unwrap();
+    assert_eq!(report.actions, vec![]);
+    assert_eq!(report.skipped.len(), 2);
+
+    // 외부 파일 연결은 절대 경로만 지운다
+    let path = get_tests_path("integration/project/files/embedded_objects.hwp");
+    let file = fs::read(path).unwrap();
+
+    let (scrubbed, report) =
+        scrub(&file, &ScrubOptions::default(), &ParseOptions::default()).unwrap();
+    assert_eq!(
+        report.actions[2..],
+        [ScrubAction::BinDataPath(
+            "C:\\Users\\hwp\\secret.png".to_string()
+        )]
+    );
+
+    let hwp = HWP::from_bytes(&scrubbed);
+    let link = &hwp.doc_info.id_mappings.binary_data[3];
+    assert_eq!(link.absolute_path.as_deref(), Some(""));
+    assert_eq!(link.relative_path.as_deref(), Some("secret.png"));
+}
+
+#[test]
+fn check_redact() {
+    let path = get_tests_path("integration/project/files/hello_world.hwp");
+    let file = fs::read(path).unwrap();
+
+    let matcher = |text: &str| {
+        text.match_indices("World")
+            .map(|(start, found)| start..start + found.len())
+            .collect()
+    };
+    let (redacted, report) = redact(
+        &file,
+        &RedactOptions::default(),
+        &ParseOptions::default(),
+        matcher,
+    )
+    .unwrap();
+    assert_eq!(
+        report.actions[0],
+        RedactAction::Paragraph {
+            path: "/BodyText/Section0".to_string(),
+            paragraph: 0,
+            start_position: 22,
+            end_position: 27,
+        }
+    );
+    assert_eq!(report.actions.contains(&RedactAction::PreviewImage), true);
+
+    let hwp = HWP::from_bytes(&redacted);
+    assert_eq!(
+        hwp.body_texts.sections[0].paragraphs[0].to_string(),
+        "Hello *****!"
+    );
+    assert_eq!(hwp.preview_text(), Some("Hello *****!\r\n"));
+    assert_eq!(hwp.preview_image().is_none(), true);
+    assert_eq!(hwp.summary_info.unwrap().title(), Some("Hello *****"));
+
+    // 가린 문서에는 다시 가릴 것이 없다
+    let (_, report) = redact(
+        &redacted,
+        &RedactOptions::default(),
+        &ParseOptions::default(),
+        matcher,
+    )
+    .unwrap();
+    assert_eq!(report.is_clean(), true);
+
+    // 문서가 아니라면 오류를 반환한다
+    assert!(matches!(
+        redact(
+            b"not a document",
+            &RedactOptions::default(),
+            &ParseOptions::default(),
+            matcher
+        ),
+        Err(Error::InvalidFile)
+    ));
+
+    // 글상자 안의 문단
+    let path = get_tests_path("integration/project/files/draw_text.hwp");
+    let file = fs::read(path).unwrap();
+
+    let spans = [RedactSpan {
+        section: 0,
+        paragraph: 1,
+        start_position: 0,
+        end_position: 1,
+    }];
+    let options = RedactOptions { mask: '○' };
+    let (redacted, report) =
+        redact_spans(&file, &options, &ParseOptions::default(), &spans).unwrap();
+    assert_eq!(report.actions.contains(&RedactAction::PreviewText), true);
+
+    let hwp = HWP::from_bytes(&redacted);
+    assert_eq!(hwp.preview_text(), Some("<○상자1><○상자2>\r\n"));
+}
+
+#[test]
+fn check_privacy() {
+    let kinds = |text: &str| -> Vec<(PersonalInfoKind, String)> {
+        find_personal_info(text)
+            .into_iter()
+            .map(|(kind, range)| (kind, text[range].to_string()))
+            .collect()
+    };
+
+    assert_eq!(
+        kinds("주민등록번호: 900101-1234567, 여권 M12345678"),
+        vec![
+            (
+                PersonalInfoKind::ResidentRegistrationNumber,
+                "900101-1234567".to_string()
+            ),
+            (PersonalInfoKind::PassportNumber, "M12345678".to_string()),
+        ]
+    );
+    assert_eq!(
+        kinds("연락처 010-1234-5678, 02)123-4567 hong.gildong@example.co.kr"),
+        vec![
+            (PersonalInfoKind::PhoneNumber, "010-1234-5678".to_string()),
+            (
+                PersonalInfoKind::Email,
+                "hong.gildong@example.co.kr".to_string()
+            ),
+        ]
+    );
+    assert_eq!(
+        kinds("입금 계좌 110-123-456789 (2023-01-01)"),
+        vec![(PersonalInfoKind::BankAccount, "110-123-456789".to_string())]
+    );
+    assert_eq!(kinds("991332-1234567 A12345678 1234-5678"), vec![]);
+
+    let path = get_tests_path("integration/project/files/hello_world.hwp");
+    let file = fs::read(path).unwrap();
+    assert_eq!(scan(&file, &ParseOptions::default()).unwrap(), vec![]);
+
+    // 본문의 "Hello World!"를 같은 길이의 전화번호로 바꾼다
+    let phone_number: Vec<u16> = "02-123-4567!".encode_utf16().collect();
+    let file = replace_hello_world(&file, &phone_number);
+
+    let found = scan(&file, &ParseOptions::default()).unwrap();
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].kind, PersonalInfoKind::PhoneNumber);
+    assert_eq!(found[0].path, "/BodyText/Section0");
+    assert_eq!(found[0].text, "02-123-4567");
+    assert_eq!(
+        found[0].location,
+        ParagraphLocation {
+            section: 0,
+            paragraph: 0,
+            nested: vec![],
+        }
+    );
+    assert_eq!(
+        found[0].span,
+        RedactSpan {
+            section: 0,
+            paragraph: 0,
+            start_position: 16,
+            end_position: 27,
+        }
+    );
+
+    let spans: Vec<RedactSpan> = found.into_iter().map(|info| info.span).collect();
+    let (redacted, _) = redact_spans(
+        &file,
+        &RedactOptions::default(),
+        &ParseOptions::default(),
+        &spans,
+    )
+    .unwrap();
+    let hwp = HWP::from_bytes(&redacted);
+    assert_eq!(
+        hwp.body_texts.sections[0].paragraphs[0].to_string(),
+        "***********!"
+    );
+
+    // 글상자 안의 문단은 문서 모델을 따라 위치를 찾는다
+    let path = get_tests_path("integration/project/files/draw_text.hwp");
+    let mut storage =
+        Storage::from_bytes(&fs::read(path).unwrap(), &ParseOptions::default()).unwrap();
+    update_stream_records(&mut storage, "/BodyText/Section0", |records| {
+        let to_bytes =
+            |text: &str| -> Vec<u8> { text.encode_utf16().flat_map(|c| c.to_le_bytes()).collect() };
+        let index = records
+            .iter()
+            .position(|(_, _, data)| *data == to_bytes("글상자2\r"))
+            .unwrap();
+
+        let text = to_bytes("문의 02-123-4567\r");
+        let header = &mut records[index - 1].2;
+        let chars = u32::from_le_bytes(header[0..4].try_into().unwrap()) & 0x80000000
+            | (text.len() / 2) as u32;
+        header[0..4].copy_from_slice(&chars.to_le_bytes());
+        records[index].2 = text;
+    });
+    let file = storage.to_bytes();
+
+    let found = scan(&file, &ParseOptions::default()).unwrap();
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].text, "02-123-4567");
+    assert_eq!(
+        found[0].location,
+        ParagraphLocation {
+            section: 0,
+            paragraph: 0,
+            nested: vec![NestedParagraph {
+                control: 3,
+                list: 0,
+                paragraph: 0,
+            }],
+        }
+    );
+    // 구역 스트림에서는 본문 문단과 첫 글상자의 문단 다음이다
+    assert_eq!(found[0].span.paragraph, 2);
+
+    let location = &found[0].location;
+    let hwp = HWP::from_bytes(&file);
+    let control = &hwp.body_texts.sections[location.section].paragraphs[location.paragraph]
+        .controls[location.nested[0].control];
+    let paragraph = &control.paragraph_lists()[location.nested[0].list].paragraphs
+        [location.nested[0].paragraph];
+    assert_eq!(paragraph.to_string(), "문의 02-123-4567");
+
+    let spans: Vec<RedactSpan> = found.into_iter().map(|info| info.span).collect();
+    let (redacted, _) = redact_spans(
+        &file,
+        &RedactOptions::default(),
+        &ParseOptions::default(),
+        &spans,
+    )
+    .unwrap();
+    assert_eq!(
+        HWP::from_bytes(&redacted).to_text(),
+        "글상자1\n문의 ***********\n"
+    );
+    assert_eq!(scan(&redacted, &ParseOptions::default()).unwrap(), vec![]);
+}
+
+#[test]
+fn check_paragraph_to_text() {
+    let path = get_tests_path("integration/project/files/hello_world.hwp");
+    let file = fs::read(path).unwrap();
+
+    // 서로게이트 쌍, 고정폭 빈칸, 하이픈, 탭
+    let mut units: Vec<u16> = "😀".encode_utf16().collect();
+    units.extend([31, 24]);
+    units.extend([9, 0, 0, 0, 0, 0, 0, 9]);
+    let file = replace_hello_world(&file, &units);
+
+    let hwp = HWP::from_bytes(&file);
+    let paragraph = &hwp.body_texts.sections[0].paragraphs[0];
+    assert_eq!(paragraph.to_string(), "😀\u{A0}\u{AD}\t");
+    assert_eq!(paragraph.to_text(&TextOptions::plain()), "😀  ");
+    assert_eq!(
+        paragraph.to_text(&TextOptions {
+            tab: None,
+            hyphen: Some('-'),
+            ..Default::default()
+        }),
+        "😀\u{A0}-"
+    );
+}
+
+/// hello_world.hwp 본문의 "Hello World!"(12글자)를 같은 길이의 글자로 바꾼다
+fn replace_hello_world(file: &[u8], units: &[u16]) -> Vec<u8> {
+    assert_eq!(units.len(), 12);
+
+    let mut storage = Storage::from_bytes(file, &ParseOptions::default()).unwrap();
+    let mut section = vec![];
+    DeflateDecoder::new(storage.get("/BodyText/Section0").unwrap())
+        .read_to_end(&mut section)
+        .unwrap();
+
+    let from: Vec<u8> = "Hello World!"
+        .encode_utf16()
+        .flat_map(|c| c.to_le_bytes())
+        .collect();
+    let to: Vec<u8> = units.iter().flat_map(|c| c.to_le_bytes()).collect();
+    let offset = section
+        .windows(from.len())
+        .position(|window| window == from)
+        .unwrap();
+    section[offset..offset + from.len()].copy_from_slice(&to);
+
+    let mut encoder = DeflateEncoder::new(vec![], Compression::default());
+    encoder.write_all(&section).unwrap();
+    storage.set("/BodyText/Section0", encoder.finish().unwrap());
+
+    storage.to_bytes()
+}
+
+#[test]
+fn check_pua_map() {
+    let path = get_tests_path("integration/project/files/hello_world.hwp");
+    let file = fs::read(path).unwrap();
+
+    let units: Vec<u16> = "\u{E0BC}bcdefghijkl".encode_utf16().collect();
+    let file = replace_hello_world(&file, &units);
+
+    let hwp = HWP::from_bytes(&file);
+    let paragraph = &hwp.body_texts.sections[0].paragraphs[0];
+    assert_eq!(paragraph.to_string(), "\u{E0BC}bcdefghijkl");
+
+    let pua =
+        PuaMap::from_table("# 옛한글\nE0BC\t1100 119E\n\nU+E0BD 1100 119E 11A8 # 주석\n").unwrap();
+    assert_eq!(pua.len(), 2);
+    assert_eq!(pua.get(0xE0BD), Some("\u{1100}\u{119E}\u{11A8}"));
+
+    let options = TextOptions {
+        pua: Some(pua),
+        ..Default::default()
+    };
+    assert_eq!(paragraph.to_text(&options), "\u{1100}\u{119E}bcdefghijkl");
+
+    assert!(matches!(
+        PuaMap::from_table("E0BC 1100\nE0BD"),
+        Err(Error::InvalidPuaTable(2))
+    ));
+}
+
+#[test]
+fn check_to_text() {
+    let path = get_tests_path("integration/project/files/hello_world.hwp");
+    let file = fs::read(path).unwrap();
+
+    let hwp = HWP::from_bytes(&file);
+    assert_eq!(hwp.to_text(), "Hello World!\n");
+
+    // 배포용 문서는 ViewText의 본문을 사용한다
+    let options = DistributeOptions {
+        password: "password".to_string(),
+        restrictions: 0x0001,
+        seed: None,
+    };
+    let hwp = HWP::from_bytes(&to_distributed(&file, &options, &ParseOptions::default()).unwrap());
+    assert_eq!(hwp.to_text(), "Hello World!\n");
+
+    // 글상자
+    let path = get_tests_path("integration/project/files/draw_text.hwp");
+    let file = fs::read(path).unwrap();
+
+    let hwp = HWP::from_bytes(&file);
+    assert_eq!(hwp.to_text(), "글상자1\n글상자2\n");
+
+    // 표는 행 순서대로 셀의 문단을 넣는다
+    let path = get_tests_path("integration/naver_documents/files/work_report.hwp");
+    let file = fs::read(path).unwrap();
+
+    let hwp = HWP::from_bytes(&file);
+    let text = hwp.to_text();
+    assert_eq!(text.starts_with("주간업무보고서\n"), true);
+    assert_eq!(
+        text.contains("금주 진행업무\n업무내용\n완결여부\n산출물\n비고\n"),
+        true
+    );
+    assert_eq!(text.trim_end().ends_with("설치하기"), true);
+}
+
+#[test]
+fn check_to_text_controls() {
+    let path = get_tests_path("integration/project/files/draw_text.hwp");
+    let file = fs::read(path).unwrap();
+
+    // 첫 문단의 세 번째, 네 번째 컨트롤이 글상자1, 글상자2 이다
+    let hwp = HWP::from_bytes(&file);
+    let paragraph = &hwp.body_texts.sections[0].paragraphs[0];
+    let first = paragraph.controls[2].draw_texts()[0].paragraph_list.clone();
+    let second = paragraph.controls[3].draw_texts()[0].paragraph_list.clone();
+
+    let to_text = |controls: Vec<[Control; 2]>, options: &TextOptions| {
+        let mut hwp = HWP::from_bytes(&file);
+        let section = &mut hwp.body_texts.sections[0];
+        let paragraph = section.paragraphs.pop().unwrap();
+        for [third, fourth] in controls {
+            let mut paragraph = paragraph.clone();
+            paragraph.controls[2] = third;
+            paragraph.controls[3] = fourth;
+            section.paragraphs.push(paragraph);
+        }
+        hwp.to_text_with_options(options)
+    };
+    let footnote = |paragraph_list| Control::Footnote(FootnoteEndnote { paragraph_list });
+    let endnote = |paragraph_list| Control::Endnote(FootnoteEndnote { paragraph_list });
+
+    // 각주와 미주는 따로 번호를 매긴다
+    let notes = vec![
+        [footnote(first.clone()), endnote(second.clone())],
+        [footnote(second.clone()), footnote(first.clone())],
+    ];
+    assert_eq!(
+        to_text(notes.clone(), &TextOptions::default()),
+        "[1][미주 1]\n[2][3]\n\n[1] 글상자1\n[2] 글상자2\n[3] 글상자1\n\n[미주 1] 글상자2\n"
+    );
+    let options = TextOptions {
+        notes: false,
+        ..Default::default()
+    };
+    assert_eq!(to_text(notes, &options), "\n\n");
+
+    // 위쪽 캡션은 개체 앞에, 나머지 캡션은 개체 뒤에 넣는다
+    let mut controls = paragraph.controls[2..].to_vec();
+    for (control, align) in controls
+        .iter_mut()
+        .zip([CaptionAlign::Top, CaptionAlign::Bottom])
+    {
+        if let Control::GenShapeObject(control) = control {
+            control.common_properties.caption = Some(Caption {
+                paragraph_list: second.clone(),
+                align,
+                full_size: false,
+                width: 0,
+                gap: 0,
+                last_width: 0,
+            });
+        }
+    }
+    let captions = vec![[controls[0].clone(), controls[1].clone()]];
+    assert_eq!(
+        to_text(captions, &TextOptions::default()),
+        "글상자2\n글상자1\n글상자2\n글상자2\n"
+    );
+
+    // 머리말/꼬리말은 설정한 경우에만 넣는다
+    let headers_footers = vec![[
+        Control::Header(HeaderFooter {
+            paragraph_list: first,
+        }),
+        Control::Footer(HeaderFooter {
+            paragraph_list: second,
+        }),
+    ]];
+    assert_eq!(
+        to_text(headers_footers.clone(), &TextOptions::default()),
+        "\n"
+    );
+    let options = TextOptions {
+        headers_footers: true,
+        ..Default::default()
+    };
+    assert_eq!(to_text(headers_footers, &options), "글상자1\n글상자2\n");
+}
+
+#[test]
+fn check_to_markdown() {
+    let path = get_tests_path("integration/project/files/hello_world.hwp");
+    let file = fs::read(path).unwrap();
+
+    let hwp = HWP::from_bytes(&file);
+    assert_eq!(hwp.to_markdown(), "Hello World!\n");
+
+    // 마크다운 문법으로 해석될 수 있는 문자는 이스케이프 한다
+    let units: Vec<u16> = "1. a*b_[x]|!".encode_utf16().collect();
+    let hwp = HWP::from_bytes(&replace_hello_world(&file, &units));
+    assert_eq!(hwp.to_markdown(), "1\\. a\\*b\\_\\[x\\]\\|!\n");
+
+    // 글상자
+    let path = get_tests_path("integration/project/files/draw_text.hwp");
+    let file = fs::read(path).unwrap();
+
+    let hwp = HWP::from_bytes(&file);
+    assert_eq!(hwp.to_markdown(), "글상자1\n\n글상자2\n");
+
+    // 표와 진하게
+    let path = get_tests_path("integration/naver_documents/files/work_report.hwp");
+    let file = fs::read(path).unwrap();
+
+    let hwp = HWP::from_bytes(&file);
+    let markdown = hwp.to_markdown();
+    assert_eq!(
+        markdown.starts_with("| 주간업무보고서 | **부서명:** 부서명<br>**이름:** 이름<br>"),
+        true
+    );
+    assert_eq!(
+        markdown.contains(
+            "| 금주 진행업무 |  |  |  |\n| --- | --- | --- | --- |\n| 업무내용 | 완결여부 | 산출물 | 비고 |\n"
+        ),
+        true
+    );
+
+    // 개요 문단은 제목으로, 그림은 BinData의 파일 이름으로 참조한다
+    let path = get_tests_path("integration/hancom/files/한글문서파일형식_5.0_revision1.3.hwp");
+    let file = fs::read(path).unwrap();
+
+    let hwp = HWP::from_bytes(&file);
+    let markdown = hwp.to_markdown();
+    assert_eq!(markdown.contains("\n# 저작권\n"), true);
+    assert_eq!(markdown.contains("\n## 개요\n"), true);
+    assert_eq!(markdown.contains("\n#### 파일 인식 정보\n"), true);
+    assert_eq!(markdown.contains("![](BinData/BIN0003.bmp)"), true);
+
+    let options = MarkdownOptions {
+        image_path: "images/".to_string(),
+        ..MarkdownOptions::default()
+    };
+    let markdown = hwp.to_markdown_with_options(&options);
+    assert_eq!(markdown.contains("![](images/BIN0003.bmp)"), true);
+
+    // 수식과 글머리표
+    let path = get_tests_path("integration/hancom/files/한글문서파일형식_수식_revision1.3.hwp");
+    let file = fs::read(path).unwrap();
+
+    let hwp = HWP::from_bytes(&file);
+    let markdown = hwp.to_markdown();
+    assert_eq!(markdown.contains("$a^3$처럼 거듭제곱의 명령어는"), true);
+    assert_eq!(markdown.contains("\n- **revision 1.3:20181108**\n"), true);
+}
+
+#[test]
+fn check_to_html() {
+    let path = get_tests_path("integration/project/files/hello_world.hwp");
+    let file = fs::read(path).unwrap();
+
+    let hwp = HWP::from_bytes(&file);
+    let html = hwp.to_html();
+    assert_eq!(html.starts_with("<!DOCTYPE html>"), true);
+    assert_eq!(html.contains("<title>Hello World</title>"), true);
+    assert_eq!(
+        html.contains("<p class=\"ps0\"><span class=\"cs6\">Hello World!</span></p>"),
+        true
+    );
+
+    // 셀 병합과 테두리/배경
+    let path = get_tests_path("integration/naver_documents/files/work_report.hwp");
+    let file = fs::read(path).unwrap();
+
+    let hwp = HWP::from_bytes(&file);
+    let html = hwp.to_html();
+    assert_eq!(html.contains("colspan=\"4\""), true);
+    assert_eq!(html.contains("\n.bf0 {"), true);
+
+    // 그림은 기본적으로 data URI로 넣는다
+    let path = get_tests_path("integration/hancom/files/한글문서파일형식_5.0_revision1.3.hwp");
+    let file = fs::read(path).unwrap();
+
+    let hwp = HWP::from_bytes(&file);
+    let html = hwp.to_html();
+    assert_eq!(html.contains("<img src=\"data:image/png;base64,"), true);
+    assert_eq!(html.contains("<header>"), true);
+
+    let options = HtmlOptions {
+        image_path: Some("images/".to_string()),
+        ..HtmlOptions::default()
+    };
+    let html = hwp.to_html_with_options(&options);
+    assert_eq!(html.contains("src=\"images/BIN0003.bmp\""), true);
+    assert_eq!(html.contains("data:image/"), false);
+
+    // 알 수 없는 형식의 그림은 넣지 않는다
+    let mut hwp = hwp;
+    let extension = "png\" onerror=\"alert(1)";
+    for item in hwp.doc_info.id_mappings.binary_data.iter_mut() {
+        item.extension = Some(extension.to_string());
+    }
+    for file in hwp.bin_data.iter_mut() {
+        let (name, _) = file.name.split_once('.').unwrap();
+        file.name = format!("{}.{}", name, extension);
+    }
+    let html = hwp.to_html();
+    assert_eq!(html.contains("onerror"), false);
+    assert_eq!(html.contains("<img"), false);
+
+    // 문서에 기록된 글꼴 이름으로 스타일을 벗어날 수 없다
+    let path = get_tests_path("integration/project/files/hello_world.hwp");
+    let file = fs::read(path).unwrap();
+
+    let mut hwp = HWP::from_bytes(&file);
+    let id_mappings = &mut hwp.doc_info.id_mappings;
+    for font in id_mappings
+        .korean_fonts
+        .iter_mut()
+        .chain(id_mappings.english_fonts.iter_mut())
+    {
+        font.name = "x\"; } </style><script>alert(1)</script><style> p { a: \"".to_string();
+    }
+    let html = hwp.to_html();
+    assert_eq!(html.contains("<script>"), false);
+    assert_eq!(html.matches("</style>").count(), 1);
+    assert_eq!(
+        html.contains("font-family: \"x  stylescriptalert(1)scriptstyle p  a \";"),
+        true
+    );
+}
+
+#[test]
+fn check_runs() {
+    let path = get_tests_path("integration/project/files/hello_world.hwp");
+    let file = fs::read(path).unwrap();
+
+    let hwp = HWP::from_bytes(&file);
+    let paragraph = &hwp.body_texts.sections[0].paragraphs[0];
+    let runs = paragraph.runs(&hwp.doc_info);
+    assert_eq!(runs.len(), 3);
+
+    // 구역 정의, 단 정의
+    assert_eq!(runs[0].range, 0..8);
+    assert_eq!(
+        matches!(
+            runs[0].content,
+            RunContent::Control(Control::SectionDefinition(_))
+        ),
+        true
+    );
+    assert_eq!(
+        matches!(runs[1].content, RunContent::Control(Control::Column(_))),
+        true
+    );
+
+    assert_eq!(runs[2].range, 16..28);
+    assert_eq!(
+        matches!(&runs[2].content, RunContent::Text(text) if text == "Hello World!"),
+        true
+    );
+    let style = runs[2].style.as_ref().unwrap();
+    assert_eq!(style.font_names[0], Some("함초롬바탕"));
+    assert_eq!(style.size, 10.0);
+    assert_eq!(style.bold, false);
+    assert_eq!(style.color.to_hex(), "#000000");
+
+    // 글자 모양이 바뀌는 곳에서 나눈다
+    let path = get_tests_path("integration/hancom/files/한글문서파일형식_5.0_revision1.3.hwp");
+    let file = fs::read(path).unwrap();
+
+    let hwp = HWP::from_bytes(&file);
+    let body = hwp.view_texts.as_ref().unwrap();
+    let paragraph = &body.sections[3].paragraphs[142];
+    let runs = paragraph.runs(&hwp.doc_info);
+    assert_eq!(runs.len(), 2);
+    assert_eq!(runs[0].range, 0..6);
+    assert_eq!(
+        matches!(&runs[0].content, RunContent::Text(text) if text == "Tag ID"),
+        true
+    );
+    assert_eq!(runs[0].style.as_ref().unwrap().bold, true);
+    assert_eq!(runs[1].style.as_ref().unwrap().bold, false);
+}
+
+#[test]
+fn check_position_map() {
+    let path = get_tests_path("integration/project/files/range.hwp");
+    let file = fs::read(path).unwrap();
+
+    let hwp = HWP::from_bytes(&file);
+    let paragraph = &hwp.body_texts.sections[0].paragraphs[0];
+
+    // 구역 정의와 단 정의는 8로 센다
+    let positions = paragraph.char_list.positions();
+    assert_eq!(positions[..4], [0, 8, 16, 17]);
+    assert_eq!(positions.last(), Some(&29));
+
+    let options = TextOptions::default();
+    let text = paragraph.to_text(&options);
+    let map = paragraph.position_map(&options);
+    assert_eq!(map.from_hwp(16).index, 2);
+    assert_eq!(map.from_hwp(16).utf8, 0);
+    // 컨트롤 안의 위치는 컨트롤의 위치로 본다
+    assert_eq!(map.from_hwp(3).hwp, 0);
+    assert_eq!(map.end().utf8, text.len());
+
+    let ranges: Vec<_> = paragraph
+        .range_tags
+        .iter()
+        .map(|tag| &text[map.hwp_to_utf8(tag.start_position..tag.end_position)])
+        .collect();
+    assert_eq!(ranges, ["Hell", "o Wo", "rld!"]);
+    assert_eq!(map.utf8_to_hwp(4..8), 20..24);
+
+    // UTF-8과 UTF-16 위치
+    let path = get_tests_path("integration/hancom/files/한글문서파일형식_5.0_revision1.3.hwp");
+    let file = fs::read(path).unwrap();
+
+    let hwp = HWP::from_bytes(&file);
+    let body = hwp.view_texts.as_ref().unwrap();
+    let paragraph = &body.sections[3].paragraphs[142];
+    let text = paragraph.to_text(&options);
+    let map = paragraph.position_map(&options);
+
+    let offset = text.find("레코드").unwrap();
+    let position = map.from_utf8(offset);
+    assert_eq!(position.utf16, 9);
+    assert_eq!(position.hwp, 9);
+    assert_eq!(map.from_utf16(10).utf8, offset + "레".len());
+}
+
+#[test]
+fn check_to_docx() {
+    let path = get_tests_path("integration/project/files/hello_world.hwp");
+    let file = fs::read(path).unwrap();
+
+    let hwp = HWP::from_bytes(&file);
+    let docx = hwp.to_docx();
+    assert_eq!(docx.starts_with(b"PK\x03\x04"), true);
+
+    let content_types = read_zip_entry(&docx, "[Content_Types].xml").unwrap();
+    assert_eq!(content_types.contains("/word/document.xml"), true);
+
+    let document = read_zip_entry(&docx, "word/document.xml").unwrap();
+    assert_eq!(
+        document.contains("<w:t xml:space=\"preserve\">Hello World!</w:t>"),
+        true
+    );
+    // A4 용지와 한/글 기본 여백
+    assert_eq!(
+        document.contains("<w:pgSz w:w=\"11905\" w:h=\"16837\"/>"),
+        true
+    );
+    assert_eq!(document.contains("w:top=\"1984\""), true);
+
+    let styles = read_zip_entry(&docx, "word/styles.xml").unwrap();
+    assert_eq!(styles.contains("<w:name w:val=\"바탕글\"/>"), true);
+
+    let core = read_zip_entry(&docx, "docProps/core.xml").unwrap();
+    assert_eq!(core.contains("<dc:title>Hello World</dc:title>"), true);
+
+    // 책갈피
+    let path = get_tests_path("integration/project/files/bookmark.hwp");
+    let file = fs::read(path).unwrap();
+
+    let hwp = HWP::from_bytes(&file);
+    let document = read_zip_entry(&hwp.to_docx(), "word/document.xml").unwrap();
+    assert_eq!(
+        document.contains("<w:bookmarkStart w:id=\"1\" w:name=\"책갈피테스트\"/>"),
+        true
+    );
+
+    // 셀 병합
+    let path = get_tests_path("integration/naver_documents/files/work_report.hwp");
+    let file = fs::read(path).unwrap();
+
+    let hwp = HWP::from_bytes(&file);
+    let document = read_zip_entry(&hwp.to_docx(), "word/document.xml").unwrap();
+    assert_eq!(document.contains("<w:gridSpan w:val=\"4\"/>"), true);
+
+    // 그림, 세로 병합, 머리말과 개요 번호
+    let path = get_tests_path("integration/hancom/files/한글문서파일형식_5.0_revision1.3.hwp");
+    let file = fs::read(path).unwrap();
+
+    let hwp = HWP::from_bytes(&file);
+    let docx = hwp.to_docx();
+    let document = read_zip_entry(&docx, "word/document.xml").unwrap();
+    assert_eq!(document.contains("<a:blip r:embed=\"image3\"/>"), true);
+    assert_eq!(document.contains("<w:vMerge w:val=\"restart\"/>"), true);
+    assert_eq!(document.contains("<w:headerReference"), true);
+    assert_eq!(document.contains("<w:outlineLvl w:val=\"0\"/>"), true);
+
+    let relationships = read_zip_entry(&docx, "word/_rels/document.xml.rels").unwrap();
+    assert_eq!(relationships.contains("Target=\"media/BIN0003.bmp\""), true);
+    assert_eq!(read_zip_entry(&docx, "word/header1.xml").is_some(), true);
+}
+
+#[test]
+fn check_to_odt() {
+    let path = get_tests_path("integration/project/files/hello_world.hwp");
+    let file = fs::read(path).unwrap();
+
+    let hwp = HWP::from_bytes(&file);
+    let odt = hwp.to_odt();
+    // mimetype은 압축하지 않은 첫 파일
+    assert_eq!(odt[30..38], *b"mimetype");
+    assert_eq!(
+        read_zip_entry(&odt, "mimetype"),
+        Some("application/vnd.oasis.opendocument.text".to_string())
+    );
+
+    let manifest = read_zip_entry(&odt, "META-INF/manifest.xml").unwrap();
+    assert_eq!(
+        manifest.contains("manifest:full-path=\"content.xml\""),
+        true
+    );
+
+    let content = read_zip_entry(&odt, "content.xml").unwrap();
+    assert_eq!(
+        content.contains("<text:span text:style-name=\"T1\">Hello World!</text:span>"),
+        true
+    );
+
+    // A4 용지와 한/글 기본 여백
+    let styles = read_zip_entry(&odt, "styles.xml").unwrap();
+    assert_eq!(
+        styles.contains("fo:page-width=\"595.28pt\" fo:page-height=\"841.86pt\""),
+        true
+    );
+    assert_eq!(styles.contains("style:display-name=\"바탕글\""), true);
+    assert_eq!(content.contains("style:master-page-name=\"MP1\""), true);
+
+    let meta = read_zip_entry(&odt, "meta.xml").unwrap();
+    assert_eq!(meta.contains("<dc:title>Hello World</dc:title>"), true);
+
+    // 셀 병합
+    let path = get_tests_path("integration/naver_documents/files/work_report.hwp");
+    let file = fs::read(path).unwrap();
+
+    let hwp = HWP::from_bytes(&file);
+    let content = read_zip_entry(&hwp.to_odt(), "content.xml").unwrap();
+    assert_eq!(content.contains("table:number-columns-spanned=\"4\""), true);
+    assert_eq!(content.contains("<table:covered-table-cell/>"), true);
+
+    // 그림, 머리말과 개요 번호
+    let path = get_tests_path("integration/hancom/files/한글문서파일형식_5.0_revision1.3.hwp");
+    let file = fs::read(path).unwrap();
+
+    let hwp = HWP::from_bytes(&file);
+    let odt = hwp.to_odt();
+    let content = read_zip_entry(&odt, "content.xml").unwrap();
+    assert_eq!(
+        content.contains("xlink:href=\"Pictures/BIN0003.bmp\""),
+        true
+    );
+    assert_eq!(content.contains("text:outline-level=\"1\""), true);
+
+    let styles = read_zip_entry(&odt, "styles.xml").unwrap();
+    assert_eq!(styles.contains("<style:header>"), true);
+    assert_eq!(styles.contains("<text:outline-style"), true);
+
+    let manifest = read_zip_entry(&odt, "META-INF/manifest.xml").unwrap();
+    assert_eq!(manifest.contains("\"Pictures/BIN0003.bmp\""), true);
+
+    // 알 수 없는 형식의 그림은 넣지 않는다
+    let mut hwp = hwp;
+    let extension = "bmp\"/><manifest:file-entry manifest:full-path=\"x";
+    for item in hwp.doc_info.id_mappings.binary_data.iter_mut() {
+        item.extension = Some(extension.to_string());
+    }
+    for file in hwp.bin_data.iter_mut() {
+        let (name, _) = file.name.split_once('.').unwrap();
+        file.name = format!("{}.{}", name, extension);
+    }
+    let odt = hwp.to_odt();
+    let manifest = read_zip_entry(&odt, "META-INF/manifest.xml").unwrap();
+    assert_eq!(manifest.contains("Pictures/"), false);
+    assert_eq!(manifest.contains("full-path=\"x"), false);
+    let content = read_zip_entry(&odt, "content.xml").unwrap();
+    assert_eq!(content.contains("<draw:image"), false);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn check_serde() {
+    let path = get_tests_path("integration/naver_documents/files/work_report.hwp");
+    let file = fs::read(path).unwrap();
+
+    let hwp = HWP::from_bytes(&file);
+    let json = serde_json::to_string(&hwp).unwrap();
+
+    // 바이너리 데이터는 Base64 문자열로 나타낸다 (BMP의 "BM")
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    let data = value["bin_data"][0]["data"].as_str().unwrap();
+    assert_eq!(data.starts_with("Qk"), true);
+
+    let restored: HWP = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored.to_text(), hwp.to_text());
+    assert_eq!(restored.bin_data[0].data, hwp.bin_data[0].data);
+    assert_eq!(restored.header.reserved, hwp.header.reserved);
+    assert_eq!(
+        restored.doc_info.id_mappings.char_shapes.len(),
+        hwp.doc_info.id_mappings.char_shapes.len()
+    );
+}
+
+/// ZIP 파일에서 이름이 같은 파일을 찾아 압축을 푼다
+fn read_zip_entry(zip: &[u8], name: &str) -> Option<String> {
+    let mut offset = 0;
+    while zip[offset..].starts_with(b"PK\x03\x04") {
+        let header = &zip[offset..];
+        let method = u16::from_le_bytes([header[8], header[9]]);
+        let size = u32::from_le_bytes([header[18], header[19], header[20], header[21]]) as usize;
+        let name_size = u16::from_le_bytes([header[26], header[27]]) as usize;
+        let extra_size = u16::from_le_bytes([header[28], header[29]]) as usize;
+
+        let start = 30 + name_size + extra_size;
+        let data = &header[start..start + size];
+        if &header[30..30 + name_size] == name.as_bytes() {
+            let mut out = String::new();
+            if method == 8 {
+                DeflateDecoder::new(data).read_to_string(&mut out).unwrap();
+            } else {
+                out = String::from_utf8(data.to_vec()).unwrap();
+            }
+            return Some(out);
+        }
+
+        offset += start + size;
+    }
+
+    None
+}
@@ -14,10 +14,7 @@ fn as_literal(token: &TokenTree) -> &Literal {
 pub fn make_4chid(token: TokenStream) -> TokenStream {
     let literals = token
         .into_iter()
-        .filter(|x| match x {
-            TokenTree::Literal(_) => true,
-            _ => false,
-        })
+        .filter(|x| matches!(x, TokenTree::Literal(_)))
         .collect::<Vec<TokenTree>>();
 
     if literals.len() != 4 {
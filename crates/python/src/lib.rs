@@ -26,6 +26,7 @@ struct HWPReader {
     pub sections: Vec<PySection>,
     #[pyo3(get)]
     pub bin_data: Vec<PyFile>,
+    hwp: HWP,
 }
 
 #[pymethods]
@@ -34,7 +35,7 @@ impl HWPReader {
     fn new(path: String) -> PyResult<Self> {
         // TODO: (@hahnlee) 메모리에 있는 파일 읽기 등 더 좋은 방법 필요
         let file = fs::read(path)?;
-        let hwp = HWP::from_bytes(&file);
+        let mut hwp = HWP::from_bytes(&file);
 
         let body = if hwp.header.flags.distributed {
             hwp.view_texts.as_ref().unwrap()
@@ -47,17 +48,25 @@ impl HWPReader {
             .map(|s| PySection::from_section(s))
             .collect::<Vec<PySection>>();
 
+        // NOTE: 문자열은 to_text를 부를 때 만들고, 바이너리 데이터는 PyFile로 옮겨 두 번 들고 있지 않는다
+        let bin_data = std::mem::take(&mut hwp.bin_data)
+            .into_iter()
+            .map(|b| PyFile::from_rust(&b))
+            .collect();
+
         Ok(Self {
             version: PyVersion(hwp.header.version.clone()),
             sections,
-            bin_data: hwp
-                .bin_data
-                .into_iter()
-                .map(|b| PyFile::from_rust(&b))
-                .collect(),
+            bin_data,
+            hwp,
         })
     }
 
+    /// 본문을 읽는 순서대로 문자열로 바꾼다
+    pub fn to_text(&self) -> String {
+        self.hwp.to_text()
+    }
+
     #[args(kwargs = "**")]
     pub fn find_all(&self, tag: &str, kwargs: Option<&PyDict>) -> Vec<Py<PyAny>> {
         (&self.sections)